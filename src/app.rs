@@ -2,14 +2,30 @@
 //! 
 //! 定义了系统监控工具的主应用程序结构和状态管理。
 
-use crate::config::{AppConfig, ConfigManager};
-use crate::error::{Result, SystemMonitorError, ErrorRecovery};
-use crate::system::{SystemInfoManager, SystemSnapshot, SystemHealthStatus};
+use crate::config::{AppConfig, ConfigManager, ConfigWatcher, MonitoringConfig};
+use crate::error::{Result, SystemMonitorError, ErrorRecovery, RecoveryStrategy};
+use crate::system::{SystemInfoManager, SystemSnapshot, SystemHealthStatus, SnapshotRequest, CpuInfo, ProcessInfo, SystemInfo};
+use crate::system::alert::{Alert, AlertLevel, AlertMonitor, NotificationThrottler};
+use crate::system::connections::ConnectionInfo;
+use crate::system::export;
+use crate::system::history::SnapshotHistory;
+use crate::system::process::{self, ProcessTarget};
+use crate::system::remote::{RemoteSource, SnapshotSource};
 use crate::ui::{UiManager, TabType};
+use crate::utils::t;
 use eframe::egui;
-use std::sync::Arc;
+use std::collections::{HashMap, VecDeque};
+use std::path::PathBuf;
+use std::sync::{Arc, RwLock};
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::time::{Duration, Instant};
-use tokio::sync::mpsc;
+use tokio::sync::{mpsc, watch, Notify};
+
+/// 配置文件变化的轮询间隔
+const CONFIG_POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// 事件日志保留的最大条数
+pub const EVENT_LOG_CAPACITY: usize = 500;
 
 /// 主应用程序结构
 pub struct SystemMonitorApp {
@@ -21,6 +37,10 @@ pub struct SystemMonitorApp {
     ui_manager: UiManager,
     /// 错误恢复处理器
     error_recovery: ErrorRecovery,
+    /// 告警监视器
+    alert_monitor: AlertMonitor,
+    /// 告警通知节流器
+    notification_throttler: NotificationThrottler,
     /// 应用程序状态
     app_state: AppState,
     /// 最后更新时间
@@ -31,6 +51,24 @@ pub struct SystemMonitorApp {
     message_receiver: Option<mpsc::UnboundedReceiver<AppMessage>>,
     /// 用于取消后台任务的令牌
     cancellation_token: tokio_util::sync::CancellationToken,
+    /// 进程标签页是否处于活动状态（与后台采集任务共享）
+    process_tab_active: Arc<AtomicBool>,
+    /// 通知后台任务立即采集一次
+    collect_now: Arc<Notify>,
+    /// 当前监控配置（与后台采集任务共享，决定每次采集的数据类别）
+    monitoring_config: Arc<RwLock<MonitoringConfig>>,
+    /// 后台采集间隔，更新后采集任务按新间隔计时
+    refresh_interval: watch::Sender<Duration>,
+    /// 窗口是否已最小化（与后台采集任务共享）
+    window_minimized: Arc<AtomicBool>,
+    /// 用于后台任务唤醒界面，窗口最小化时也能及时处理采集结果和告警
+    egui_ctx: egui::Context,
+    /// 当前查看的远程主机（与后台采集任务共享，`None` 表示本机）
+    remote_source: Arc<RwLock<Option<RemoteSource>>>,
+    /// 通知远程采集任务立即采集一次
+    remote_collect_now: Arc<Notify>,
+    /// 用于停止当前远程采集任务的令牌，查看本机时为 `None`
+    remote_cancellation: Option<tokio_util::sync::CancellationToken>,
 }
 
 /// 应用程序状态
@@ -40,29 +78,186 @@ pub struct AppState {
     pub is_running: bool,
     /// 当前系统快照
     pub current_snapshot: Option<SystemSnapshot>,
+    /// 最近的完整快照（受数量和内存预算限制）
+    pub snapshot_history: SnapshotHistory,
     /// 系统健康状态
     pub health_status: SystemHealthStatus,
     /// 错误信息
     pub last_error: Option<String>,
+    /// 是否处于降级模式（采集失败且恢复策略为降级时进入，下次采集成功后退出）
+    pub degraded: bool,
     /// 是否显示设置窗口
     pub show_settings: bool,
     /// 是否显示关于窗口
     pub show_about: bool,
+    /// 是否显示告警历史窗口
+    pub show_alert_history: bool,
+    /// 最近的告警（最新的在末尾）
+    pub alert_history: VecDeque<Alert>,
+    /// 是否显示事件日志窗口
+    pub show_event_log: bool,
+    /// 错误和告警事件日志（最新的在末尾，最多保留 [`EVENT_LOG_CAPACITY`] 条）
+    pub event_log: VecDeque<LogEntry>,
+    /// 启动健康检查发现的严重问题（非空时显示警告窗口）
+    pub startup_warnings: Vec<Alert>,
+    /// 当前显示的告警通知（多条告警合并后的文本）
+    pub notification: Option<String>,
+    /// 当前查看的远程主机名称（`None` 表示本机）
+    pub selected_host: Option<String>,
     /// 当前活动标签页
     pub active_tab: TabType,
+    /// 冻结显示时的快照（采集仍继续，标签页显示该快照）
+    pub frozen_snapshot: Option<FrozenSnapshot>,
+    /// 各标签页对应数据类别的采集状态
+    pub source_health: HashMap<TabType, SourceHealth>,
+    /// 最近一次获取的网络连接列表（尚未获取时为 `None`）
+    pub connections: Option<Vec<ConnectionInfo>>,
     /// 应用程序启动时间
     pub start_time: Instant,
 }
 
+/// 事件日志级别
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogLevel {
+    /// 警告级告警
+    Warning,
+    /// 严重级告警
+    Critical,
+    /// 应用程序错误
+    Error,
+}
+
+impl LogLevel {
+    /// 获取级别描述
+    pub fn description(&self) -> &'static str {
+        match self {
+            LogLevel::Warning => t("警告"),
+            LogLevel::Critical => t("严重"),
+            LogLevel::Error => t("错误"),
+        }
+    }
+}
+
+/// 事件日志条目
+#[derive(Debug, Clone, PartialEq)]
+pub struct LogEntry {
+    /// 发生时间
+    pub timestamp: chrono::DateTime<chrono::Utc>,
+    /// 级别
+    pub level: LogLevel,
+    /// 事件描述
+    pub message: String,
+}
+
+impl LogEntry {
+    /// 创建当前时间的错误条目
+    pub fn error(message: impl Into<String>) -> Self {
+        Self {
+            timestamp: chrono::Utc::now(),
+            level: LogLevel::Error,
+            message: message.into(),
+        }
+    }
+}
+
+impl From<&Alert> for LogEntry {
+    fn from(alert: &Alert) -> Self {
+        let level = match alert.level {
+            AlertLevel::Warning => LogLevel::Warning,
+            AlertLevel::Critical => LogLevel::Critical,
+        };
+        Self {
+            timestamp: alert.timestamp,
+            level,
+            message: alert.message(),
+        }
+    }
+}
+
+impl AppState {
+    /// 记录事件，超出容量时丢弃最早的条目
+    pub fn push_event(&mut self, entry: LogEntry) {
+        if self.event_log.len() == EVENT_LOG_CAPACITY {
+            self.event_log.pop_front();
+        }
+        self.event_log.push_back(entry);
+    }
+
+    /// 记录错误：显示在状态栏并写入事件日志
+    pub fn record_error(&mut self, message: String) {
+        self.push_event(LogEntry::error(message.clone()));
+        self.last_error = Some(message);
+    }
+}
+
+/// 冻结的显示快照
+#[derive(Debug, Clone)]
+pub struct FrozenSnapshot {
+    /// 冻结时的系统快照
+    pub snapshot: SystemSnapshot,
+    /// 冻结时间
+    pub frozen_at: Instant,
+}
+
+/// 数据类别的健康状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SourceStatus {
+    /// 数据新鲜
+    Fresh,
+    /// 数据过期（长时间未成功采集或从未采集）
+    Stale,
+    /// 最近一次采集失败
+    Error,
+}
+
+/// 单个数据类别的采集状态
+#[derive(Debug, Clone, Default)]
+pub struct SourceHealth {
+    /// 最近一次成功采集的时间
+    pub last_success: Option<Instant>,
+    /// 最近一次采集失败的时间和原因
+    pub last_error: Option<(Instant, String)>,
+}
+
+impl SourceHealth {
+    /// 根据最近的成功和失败时间判断健康状态
+    pub fn status(&self, now: Instant, stale_after: Duration) -> SourceStatus {
+        let failed_after_success = match (&self.last_error, self.last_success) {
+            (Some((failed_at, _)), Some(success_at)) => *failed_at > success_at,
+            (Some(_), None) => true,
+            (None, _) => false,
+        };
+        if failed_after_success {
+            return SourceStatus::Error;
+        }
+        match self.last_success {
+            Some(success_at) if now.saturating_duration_since(success_at) <= stale_after => SourceStatus::Fresh,
+            _ => SourceStatus::Stale,
+        }
+    }
+}
+
 /// 应用程序消息
+///
+/// 消息在通道中停留时间很短，不为较大的变体额外装箱。
 #[derive(Debug, Clone)]
+#[allow(clippy::large_enum_variant)]
 pub enum AppMessage {
     /// 系统信息更新
     SystemUpdate(SystemSnapshot),
     /// 配置更新
     ConfigUpdate,
+    /// 配置文件发生变化（内容与当前配置一致时忽略）
+    ConfigFileChanged,
     /// 错误发生
     Error(String),
+    /// 数据采集失败，附带错误恢复策略
+    CollectionFailed {
+        /// 错误描述
+        message: String,
+        /// 恢复策略
+        strategy: RecoveryStrategy,
+    },
     /// 切换标签页
     SwitchTab(TabType),
     /// 应用配置
@@ -75,6 +270,54 @@ pub enum AppMessage {
     ShowAbout,
     /// 隐藏关于
     HideAbout,
+    /// 告警触发
+    Alert(Alert),
+    /// 清空告警历史
+    ClearAlerts,
+    /// 显示告警历史
+    ShowAlertHistory,
+    /// 隐藏告警历史
+    HideAlertHistory,
+    /// 显示事件日志
+    ShowEventLog,
+    /// 隐藏事件日志
+    HideEventLog,
+    /// 清空事件日志
+    ClearEventLog,
+    /// 关闭告警通知
+    DismissNotification,
+    /// 切换查看的主机（`None` 表示本机）
+    SelectHost(Option<String>),
+    /// 重置历史统计
+    ResetStatistics,
+    /// 冻结显示
+    FreezeDisplay,
+    /// 恢复实时显示
+    UnfreezeDisplay,
+    /// 按给定顺序导出进程列表到CSV文件
+    ExportProcesses {
+        /// 导出路径
+        path: PathBuf,
+        /// 按表格顺序排列的进程
+        processes: Vec<ProcessInfo>,
+    },
+    /// 导出快照历史到CSV文件
+    ExportHistory(PathBuf),
+    /// 结束选中的进程
+    KillProcesses(Vec<ProcessTarget>),
+    /// 立即采集一次
+    RefreshNow,
+    /// 重新获取网络连接列表
+    RefreshConnections,
+    /// 网络连接列表更新
+    ConnectionsUpdate(Vec<ConnectionInfo>),
+    /// 导出当前快照到JSON文件，`with_stats` 为真时附带统计和配置摘要
+    ExportSnapshot {
+        /// 导出路径
+        path: PathBuf,
+        /// 是否导出为包含统计信息的快照报告
+        with_stats: bool,
+    },
     /// 退出应用
     Exit,
 }
@@ -84,11 +327,26 @@ impl Default for AppState {
         Self {
             is_running: true,
             current_snapshot: None,
+            snapshot_history: SnapshotHistory::with_budget_mb(
+                MonitoringConfig::default().snapshot_history_size,
+                MonitoringConfig::default().snapshot_history_max_mb,
+            ),
             health_status: SystemHealthStatus::Good,
             last_error: None,
+            degraded: false,
             show_settings: false,
             show_about: false,
+            show_alert_history: false,
+            alert_history: VecDeque::new(),
+            show_event_log: false,
+            event_log: VecDeque::with_capacity(EVENT_LOG_CAPACITY),
+            startup_warnings: Vec::new(),
+            notification: None,
+            selected_host: None,
             active_tab: TabType::Overview,
+            frozen_snapshot: None,
+            source_health: HashMap::new(),
+            connections: None,
             start_time: Instant::now(),
         }
     }
@@ -110,26 +368,49 @@ impl SystemMonitorApp {
         let error_recovery = ErrorRecovery::default();
         
         // 初始化应用程序状态
-        let app_state = AppState::default();
+        let mut app_state = AppState::default();
+        let monitoring = config_manager.get().monitoring.clone();
+        app_state.snapshot_history = SnapshotHistory::with_budget_mb(
+            monitoring.snapshot_history_size,
+            monitoring.snapshot_history_max_mb,
+        );
         
+        let notification_throttler = NotificationThrottler::from_config(&config_manager.get().alerts);
         let mut app = Self {
             config_manager,
             system_manager: None,
             ui_manager,
             error_recovery,
+            alert_monitor: AlertMonitor::new(),
+            notification_throttler,
             app_state,
             last_update: Instant::now(),
             message_sender: Some(message_sender),
             message_receiver: Some(message_receiver),
             cancellation_token: tokio_util::sync::CancellationToken::new(),
+            process_tab_active: Arc::new(AtomicBool::new(false)),
+            collect_now: Arc::new(Notify::new()),
+            refresh_interval: watch::Sender::new(Duration::from_millis(monitoring.refresh_interval_ms)),
+            monitoring_config: Arc::new(RwLock::new(monitoring)),
+            window_minimized: Arc::new(AtomicBool::new(false)),
+            egui_ctx: cc.egui_ctx.clone(),
+            remote_source: Arc::new(RwLock::new(None)),
+            remote_collect_now: Arc::new(Notify::new()),
+            remote_cancellation: None,
         };
         
         // 初始化系统信息管理器
         app.initialize_system_manager()?;
+
+        // 在首次定时采集前检查磁盘和内存
+        app.startup_health_check();
         
         // 启动后台数据采集任务
         app.start_background_collector();
 
+        // 监视配置文件，外部修改后自动重新加载
+        app.start_config_watcher();
+
         log::info!("系统监控应用程序初始化完成");
         Ok(app)
     }
@@ -145,37 +426,119 @@ impl SystemMonitorApp {
             Err(e) => {
                 let error_msg = format!("系统信息管理器初始化失败: {}", e);
                 log::error!("{}", error_msg);
-                self.app_state.last_error = Some(error_msg.clone());
+                self.app_state.record_error(error_msg.clone());
                 Err(SystemMonitorError::Runtime(error_msg))
             }
         }
     }
     
+    /// 启动健康检查，同步采集内存和磁盘信息并按告警阈值检查
+    fn startup_health_check(&mut self) {
+        let config = self.config_manager.get();
+        let alerts_config = &config.alerts;
+        // 快速启动时不在首次绘制前同步采集磁盘，交由后续定时采集告警
+        if !alerts_config.enabled || !alerts_config.startup_check || config.monitoring.fast_start {
+            return;
+        }
+
+        let Some(ref system_manager) = self.system_manager else {
+            return;
+        };
+
+        let collected = system_manager.get_memory_info()
+            .and_then(|memory| system_manager.get_disk_info(&config.monitoring.disk_filter).map(|disks| (memory, disks)));
+        match collected {
+            Ok((memory, disks)) => {
+                let snapshot = SystemSnapshot::new(CpuInfo::default(), memory, disks, SystemInfo::default(), None);
+                self.check_startup_snapshot(&snapshot);
+            }
+            Err(e) => log::warn!("启动健康检查失败: {}", e),
+        }
+    }
+
+    /// 检查启动快照，严重告警会立即显示警告窗口
+    fn check_startup_snapshot(&mut self, snapshot: &SystemSnapshot) {
+        // 通过告警监视器检查，避免首次定时采集时重复告警
+        let alerts = self.alert_monitor.evaluate(snapshot, &self.config_manager.get().alerts);
+        for alert in alerts {
+            if alert.level == AlertLevel::Critical {
+                log::warn!("启动健康检查: {}", alert.message());
+                self.app_state.startup_warnings.push(alert.clone());
+            }
+            self.handle_message(AppMessage::Alert(alert));
+        }
+    }
+    
     /// 处理应用程序消息
     fn handle_message(&mut self, message: AppMessage) {
         match message {
-            AppMessage::SystemUpdate(snapshot) => {
+            AppMessage::SystemUpdate(mut snapshot) => {
+                // 在进入历史、统计和显示之前统一处理超过100%的CPU读数
+                if self.config_manager.get().ui.clamp_cpu_usage {
+                    snapshot.cpu.clamp_usage();
+                }
+                self.app_state.degraded = false;
+                self.record_source_success(&snapshot);
+                self.app_state.snapshot_history.push(snapshot.clone());
                 self.app_state.current_snapshot = Some(snapshot.clone());
                 self.app_state.health_status = snapshot.get_health_status();
+
+                let alerts = self.alert_monitor.evaluate(&snapshot, &self.config_manager.get().alerts);
                 self.ui_manager.update_system_data(snapshot);
+                for alert in alerts {
+                    self.handle_message(AppMessage::Alert(alert));
+                }
+            }
+            AppMessage::ConfigFileChanged => {
+                // 自身保存的配置不需要重新加载；读取失败时交由重新加载报告错误
+                if self.config_manager.matches_file().unwrap_or(false) {
+                    log::debug!("配置文件内容与当前配置一致，忽略变化");
+                } else {
+                    log::info!("检测到配置文件被外部修改，重新加载配置");
+                    self.handle_message(AppMessage::ConfigUpdate);
+                }
             }
             AppMessage::ConfigUpdate => {
                 if let Err(e) = self.reload_configuration() {
                     log::error!("重新加载配置失败: {}", e);
-                    self.app_state.last_error = Some(format!("配置更新失败: {}", e));
+                    self.app_state.record_error(format!("配置更新失败: {}", e));
                 }
             }
             AppMessage::Error(error) => {
                 log::error!("应用程序错误: {}", error);
-                self.app_state.last_error = Some(error);
+                self.app_state.record_error(error);
+            }
+            AppMessage::CollectionFailed { message, strategy } => {
+                // 采集是整体进行的，失败时所有类别都记为出错
+                let now = Instant::now();
+                for tab in TabType::all() {
+                    self.app_state.source_health.entry(tab).or_default().last_error = Some((now, message.clone()));
+                }
+                if strategy == RecoveryStrategy::Degrade {
+                    self.app_state.degraded = true;
+                }
+                // 忽略策略的错误只记录日志，不打扰用户
+                if strategy.should_notify() {
+                    self.app_state.record_error(message);
+                }
             }
             AppMessage::SwitchTab(tab) => {
                 self.app_state.active_tab = tab;
                 self.ui_manager.set_active_tab(tab);
+
+                let process_active = tab == TabType::Process;
+                let was_active = self.process_tab_active.swap(process_active, Ordering::Relaxed);
+                if process_active && !was_active {
+                    // 切换到进程标签页时立即采集，避免等待下一个周期
+                    self.collect_now.notify_one();
+                }
             }
-            AppMessage::ApplyConfig(new_config) => {
+            AppMessage::ApplyConfig(mut new_config) => {
+                // 界面持有的配置副本可能是旧的，窗口尺寸和位置以当前记录为准
+                new_config.window.copy_geometry_from(&self.config_manager.get().window);
                 if let Err(e) = self.config_manager.update(|cfg| *cfg = new_config) {
                     log::error!("更新配置失败: {}", e);
+                    self.app_state.record_error(format!("更新配置失败: {}", e));
                 } else if let Some(ref sender) = self.message_sender {
                     let _ = sender.send(AppMessage::ConfigUpdate);
                 }
@@ -192,12 +555,163 @@ impl SystemMonitorApp {
             AppMessage::HideAbout => {
                 self.app_state.show_about = false;
             }
+            AppMessage::Alert(alert) => {
+                log::warn!("告警: {}", alert.message());
+                self.notification_throttler.push(alert.clone(), Instant::now());
+                self.app_state.push_event(LogEntry::from(&alert));
+                self.app_state.alert_history.push_back(alert);
+
+                let capacity = self.config_manager.get().alerts.history_size;
+                while self.app_state.alert_history.len() > capacity {
+                    self.app_state.alert_history.pop_front();
+                }
+            }
+            AppMessage::ClearAlerts => {
+                self.app_state.alert_history.clear();
+            }
+            AppMessage::ShowAlertHistory => {
+                self.app_state.show_alert_history = true;
+            }
+            AppMessage::HideAlertHistory => {
+                self.app_state.show_alert_history = false;
+            }
+            AppMessage::ShowEventLog => {
+                self.app_state.show_event_log = true;
+            }
+            AppMessage::HideEventLog => {
+                self.app_state.show_event_log = false;
+            }
+            AppMessage::ClearEventLog => {
+                self.app_state.event_log.clear();
+            }
+            AppMessage::DismissNotification => {
+                self.app_state.notification = None;
+            }
+            AppMessage::SelectHost(name) => {
+                self.select_host(name);
+            }
+            AppMessage::ResetStatistics => {
+                self.ui_manager.reset_statistics();
+                log::info!("已重置历史统计");
+            }
+            AppMessage::FreezeDisplay => {
+                if let Some(ref snapshot) = self.app_state.current_snapshot {
+                    self.app_state.frozen_snapshot = Some(FrozenSnapshot {
+                        snapshot: snapshot.clone(),
+                        frozen_at: Instant::now(),
+                    });
+                }
+            }
+            AppMessage::UnfreezeDisplay => {
+                self.app_state.frozen_snapshot = None;
+            }
+            AppMessage::ExportProcesses { path, processes } => {
+                let visible: Vec<&ProcessInfo> = processes.iter().collect();
+
+                match export::export_processes_csv(&path, &visible) {
+                    Ok(()) => log::info!("已导出 {} 个进程到 {}", visible.len(), path.display()),
+                    Err(e) => {
+                        log::error!("导出进程列表失败: {}", e);
+                        self.app_state.record_error(format!("{}: {}", t("导出进程列表失败"), e));
+                    }
+                }
+            }
+            AppMessage::ExportHistory(path) => {
+                // 快照历史已包含最新快照，历史为空时只写入表头
+                let snapshots: Vec<SystemSnapshot> = self.app_state.snapshot_history.iter().cloned().collect();
+                match export::export_csv(&path, &snapshots) {
+                    Ok(()) => log::info!("已导出 {} 个快照到 {}", snapshots.len(), path.display()),
+                    Err(e) => {
+                        log::error!("导出CSV失败: {}", e);
+                        self.app_state.record_error(format!("{}: {}", t("导出CSV失败"), e));
+                    }
+                }
+            }
+            AppMessage::KillProcesses(targets) => {
+                let report = process::kill_processes(&targets);
+                log::info!(
+                    "结束进程: 成功 {} 个，已退出 {} 个，失败 {} 个",
+                    report.killed.len(),
+                    report.exited.len(),
+                    report.failed.len()
+                );
+                if !report.failed.is_empty() {
+                    let pids: Vec<String> = report.failed.iter().map(u32::to_string).collect();
+                    self.app_state.record_error(format!(
+                        "无法结束 {} 个进程（可能权限不足）: {}",
+                        report.failed.len(),
+                        pids.join(", ")
+                    ));
+                }
+                // 立即采集一次，让进程列表尽快反映结果
+                self.collect_now.notify_one();
+            }
+            AppMessage::RefreshNow => {
+                self.request_collection();
+            }
+            AppMessage::RefreshConnections => {
+                if let (Some(system_manager), Some(sender)) = (self.system_manager.clone(), self.message_sender.clone()) {
+                    let error_recovery = self.error_recovery.clone();
+                    tokio::spawn(async move {
+                        let message = match system_manager.get_connections_async().await {
+                            Ok(connections) => AppMessage::ConnectionsUpdate(connections),
+                            Err(e) => {
+                                if !error_recovery.strategy_for(&e, "连接采集").should_notify() {
+                                    return;
+                                }
+                                AppMessage::Error(format!("获取网络连接失败: {}", e))
+                            }
+                        };
+                        let _ = sender.send(message);
+                    });
+                }
+            }
+            AppMessage::ConnectionsUpdate(connections) => {
+                self.app_state.connections = Some(connections);
+            }
+            AppMessage::ExportSnapshot { path, with_stats } => {
+                let Some(snapshot) = self.app_state.current_snapshot.clone() else {
+                    self.app_state.record_error(format!("{}: {}", t("导出快照失败"), t("暂无快照数据")));
+                    return;
+                };
+
+                let result = if with_stats {
+                    let report = export::SnapshotReport::new(snapshot, self.ui_manager.metrics(), self.config_manager.get());
+                    export::export_json(&path, &report)
+                } else {
+                    export::export_json(&path, &snapshot)
+                };
+                match result {
+                    Ok(()) => log::info!("已导出快照到 {}", path.display()),
+                    Err(e) => {
+                        log::error!("导出快照失败: {}", e);
+                        self.app_state.record_error(format!("{}: {}", t("导出快照失败"), e));
+                    }
+                }
+            }
             AppMessage::Exit => {
                 self.app_state.is_running = false;
             }
         }
     }
     
+    /// 记录快照中包含的数据类别采集成功
+    fn record_source_success(&mut self, snapshot: &SystemSnapshot) {
+        let now = Instant::now();
+        for tab in TabType::all() {
+            // 进程和网络可能因配置或当前标签页未采集，这些类别保持原有状态直至过期
+            let collected = match tab {
+                TabType::Process => snapshot.processes.is_some(),
+                TabType::Network => snapshot.networks.is_some(),
+                TabType::Gpu => snapshot.gpus.is_some(),
+                _ => true,
+            };
+            if collected {
+                self.app_state.source_health.entry(tab).or_default().last_success = Some(now);
+            }
+        }
+    }
+
     /// 重新加载配置
     fn reload_configuration(&mut self) -> Result<()> {
         // 重新加载配置管理器
@@ -206,6 +720,24 @@ impl SystemMonitorApp {
         // 重新初始化系统管理器（如果配置发生变化）
         self.initialize_system_manager()?;
         
+        // 按新配置调整快照历史限制
+        let monitoring = &self.config_manager.get().monitoring;
+        self.app_state.snapshot_history.set_limits_mb(
+            monitoring.snapshot_history_size,
+            monitoring.snapshot_history_max_mb,
+        );
+
+        self.notification_throttler.configure(&self.config_manager.get().alerts);
+
+        // 后台采集任务在下一次采集时使用新的监控配置
+        if let Ok(mut shared) = self.monitoring_config.write() {
+            *shared = monitoring.clone();
+        }
+        self.refresh_interval.send_if_modified(|interval| {
+            let new_interval = Duration::from_millis(monitoring.refresh_interval_ms);
+            std::mem::replace(interval, new_interval) != new_interval
+        });
+        
         // 更新UI管理器配置
         let config = Arc::new(self.config_manager.get().clone());
         self.ui_manager.update_config(config)?;
@@ -214,41 +746,229 @@ impl SystemMonitorApp {
         Ok(())
     }
     
+    /// 切换查看的主机，清空上一台主机的数据后立即采集一次
+    fn select_host(&mut self, name: Option<String>) {
+        let host = name.as_ref().and_then(|name| {
+            self.config_manager.get().remote_hosts.iter().find(|host| &host.name == name).cloned()
+        });
+        if name.is_some() && host.is_none() {
+            self.app_state.record_error(format!("未找到远程主机: {}", name.unwrap_or_default()));
+            return;
+        }
+
+        let source = host.map(RemoteSource::new);
+        if let Ok(mut remote_source) = self.remote_source.write() {
+            *remote_source = source.clone();
+        }
+        self.start_remote_collector(source);
+        log::info!("切换查看主机: {}", name.as_deref().unwrap_or("本机"));
+
+        self.app_state.selected_host = name;
+        self.app_state.current_snapshot = None;
+        self.app_state.frozen_snapshot = None;
+        self.app_state.snapshot_history.clear();
+        self.app_state.degraded = false;
+        self.app_state.source_health.clear();
+        self.alert_monitor = AlertMonitor::new();
+        self.ui_manager.reset_statistics();
+        // 查看本机时立即采集一次，远程采集任务启动后会自行立即采集
+        self.collect_now.notify_one();
+    }
+
+    /// 请求立即采集一次，查看远程主机时由远程采集任务响应
+    fn request_collection(&self) {
+        if self.remote_cancellation.is_some() {
+            self.remote_collect_now.notify_one();
+        } else {
+            self.collect_now.notify_one();
+        }
+    }
+
+    /// 停止上一台远程主机的采集任务，并为 `source` 启动新的采集任务
+    ///
+    /// SSH采集最长需要等待超时，因此在独立的任务中按采集间隔进行，不阻塞本机采集和界面；
+    /// 上一次采集完成后才开始计时，连接缓慢时不会堆积请求。
+    fn start_remote_collector(&mut self, source: Option<RemoteSource>) {
+        if let Some(cancellation) = self.remote_cancellation.take() {
+            cancellation.cancel();
+        }
+        let (Some(source), Some(sender)) = (source, self.message_sender.clone()) else {
+            return;
+        };
+
+        let cancellation = self.cancellation_token.child_token();
+        self.remote_cancellation = Some(cancellation.clone());
+        let mut interval_rx = self.refresh_interval.subscribe();
+        let trigger = self.remote_collect_now.clone();
+        let egui_ctx = self.egui_ctx.clone();
+        tokio::spawn(async move {
+            loop {
+                // 连接失败只降级显示
+                let message = tokio::select! {
+                    _ = cancellation.cancelled() => break,
+                    result = source.snapshot(SnapshotRequest::default()) => match result {
+                        Ok(snapshot) => AppMessage::SystemUpdate(snapshot),
+                        Err(e) => AppMessage::CollectionFailed {
+                            strategy: RecoveryStrategy::Degrade,
+                            message: format!("远程主机 {} 不可达: {}", source.host().name, e),
+                        },
+                    },
+                };
+                if sender.send(message).is_err() {
+                    break; // Channel closed
+                }
+                egui_ctx.request_repaint();
+
+                let period = *interval_rx.borrow_and_update();
+                tokio::select! {
+                    _ = cancellation.cancelled() => break,
+                    _ = tokio::time::sleep(period) => {}
+                    _ = trigger.notified() => {}
+                }
+            }
+        });
+    }
+
     /// 启动后台数据采集任务
     fn start_background_collector(&mut self) {
         if let (Some(system_manager), Some(sender)) = (self.system_manager.as_ref(), self.message_sender.as_ref()) {
-            let system_manager = system_manager.clone();
             let sender = sender.clone();
-            let config = self.config_manager.get().clone();
-            let token = self.cancellation_token.clone();
+            let error_recovery = self.error_recovery.clone();
+            let monitoring = &self.config_manager.get().monitoring;
+            let monitoring_config = self.monitoring_config.clone();
+            let process_tab_active = self.process_tab_active.clone();
+            let remote_source = self.remote_source.clone();
+            let window_minimized = self.window_minimized.clone();
+            let egui_ctx = self.egui_ctx.clone();
+            #[cfg(feature = "prometheus")]
+            let metrics_snapshot = self.start_metrics_server();
+            // 快速启动时首次仅采集摘要信息，尽快完成首次绘制
+            let summary_pending = AtomicBool::new(monitoring.fast_start);
+
+            // 每次采集前读取最新的监控配置，禁用的类别不采集；
+            // 进程采集开销较大，按采集模式决定是否进行；查看远程主机时不采集本机
+            let mut snapshots = system_manager.snapshot_stream_with(
+                self.refresh_interval.subscribe(),
+                self.cancellation_token.clone(),
+                Some(self.collect_now.clone()),
+                move || {
+                    if remote_source.read().map(|source| source.is_some()).unwrap_or(false) {
+                        return None;
+                    }
+                    if summary_pending.swap(false, Ordering::Relaxed) {
+                        return Some(SnapshotRequest::summary());
+                    }
+                    let process_tab_active = process_tab_active.load(Ordering::Relaxed);
+                    let minimized = window_minimized.load(Ordering::Relaxed);
+                    let request = monitoring_config.read()
+                        .map(|monitoring| {
+                            let request = SnapshotRequest::from_monitoring(&monitoring, process_tab_active);
+                            if minimized && monitoring.suspend_when_minimized {
+                                request.minimized()
+                            } else {
+                                request
+                            }
+                        })
+                        .unwrap_or_default();
+                    Some(request)
+                },
+            );
+
+            // 传感器按独立的慢速间隔采样，快照带上最近一次的结果；
+            // GPU查询需要启动 nvidia-smi 进程，同样按传感器间隔采样
+            let sensor_config = self.monitoring_config.clone();
+            let gpu_config = self.monitoring_config.clone();
+            system_manager.sample_sensors_with(
+                self.cancellation_token.clone(),
+                move || {
+                    let interval_ms = sensor_config.read()
+                        .map(|monitoring| monitoring.sensor_interval_ms)
+                        .unwrap_or_else(|_| MonitoringConfig::default().sensor_interval_ms);
+                    Duration::from_millis(interval_ms)
+                },
+                move || gpu_config.read()
+                    .map(|monitoring| monitoring.enable_gpu_monitoring)
+                    .unwrap_or(false),
+            );
 
             tokio::spawn(async move {
-                let mut interval = tokio::time::interval(Duration::from_millis(config.monitoring.refresh_interval_ms));
-                loop {
-                    tokio::select! {
-                        _ = token.cancelled() => {
-                            break;
-                        }
-                        _ = interval.tick() => {
-                            match system_manager.get_snapshot().await {
-                                Ok(snapshot) => {
-                                    if sender.send(AppMessage::SystemUpdate(snapshot)).is_err() {
-                                        break; // Channel closed
-                                    }
-                                },
-                                Err(e) => {
-                                    if sender.send(AppMessage::Error(format!("数据采集失败: {}", e))).is_err() {
-                                        break; // Channel closed
-                                    }
-                                }
+                while let Some(result) = snapshots.recv().await {
+                    let message = match result {
+                        Ok(snapshot) => {
+                            // 指标端点只提供本机数据
+                            #[cfg(feature = "prometheus")]
+                            if let Some(Ok(mut latest)) = metrics_snapshot.as_ref().map(|shared| shared.write()) {
+                                *latest = Some(snapshot.clone());
                             }
+                            AppMessage::SystemUpdate(snapshot)
                         }
+                        Err(e) => AppMessage::CollectionFailed {
+                            strategy: error_recovery.strategy_for(&e, "数据采集"),
+                            message: format!("数据采集失败: {}", e),
+                        },
+                    };
+                    if sender.send(message).is_err() {
+                        break; // Channel closed
                     }
+                    egui_ctx.request_repaint();
                 }
             });
         }
     }
     
+    /// 按配置启动Prometheus指标端点，返回与端点共享的最新快照
+    #[cfg(feature = "prometheus")]
+    fn start_metrics_server(&self) -> Option<crate::system::prometheus::SharedSnapshot> {
+        let port = self.config_manager.get().monitoring.metrics_port?;
+        let shared: crate::system::prometheus::SharedSnapshot = Arc::new(RwLock::new(None));
+        let snapshot = shared.clone();
+        let cancellation_token = self.cancellation_token.clone();
+        let sender = self.message_sender.clone();
+        tokio::spawn(async move {
+            if let Err(e) = crate::system::prometheus::serve(port, snapshot, cancellation_token).await {
+                log::error!("Prometheus指标端点启动失败: {}", e);
+                if let Some(sender) = sender {
+                    let _ = sender.send(AppMessage::Error(format!("指标端点启动失败（端口 {}）: {}", port, e)));
+                }
+            }
+        });
+        Some(shared)
+    }
+
+    /// 启动配置文件监视任务，文件变化时发送 [`AppMessage::ConfigFileChanged`]
+    ///
+    /// 重新加载失败（解析失败或未通过验证）时保留当前配置，错误显示在界面上。
+    fn start_config_watcher(&self) {
+        let Some(sender) = self.message_sender.clone() else {
+            return;
+        };
+        let mut watcher = match ConfigWatcher::for_app() {
+            Ok(watcher) => watcher,
+            Err(e) => {
+                log::warn!("无法监视配置文件: {}", e);
+                return;
+            }
+        };
+        let cancellation_token = self.cancellation_token.clone();
+        let egui_ctx = self.egui_ctx.clone();
+
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(CONFIG_POLL_INTERVAL);
+            loop {
+                tokio::select! {
+                    _ = cancellation_token.cancelled() => break,
+                    _ = interval.tick() => {}
+                }
+                if watcher.poll(Instant::now()) {
+                    if sender.send(AppMessage::ConfigFileChanged).is_err() {
+                        break;
+                    }
+                    egui_ctx.request_repaint();
+                }
+            }
+        });
+    }
+
     /// 处理待处理的消息
     fn process_messages(&mut self) {
         let mut messages = Vec::new();
@@ -262,13 +982,45 @@ impl SystemMonitorApp {
             self.handle_message(message);
         }
     }
+
+    /// 从视口读取当前窗口尺寸和位置写入配置，最小化时的尺寸无意义，不记录
+    ///
+    /// 只在保存配置和关闭窗口时调用，避免每帧修改配置。
+    fn record_window_geometry(&mut self) {
+        let geometry = self.egui_ctx.input(|i| {
+            let viewport = i.viewport();
+            if viewport.minimized.unwrap_or(false) {
+                return None;
+            }
+            let rect = viewport.inner_rect?;
+            let position = viewport.outer_rect.map(|rect| rect.min.into());
+            Some((rect.size().into(), position, viewport.maximized.unwrap_or(false)))
+        });
+        if let Some((size, position, maximized)) = geometry {
+            self.config_manager.get_mut().window.record_geometry(size, position, maximized);
+        }
+    }
     
 }
 
 impl eframe::App for SystemMonitorApp {
     fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
         // 不再调用 self.handle_periodic_update();
+        let minimized = ctx.input(|i| i.viewport().minimized.unwrap_or(false));
+        self.window_minimized.store(minimized, Ordering::Relaxed);
+        // 关闭窗口前记录最终的窗口尺寸和位置，退出时随配置保存
+        if ctx.input(|i| i.viewport().close_requested()) {
+            self.record_window_geometry();
+        }
         self.process_messages();
+
+        // 聚合窗口结束后显示合并的告警通知
+        if let Some(notification) = self.notification_throttler.poll(Instant::now()) {
+            self.app_state.notification = Some(notification);
+        }
+        if self.notification_throttler.pending_len() > 0 {
+            ctx.request_repaint_after(Duration::from_secs(1));
+        }
         
         // 将 AppState 和 message_sender 传递给 UiManager
         // UiManager 现在负责所有渲染
@@ -278,7 +1030,8 @@ impl eframe::App for SystemMonitorApp {
     }
     
     fn save(&mut self, _storage: &mut dyn eframe::Storage) {
-        // 保存应用程序状态到存储
+        // 保存应用程序状态（包括窗口尺寸和位置）到配置文件
+        self.record_window_geometry();
         if let Err(e) = self.config_manager.save() {
             log::error!("保存配置失败: {}", e);
         }
@@ -286,6 +1039,7 @@ impl eframe::App for SystemMonitorApp {
 
     fn on_exit(&mut self, _gl: Option<&eframe::glow::Context>) {
         self.cancellation_token.cancel();
+        self.ui_manager.save_history();
     }
 }
 #[cfg(test)]
@@ -308,11 +1062,22 @@ mod tests {
             system_manager: None,
             ui_manager: UiManager::new(&egui::Context::default(), Arc::new(AppConfig::default())).unwrap(),
             error_recovery: ErrorRecovery::default(),
+            alert_monitor: AlertMonitor::new(),
+            notification_throttler: NotificationThrottler::from_config(&AppConfig::default().alerts),
             app_state: default_app_state(),
             last_update: Instant::now(),
             message_sender: Some(tx),
             message_receiver: Some(rx),
             cancellation_token: tokio_util::sync::CancellationToken::new(),
+            process_tab_active: Arc::new(AtomicBool::new(false)),
+            collect_now: Arc::new(Notify::new()),
+            refresh_interval: watch::Sender::new(Duration::from_millis(MonitoringConfig::default().refresh_interval_ms)),
+            monitoring_config: Arc::new(RwLock::new(MonitoringConfig::default())),
+            window_minimized: Arc::new(AtomicBool::new(false)),
+            egui_ctx: egui::Context::default(),
+            remote_source: Arc::new(RwLock::new(None)),
+            remote_collect_now: Arc::new(Notify::new()),
+            remote_cancellation: None,
         }
     }
 
@@ -330,6 +1095,7 @@ mod tests {
         app.handle_message(message);
 
         assert!(app.app_state.current_snapshot.is_some());
+        assert_eq!(app.app_state.snapshot_history.len(), 1);
         if let Some(snap) = app.app_state.current_snapshot {
             assert_eq!(snap.cpu, snapshot.cpu);
         }
@@ -344,6 +1110,102 @@ mod tests {
         assert_eq!(app.app_state.active_tab, TabType::Process);
     }
 
+    #[tokio::test]
+    async fn test_switch_to_process_tab_triggers_collection() {
+        let mut app = test_app();
+        assert!(!app.process_tab_active.load(Ordering::Relaxed));
+
+        app.handle_message(AppMessage::SwitchTab(TabType::Process));
+        assert!(app.process_tab_active.load(Ordering::Relaxed));
+        let notified = tokio::time::timeout(Duration::from_millis(100), app.collect_now.notified()).await;
+        assert!(notified.is_ok(), "切换到进程标签页应立即触发采集");
+
+        app.handle_message(AppMessage::SwitchTab(TabType::Overview));
+        assert!(!app.process_tab_active.load(Ordering::Relaxed));
+    }
+
+    #[test]
+    fn test_handle_message_freeze_display() {
+        let mut app = test_app();
+        let snapshot = |usage: f32| SystemSnapshot::new(
+            CpuInfo { global_usage: usage, ..Default::default() },
+            MemoryInfo::default(),
+            vec![],
+            SystemInfo::default(),
+            None,
+        );
+
+        // 没有数据时无法冻结
+        app.handle_message(AppMessage::FreezeDisplay);
+        assert!(app.app_state.frozen_snapshot.is_none());
+
+        app.handle_message(AppMessage::SystemUpdate(snapshot(10.0)));
+        app.handle_message(AppMessage::FreezeDisplay);
+        app.handle_message(AppMessage::SystemUpdate(snapshot(20.0)));
+
+        // 冻结后继续采集，但冻结的快照保持不变
+        let frozen = app.app_state.frozen_snapshot.as_ref().unwrap();
+        assert_eq!(frozen.snapshot.cpu.global_usage, 10.0);
+        assert_eq!(app.app_state.current_snapshot.as_ref().unwrap().cpu.global_usage, 20.0);
+
+        app.handle_message(AppMessage::UnfreezeDisplay);
+        assert!(app.app_state.frozen_snapshot.is_none());
+    }
+
+    #[test]
+    fn test_handle_message_collection_failed() {
+        let mut app = test_app();
+
+        app.handle_message(AppMessage::CollectionFailed {
+            message: "忽略".to_string(),
+            strategy: RecoveryStrategy::Ignore,
+        });
+        assert!(app.app_state.last_error.is_none());
+        assert!(!app.app_state.degraded);
+
+        app.handle_message(AppMessage::CollectionFailed {
+            message: "降级".to_string(),
+            strategy: RecoveryStrategy::Degrade,
+        });
+        assert_eq!(app.app_state.last_error.as_deref(), Some("降级"));
+        assert!(app.app_state.degraded);
+
+        let snapshot = SystemSnapshot::new(CpuInfo::default(), MemoryInfo::default(), vec![], SystemInfo::default(), None);
+        app.handle_message(AppMessage::SystemUpdate(snapshot));
+        assert!(!app.app_state.degraded);
+    }
+
+    #[test]
+    fn test_source_health_tracks_categories() {
+        let mut app = test_app();
+        let stale_after = Duration::from_secs(10);
+        let status = |app: &SystemMonitorApp, tab: TabType| {
+            app.app_state.source_health.get(&tab).cloned().unwrap_or_default().status(Instant::now(), stale_after)
+        };
+        assert_eq!(status(&app, TabType::Cpu), SourceStatus::Stale);
+
+        // 未采集进程时进程类别保持过期
+        let snapshot = SystemSnapshot::new(CpuInfo::default(), MemoryInfo::default(), vec![], SystemInfo::default(), None);
+        app.handle_message(AppMessage::SystemUpdate(snapshot.clone()));
+        assert_eq!(status(&app, TabType::Cpu), SourceStatus::Fresh);
+        assert_eq!(status(&app, TabType::Process), SourceStatus::Stale);
+
+        app.handle_message(AppMessage::CollectionFailed {
+            message: "采集失败".to_string(),
+            strategy: RecoveryStrategy::Degrade,
+        });
+        assert_eq!(status(&app, TabType::Cpu), SourceStatus::Error);
+
+        app.handle_message(AppMessage::SystemUpdate(snapshot));
+        assert_eq!(status(&app, TabType::Cpu), SourceStatus::Fresh);
+
+        let old = SourceHealth {
+            last_success: Some(Instant::now()),
+            last_error: None,
+        };
+        assert_eq!(old.status(Instant::now() + Duration::from_secs(11), stale_after), SourceStatus::Stale);
+    }
+
     #[test]
     fn test_handle_message_show_hide_settings() {
         let mut app = test_app();
@@ -360,6 +1222,159 @@ mod tests {
         assert!(!app.app_state.show_settings);
     }
 
+    #[test]
+    fn test_handle_message_alert_history_is_capped() {
+        let mut app = test_app();
+        app.config_manager.get_mut().alerts.history_size = 2;
+
+        let snapshot = SystemSnapshot::new(
+            CpuInfo { global_usage: 99.0, ..Default::default() },
+            MemoryInfo::default(),
+            vec![],
+            SystemInfo::default(),
+            None,
+        );
+        app.handle_message(AppMessage::SystemUpdate(snapshot));
+        assert_eq!(app.app_state.alert_history.len(), 1);
+
+        let alert = app.app_state.alert_history[0].clone();
+        for _ in 0..3 {
+            app.handle_message(AppMessage::Alert(alert.clone()));
+        }
+        assert_eq!(app.app_state.alert_history.len(), 2);
+
+        app.handle_message(AppMessage::ClearAlerts);
+        assert!(app.app_state.alert_history.is_empty());
+    }
+
+    #[test]
+    fn test_event_log_records_errors_and_alerts() {
+        let mut app = test_app();
+        app.handle_message(AppMessage::Error("采集失败".to_string()));
+        let snapshot = SystemSnapshot::new(
+            CpuInfo { global_usage: 99.0, ..Default::default() },
+            MemoryInfo::default(),
+            vec![],
+            SystemInfo::default(),
+            None,
+        );
+        app.handle_message(AppMessage::SystemUpdate(snapshot));
+
+        let log = &app.app_state.event_log;
+        assert_eq!(log.len(), 2);
+        assert_eq!((log[0].level, log[0].message.as_str()), (LogLevel::Error, "采集失败"));
+        assert_eq!(log[1].level, LogLevel::Critical);
+        assert_eq!(app.app_state.last_error.as_deref(), Some("采集失败"));
+
+        // 超出容量时丢弃最早的条目
+        for i in 0..EVENT_LOG_CAPACITY {
+            app.app_state.record_error(format!("错误 {}", i));
+        }
+        assert_eq!(app.app_state.event_log.len(), EVENT_LOG_CAPACITY);
+        assert_eq!(app.app_state.event_log[0].message, "错误 0");
+
+        app.handle_message(AppMessage::ClearEventLog);
+        assert!(app.app_state.event_log.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_minimized_collection_still_alerts() {
+        let mut app = test_app();
+        let manager = SystemInfoManager::new().unwrap();
+
+        let mut snapshot = manager.get_snapshot_with(SnapshotRequest::default().minimized()).await.unwrap();
+        assert!(snapshot.disks.is_empty());
+        assert!(snapshot.processes.is_none());
+
+        snapshot.cpu.global_usage = 99.0;
+        app.handle_message(AppMessage::SystemUpdate(snapshot));
+        assert_eq!(app.app_state.alert_history.len(), 1);
+    }
+
+    #[test]
+    fn test_startup_health_check_reports_critical_only() {
+        let mut app = test_app();
+        let snapshot = SystemSnapshot::new(
+            CpuInfo::default(),
+            MemoryInfo { usage_percent: 97.0, ..Default::default() },
+            vec![
+                DiskInfo { mount_point: "/".to_string(), usage_percent: 92.0, ..Default::default() },
+                DiskInfo { mount_point: "/data".to_string(), usage_percent: 99.0, ..Default::default() },
+            ],
+            SystemInfo::default(),
+            None,
+        );
+        app.check_startup_snapshot(&snapshot);
+
+        let sources: Vec<&str> = app.app_state.startup_warnings.iter().map(|a| a.source.as_str()).collect();
+        assert_eq!(sources, vec!["内存", "/data"]);
+        assert_eq!(app.app_state.alert_history.len(), 3);
+
+        // 首次定时采集不应重复告警
+        app.handle_message(AppMessage::SystemUpdate(snapshot));
+        assert_eq!(app.app_state.alert_history.len(), 3);
+    }
+
+    #[test]
+    fn test_handle_message_export_history() {
+        let mut app = test_app();
+        let path = std::env::temp_dir().join(format!("system-monitor-history-test-{}.csv", std::process::id()));
+
+        // 没有快照历史时只写入表头
+        app.handle_message(AppMessage::ExportHistory(path.clone()));
+        assert_eq!(std::fs::read_to_string(&path).unwrap().lines().count(), 1);
+
+        let snapshot = SystemSnapshot::new(CpuInfo::default(), MemoryInfo::default(), vec![], SystemInfo::default(), None);
+        app.handle_message(AppMessage::SystemUpdate(snapshot.clone()));
+        app.handle_message(AppMessage::SystemUpdate(snapshot));
+        app.handle_message(AppMessage::ExportHistory(path.clone()));
+        assert_eq!(std::fs::read_to_string(&path).unwrap().lines().count(), 3);
+        assert!(app.app_state.last_error.is_none());
+
+        // 写入失败时记录错误而不是崩溃
+        app.handle_message(AppMessage::ExportHistory(std::env::temp_dir()));
+        assert!(app.app_state.last_error.as_deref().is_some_and(|error| error.starts_with("导出CSV失败")));
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_handle_message_export_processes() {
+        let mut app = test_app();
+        let path = std::env::temp_dir().join(format!("system-monitor-test-{}.csv", std::process::id()));
+
+        // 没有进程数据时只写入表头
+        app.handle_message(AppMessage::ExportProcesses { path: path.clone(), processes: Vec::new() });
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), format!("{}\n", export::PROCESS_CSV_HEADER));
+
+        let process = |pid: u32, name: &str, cpu_usage: f32| crate::system::info::ProcessInfo {
+            pid,
+            name: name.to_string(),
+            cpu_usage,
+            memory_usage: 1024,
+            status: "Run".to_string(),
+            start_time: None,
+            run_time: None,
+            name_lossy: false,
+            user: None,
+            exe_path: None,
+            cmd: Vec::new(),
+        };
+        let snapshot = SystemSnapshot::new(CpuInfo::default(), MemoryInfo::default(), vec![], SystemInfo::default(), None)
+            .with_processes(vec![process(1, "init", 1.0), process(2, "sshd", 5.0), process(3, "bash", 3.0)]);
+
+        // 按进程表格的顺序导出（默认按CPU降序）
+        let mut renderer = crate::ui::ProcessTabRenderer::new();
+        let processes = crate::ui::TabRenderer::visible_processes(&mut renderer, &snapshot).unwrap();
+        app.handle_message(AppMessage::ExportProcesses { path: path.clone(), processes });
+        let contents = std::fs::read_to_string(&path).unwrap();
+        let pids: Vec<&str> = contents.lines().skip(1).map(|line| line.split(',').next().unwrap()).collect();
+        assert_eq!(pids, vec!["2", "3", "1"]);
+        assert!(app.app_state.last_error.is_none());
+
+        let _ = std::fs::remove_file(&path);
+    }
+
     #[test]
     fn test_handle_message_exit() {
         let mut app = test_app();
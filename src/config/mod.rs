@@ -3,12 +3,18 @@
 //! 负责应用程序配置的加载、保存和管理，支持用户自定义设置。
 
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant, SystemTime};
 use crate::error::{Result, SystemMonitorError};
+use crate::system::DiskInfo;
+use crate::system::process::ProcessSortKey;
+use crate::utils::ValidationUtils;
 
 /// 应用程序配置
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[derive(Default)]
+#[serde(default)]
 pub struct AppConfig {
     /// 窗口配置
     pub window: WindowConfig,
@@ -18,10 +24,17 @@ pub struct AppConfig {
     pub ui: UiConfig,
     /// 性能配置
     pub performance: PerformanceConfig,
+    /// 告警配置
+    pub alerts: AlertConfig,
+    /// 导出配置
+    pub export: ExportConfig,
+    /// 可切换查看的远程主机
+    pub remote_hosts: Vec<RemoteHost>,
 }
 
 /// 窗口配置
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct WindowConfig {
     /// 窗口宽度
     pub width: f32,
@@ -31,10 +44,15 @@ pub struct WindowConfig {
     pub maximized: bool,
     /// 是否置顶
     pub always_on_top: bool,
+    /// 是否使用紧凑模式（隐藏菜单栏、侧边栏和状态栏，只显示关键指标）
+    pub compact_mode: bool,
+    /// 窗口左上角在屏幕上的位置，未记录或平台不支持时为 `None`
+    pub position: Option<[f32; 2]>,
 }
 
 /// 监控配置
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct MonitoringConfig {
     /// 刷新间隔（毫秒）
     pub refresh_interval_ms: u64,
@@ -44,16 +62,87 @@ pub struct MonitoringConfig {
     pub enable_memory_monitoring: bool,
     /// 是否启用磁盘监控
     pub enable_disk_monitoring: bool,
-    /// 是否启用进程监控
-    pub enable_process_monitoring: bool,
+    /// 是否启用GPU监控（目前仅支持NVIDIA显卡）
+    pub enable_gpu_monitoring: bool,
+    /// 是否启用网络监控
+    pub enable_network_monitoring: bool,
+    /// 进程采集模式
+    pub process_collection_mode: ProcessCollectionMode,
+    /// 采集的进程数上限，0表示不限制
+    pub process_list_limit: usize,
+    /// 按此字段选取采集的进程（CPU、内存取最大的，PID、名称按升序）
+    pub process_sort_by: ProcessSortKey,
     /// CPU历史数据点数量
     pub cpu_history_points: usize,
     /// 内存历史数据点数量
     pub memory_history_points: usize,
+    /// 保留的完整快照数量
+    pub snapshot_history_size: usize,
+    /// 快照历史的内存预算（MB）
+    pub snapshot_history_max_mb: usize,
+    /// 快速启动：首次仅采集CPU和内存，界面显示后再采集其他数据
+    pub fast_start: bool,
+    /// 窗口最小化时暂停磁盘和进程采集
+    ///
+    /// CPU和内存仍按刷新间隔采集，保证最小化期间告警照常触发；
+    /// 磁盘告警在窗口恢复后的首次采集时才会检查。
+    pub suspend_when_minimized: bool,
+    /// 温度传感器采样间隔（毫秒），传感器读取较慢，独立于刷新间隔
+    pub sensor_interval_ms: u64,
+    /// 退出时保存CPU和内存历史，下次启动时恢复
+    pub persist_history: bool,
+    /// Prometheus指标端口，`None` 表示不提供（需启用 `prometheus` 特性，修改后重启生效）
+    pub metrics_port: Option<u16>,
+    /// 磁盘过滤，隐藏虚拟文件系统和可移动磁盘
+    pub disk_filter: DiskFilter,
+}
+
+/// 磁盘过滤设置
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DiskFilter {
+    /// 隐藏的文件系统类型（不区分大小写），如 `tmpfs`、`overlay`、`squashfs`
+    pub hidden_file_systems: Vec<String>,
+    /// 仅显示物理磁盘：同时隐藏回环设备、网络文件系统和可移动磁盘
+    pub physical_only: bool,
+}
+
+impl Default for DiskFilter {
+    fn default() -> Self {
+        Self {
+            hidden_file_systems: ["tmpfs", "devtmpfs", "ramfs", "overlay", "squashfs", "efivarfs", "autofs", "devfs"]
+                .into_iter()
+                .map(String::from)
+                .collect(),
+            physical_only: false,
+        }
+    }
+}
+
+impl DiskFilter {
+    /// 网络文件系统类型
+    const NETWORK_FILE_SYSTEMS: [&'static str; 8] = ["nfs", "nfs4", "cifs", "smbfs", "smb3", "9p", "fuse.sshfs", "webdav"];
+
+    /// 判断磁盘是否应当显示，`removable` 为磁盘是否可移动
+    pub fn allows(&self, disk: &DiskInfo, removable: bool) -> bool {
+        let file_system = disk.file_system.as_str();
+        if self.hidden_file_systems.iter().any(|hidden| hidden.eq_ignore_ascii_case(file_system)) {
+            return false;
+        }
+        if !self.physical_only {
+            return true;
+        }
+
+        let network = Self::NETWORK_FILE_SYSTEMS.iter().any(|network| network.eq_ignore_ascii_case(file_system));
+        // Linux下物理磁盘的设备名都在 /dev 下，其他平台的名称是卷标
+        let device = !cfg!(target_os = "linux") || disk.name.starts_with("/dev/");
+        !removable && !network && device && !disk.name.starts_with("/dev/loop")
+    }
 }
 
 /// UI配置
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct UiConfig {
     /// 主题
     pub theme: Theme,
@@ -61,12 +150,147 @@ pub struct UiConfig {
     pub font_size: f32,
     /// 是否显示网格
     pub show_grid: bool,
-    /// 图表颜色配置
+    /// 图表颜色配置（暗色主题）
     pub chart_colors: ChartColors,
+    /// 图表颜色配置（亮色主题）
+    pub chart_colors_light: ChartColors,
     /// 默认标签页
     pub default_tab: String,
     /// 自定义字体路径
     pub font_path: Option<String>,
+    /// 原始字节数显示方式
+    pub raw_bytes_display: RawBytesDisplay,
+    /// 使用率变化的着色约定
+    pub usage_delta_convention: DeltaConvention,
+    /// 是否在CPU使用率旁显示等效繁忙核心数
+    pub show_cpu_busy_cores: bool,
+    /// 是否在CPU页顶部显示CPU型号和厂商
+    pub show_cpu_brand: bool,
+    /// 是否在菜单栏下方显示摘要条
+    pub show_summary_strip: bool,
+    /// 数值格式化设置
+    pub format: FormatConfig,
+    /// 界面语言
+    pub language: Language,
+    /// 网络接口别名（接口名 -> 显示名称）
+    pub network_aliases: BTreeMap<String, String>,
+    /// 是否在网络页顶部显示所有接口的总计
+    pub network_show_total: bool,
+    /// 网络总计是否包含虚拟接口（回环、容器、VPN等）
+    pub network_total_include_virtual: bool,
+    /// 显示负载预测所需的最低置信度（0-1）
+    pub prediction_min_confidence: f64,
+    /// 侧边栏摘要取平均的样本数（1 表示显示瞬时值）
+    pub summary_average_samples: usize,
+    /// 触发界面更新的最小变化量
+    pub change_threshold: ChangeThreshold,
+    /// 概览页布局
+    pub overview_layout: OverviewLayout,
+    /// 详细概览中以环形图而非进度条显示CPU和内存使用率
+    pub overview_use_gauges: bool,
+    /// 启动时间/运行时间显示方式
+    pub boot_time_display: BootTimeDisplay,
+    /// 日期时间格式（chrono strftime 语法，无效时使用默认格式）
+    pub datetime_format: String,
+    /// 图表线条宽度
+    pub chart_line_width: f32,
+    /// 图表是否绘制数据点
+    pub chart_show_points: bool,
+    /// CPU历史图表按数据范围自动缩放Y轴（默认固定0-100%）
+    pub cpu_chart_autoscale: bool,
+    /// 内存历史图表按数据范围自动缩放Y轴（默认固定0-100%）
+    pub memory_chart_autoscale: bool,
+    /// 是否在内存页显示已用/可回收/空闲的分解
+    pub show_memory_breakdown: bool,
+    /// CPU核心频率单位
+    pub core_frequency_unit: FrequencyUnit,
+    /// 空闲核心淡化显示
+    pub idle_core_dimming: IdleCoreDimming,
+    /// 磁盘页按剩余空间百分比显示和着色（剩余越少越红），默认按已用百分比
+    pub disk_show_free_space: bool,
+    /// 将CPU使用率限制在0-100%之间
+    ///
+    /// 采样时间误差可能使读数略超100%，关闭后显示原始值以便诊断。
+    pub clamp_cpu_usage: bool,
+    /// 复制当前标签页数据的快捷键（如 `Ctrl+Shift+C`，为空时禁用）
+    pub copy_tab_shortcut: String,
+    /// 是否在侧边栏标签页旁显示数据来源健康状态
+    pub show_source_health: bool,
+    /// 超过该秒数未成功采集的数据视为过期
+    pub source_stale_secs: u64,
+    /// 是否在概览页健康状态旁显示数值负载评分（0-100）
+    pub show_load_score: bool,
+    /// 负载评分的小数位数
+    pub load_score_decimals: usize,
+    /// 侧边栏宽度（拖动调整后自动保存）
+    pub sidebar_width: f32,
+    /// 进程表是否支持多选和批量结束
+    pub process_multi_select: bool,
+}
+
+/// 触发界面更新的最小变化量（百分点），为0时每次采样都更新
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct ChangeThreshold {
+    /// CPU使用率
+    pub cpu: f64,
+    /// 内存使用率
+    pub memory: f64,
+    /// 磁盘使用率
+    pub disk: f64,
+}
+
+/// 空闲核心淡化显示设置
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct IdleCoreDimming {
+    /// 是否启用
+    pub enabled: bool,
+    /// 使用率低于该值（%）的核心视为空闲
+    pub threshold: f32,
+    /// 空闲核心的不透明度（0-1）
+    pub opacity: f32,
+}
+
+impl Default for IdleCoreDimming {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            threshold: 5.0,
+            opacity: 0.4,
+        }
+    }
+}
+
+/// 频率显示单位
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum FrequencyUnit {
+    /// 根据数值自动选择
+    #[default]
+    Auto,
+    /// 固定使用MHz
+    MHz,
+    /// 固定使用GHz
+    GHz,
+}
+
+impl UiConfig {
+    /// 侧边栏宽度的允许范围
+    pub const SIDEBAR_WIDTH_RANGE: std::ops::RangeInclusive<f32> = 150.0..=300.0;
+
+    /// 获取网络接口的显示名称，未设置别名时返回原始名称
+    pub fn network_display_name<'a>(&'a self, name: &'a str) -> &'a str {
+        self.network_aliases
+            .get(name)
+            .map(String::as_str)
+            .filter(|alias| !alias.trim().is_empty())
+            .unwrap_or(name)
+    }
+
+    /// 获取当前主题对应的图表颜色
+    pub fn chart_colors_for(&self, dark_mode: bool) -> &ChartColors {
+        if dark_mode { &self.chart_colors } else { &self.chart_colors_light }
+    }
 }
 
 /// 主题配置
@@ -77,8 +301,128 @@ pub enum Theme {
     Auto,
 }
 
+/// 数值格式化配置
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FormatConfig {
+    /// 字节单位制
+    pub byte_units: ByteUnits,
+    /// 小数位数
+    pub decimals: usize,
+}
+
+impl FormatConfig {
+    /// 默认格式化配置
+    pub const DEFAULT: Self = Self {
+        byte_units: ByteUnits::Binary,
+        decimals: 1,
+    };
+}
+
+impl Default for FormatConfig {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// 界面语言
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum Language {
+    /// 中文
+    #[default]
+    Chinese,
+    /// 英文
+    English,
+}
+
+impl Language {
+    /// 所有语言
+    pub const ALL: [Language; 2] = [Language::Chinese, Language::English];
+
+    /// 语言名称（以该语言显示）
+    pub fn label(&self) -> &'static str {
+        match self {
+            Language::Chinese => "中文",
+            Language::English => "English",
+        }
+    }
+}
+
+/// 字节单位制
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum ByteUnits {
+    /// 1 KiB = 1024 B
+    #[default]
+    Binary,
+    /// 1 KB = 1000 B
+    Decimal,
+}
+
+/// 原始字节数显示方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum RawBytesDisplay {
+    /// 仅显示人类可读格式
+    #[default]
+    Off,
+    /// 在数值后内联显示精确字节数
+    Inline,
+    /// 鼠标悬停时显示精确字节数
+    Tooltip,
+}
+
+/// 进程采集模式
+///
+/// 进程采集需要遍历所有进程，是开销最大的一类采集：进程较多时每次采集
+/// 可能占用数十毫秒CPU时间。`Always` 可在不查看进程页时保留进程历史，
+/// `OnDemand` 仅在需要时付出这部分开销，`Never` 完全不采集。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum ProcessCollectionMode {
+    /// 每次采集都收集进程信息
+    Always,
+    /// 仅在进程标签页可见时收集
+    #[default]
+    OnDemand,
+    /// 从不收集
+    Never,
+}
+
+/// 概览页布局
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum OverviewLayout {
+    /// 根据窗口宽度自动选择
+    #[default]
+    Auto,
+    /// 紧凑布局：仅环形图和健康状态
+    Compact,
+    /// 详细布局：性能统计、负载预测和系统信息
+    Detailed,
+}
+
+/// 启动时间/运行时间显示方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum BootTimeDisplay {
+    /// 仅显示运行时间（相对）
+    #[default]
+    Uptime,
+    /// 仅显示启动时间（绝对）
+    BootTime,
+    /// 同时显示两者
+    Both,
+}
+
+/// 变化量着色约定（上升是好是坏取决于指标）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum DeltaConvention {
+    /// 上升为坏（如CPU、内存使用率）
+    #[default]
+    IncreaseIsBad,
+    /// 上升为好（如可用空间）
+    IncreaseIsGood,
+}
+
 /// 图表颜色配置
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct ChartColors {
     /// CPU使用率颜色
     pub cpu_color: [f32; 3],
@@ -92,6 +436,7 @@ pub struct ChartColors {
 
 /// 性能配置
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct PerformanceConfig {
     /// 是否启用缓存
     pub enable_caching: bool,
@@ -104,6 +449,68 @@ pub struct PerformanceConfig {
 }
 
 
+/// 告警配置
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AlertConfig {
+    /// 是否启用告警
+    pub enabled: bool,
+    /// CPU使用率阈值
+    pub cpu: AlertThreshold,
+    /// 内存使用率阈值
+    pub memory: AlertThreshold,
+    /// 磁盘使用率阈值
+    pub disk: AlertThreshold,
+    /// 保留的最近告警数量
+    pub history_size: usize,
+    /// 是否在启动时立即检查磁盘和内存
+    pub startup_check: bool,
+    /// 通知聚合窗口（秒），窗口内的告警合并为一条通知
+    pub notification_window_secs: u64,
+    /// 两条通知之间的最短间隔（秒）
+    pub notification_cooldown_secs: u64,
+}
+
+/// 远程主机配置
+///
+/// 通过SSH在远程主机上执行 `command`，命令需向标准输出写入一个JSON格式的系统快照。
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RemoteHost {
+    /// 显示名称
+    pub name: String,
+    /// SSH目标（如 `user@server`）
+    pub target: String,
+    /// 在远程主机上执行的采集命令
+    #[serde(default = "RemoteHost::default_command")]
+    pub command: String,
+}
+
+impl RemoteHost {
+    /// 默认的远程采集命令
+    pub fn default_command() -> String {
+        "system-monitor --once --format json".to_string()
+    }
+}
+
+/// 导出配置
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExportConfig {
+    /// 默认导出目录（未设置时使用文档目录）
+    pub directory: Option<PathBuf>,
+    /// 文件名模板，支持 {hostname}、{timestamp}、{type} 占位符
+    pub filename_template: String,
+}
+
+/// 告警阈值（百分比）
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct AlertThreshold {
+    /// 警告阈值
+    pub warning: f64,
+    /// 严重阈值
+    pub critical: f64,
+}
+
 impl Default for WindowConfig {
     fn default() -> Self {
         Self {
@@ -111,10 +518,66 @@ impl Default for WindowConfig {
             height: 800.0,
             maximized: false,
             always_on_top: false,
+            compact_mode: false,
+            position: None,
         }
     }
 }
 
+impl WindowConfig {
+    /// 常规布局的最小窗口尺寸
+    pub const MIN_SIZE: [f32; 2] = [800.0, 600.0];
+    /// 紧凑模式的最小窗口尺寸
+    pub const COMPACT_MIN_SIZE: [f32; 2] = [200.0, 100.0];
+    /// 以紧凑模式启动时的窗口尺寸
+    pub const COMPACT_SIZE: [f32; 2] = [320.0, 160.0];
+
+    /// 当前布局的最小窗口尺寸
+    pub fn min_size(&self) -> [f32; 2] {
+        if self.compact_mode {
+            Self::COMPACT_MIN_SIZE
+        } else {
+            Self::MIN_SIZE
+        }
+    }
+
+    /// 启动时的窗口尺寸，`width` 和 `height` 记录的是常规布局的尺寸
+    pub fn initial_size(&self) -> [f32; 2] {
+        if self.compact_mode {
+            Self::COMPACT_SIZE
+        } else {
+            [self.width, self.height]
+        }
+    }
+
+    /// 记录当前的窗口状态
+    ///
+    /// 最大化和紧凑模式下只记录最大化状态，保留常规布局的尺寸和位置供下次启动恢复。
+    pub fn record_geometry(&mut self, size: [f32; 2], position: Option<[f32; 2]>, maximized: bool) {
+        self.maximized = maximized;
+        if maximized || self.compact_mode {
+            return;
+        }
+
+        let [min_width, min_height] = Self::MIN_SIZE;
+        self.width = size[0].max(min_width);
+        self.height = size[1].max(min_height);
+        if position.is_some() {
+            self.position = position;
+        }
+    }
+
+    /// 从 `current` 复制窗口尺寸、位置和最大化状态，其余设置保持不变
+    ///
+    /// 界面持有的配置副本不含最近记录的窗口状态，应用这样的配置前用它保留当前的几何信息。
+    pub fn copy_geometry_from(&mut self, current: &WindowConfig) {
+        self.width = current.width;
+        self.height = current.height;
+        self.position = current.position;
+        self.maximized = current.maximized;
+    }
+}
+
 impl Default for MonitoringConfig {
     fn default() -> Self {
         Self {
@@ -122,9 +585,21 @@ impl Default for MonitoringConfig {
             enable_cpu_monitoring: true,
             enable_memory_monitoring: true,
             enable_disk_monitoring: true,
-            enable_process_monitoring: false,
+            enable_gpu_monitoring: true,
+            enable_network_monitoring: true,
+            process_collection_mode: ProcessCollectionMode::OnDemand,
+            process_list_limit: 50,
+            process_sort_by: ProcessSortKey::Cpu,
             cpu_history_points: 60,
             memory_history_points: 60,
+            snapshot_history_size: 300,
+            snapshot_history_max_mb: 32,
+            fast_start: false,
+            suspend_when_minimized: true,
+            sensor_interval_ms: 5000,
+            persist_history: true,
+            metrics_port: None,
+            disk_filter: DiskFilter::default(),
         }
     }
 }
@@ -136,8 +611,42 @@ impl Default for UiConfig {
             font_size: 14.0,
             show_grid: true,
             chart_colors: ChartColors::default(),
+            chart_colors_light: ChartColors::light(),
             default_tab: "overview".to_string(),
             font_path: Some("assets/fonts/NotoSansSC-Regular.ttf".to_string()),
+            raw_bytes_display: RawBytesDisplay::Off,
+            usage_delta_convention: DeltaConvention::IncreaseIsBad,
+            show_cpu_busy_cores: false,
+            show_cpu_brand: true,
+            show_summary_strip: false,
+            format: FormatConfig::default(),
+            language: Language::default(),
+            network_aliases: BTreeMap::new(),
+            network_show_total: true,
+            network_total_include_virtual: false,
+            prediction_min_confidence: 0.6,
+            summary_average_samples: 1,
+            change_threshold: ChangeThreshold::default(),
+            overview_layout: OverviewLayout::Auto,
+            overview_use_gauges: true,
+            boot_time_display: BootTimeDisplay::Uptime,
+            datetime_format: "%Y-%m-%d %H:%M:%S".to_string(),
+            chart_line_width: 2.0,
+            chart_show_points: true,
+            cpu_chart_autoscale: false,
+            memory_chart_autoscale: false,
+            show_memory_breakdown: true,
+            core_frequency_unit: FrequencyUnit::Auto,
+            idle_core_dimming: IdleCoreDimming::default(),
+            disk_show_free_space: false,
+            clamp_cpu_usage: true,
+            copy_tab_shortcut: "Ctrl+Shift+C".to_string(),
+            show_source_health: true,
+            source_stale_secs: 10,
+            show_load_score: false,
+            load_score_decimals: 0,
+            sidebar_width: 200.0,
+            process_multi_select: true,
         }
     }
 }
@@ -153,6 +662,26 @@ impl Default for ChartColors {
     }
 }
 
+impl ChartColors {
+    /// 适合亮色背景的默认颜色（较暗，避免在浅色背景上发白）
+    pub fn light() -> Self {
+        Self {
+            cpu_color: [0.1, 0.45, 0.7],
+            memory_color: [0.8, 0.45, 0.05],
+            disk_color: [0.15, 0.55, 0.15],
+            grid_color: [0.6, 0.6, 0.6],
+        }
+    }
+
+    /// 所有颜色分量是否都在0-1之间
+    pub fn is_valid(&self) -> bool {
+        [self.cpu_color, self.memory_color, self.disk_color, self.grid_color]
+            .iter()
+            .flatten()
+            .all(|component| (0.0..=1.0).contains(component))
+    }
+}
+
 impl Default for PerformanceConfig {
     fn default() -> Self {
         Self {
@@ -164,6 +693,65 @@ impl Default for PerformanceConfig {
     }
 }
 
+impl Default for AlertConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            cpu: AlertThreshold { warning: 80.0, critical: 95.0 },
+            memory: AlertThreshold { warning: 80.0, critical: 95.0 },
+            disk: AlertThreshold { warning: 90.0, critical: 95.0 },
+            history_size: 100,
+            startup_check: true,
+            notification_window_secs: 5,
+            notification_cooldown_secs: 60,
+        }
+    }
+}
+
+impl Default for ExportConfig {
+    fn default() -> Self {
+        Self {
+            directory: None,
+            filename_template: "{type}-{timestamp}".to_string(),
+        }
+    }
+}
+
+/// 配置文件格式，由文件扩展名决定
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigFormat {
+    /// JSON（默认）
+    Json,
+    /// TOML
+    Toml,
+}
+
+impl ConfigFormat {
+    /// 根据文件扩展名判断格式，`.toml` 以外的扩展名均按JSON处理
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("toml") => ConfigFormat::Toml,
+            _ => ConfigFormat::Json,
+        }
+    }
+
+    /// 解析配置文本
+    pub fn parse(self, content: &str) -> Result<AppConfig> {
+        Ok(match self {
+            ConfigFormat::Json => serde_json::from_str(content)?,
+            ConfigFormat::Toml => toml::from_str(content)?,
+        })
+    }
+
+    /// 序列化配置
+    pub fn serialize(self, config: &AppConfig) -> Result<String> {
+        Ok(match self {
+            ConfigFormat::Json => serde_json::to_string_pretty(config)?,
+            ConfigFormat::Toml => toml::to_string_pretty(config)?,
+        })
+    }
+}
+
 impl AppConfig {
     /// 加载配置文件
     pub fn load() -> Result<Self> {
@@ -179,14 +767,23 @@ impl AppConfig {
         let config_str = std::fs::read_to_string(&config_path)
             .map_err(|e| SystemMonitorError::Config(format!("读取配置文件失败: {}", e)))?;
 
-        let config: Self = serde_json::from_str(&config_str)
+        let config = ConfigFormat::from_path(&config_path)
+            .parse(&config_str)
             .map_err(|e| SystemMonitorError::Config(format!("解析配置文件失败: {}", e)))?;
 
         log::info!("成功加载配置文件: {:?}", config_path);
         Ok(config)
     }
 
-    /// 保存配置文件
+    /// 两份配置的内容是否相同（按序列化后的值比较）
+    pub fn same_contents(&self, other: &AppConfig) -> bool {
+        match (serde_json::to_value(self), serde_json::to_value(other)) {
+            (Ok(a), Ok(b)) => a == b,
+            _ => false,
+        }
+    }
+
+    /// 保存配置文件，格式与当前使用的配置文件一致
     pub fn save(&self) -> Result<()> {
         let config_path = Self::get_config_path()?;
         
@@ -196,7 +793,8 @@ impl AppConfig {
                 .map_err(|e| SystemMonitorError::Config(format!("创建配置目录失败: {}", e)))?;
         }
 
-        let config_str = serde_json::to_string_pretty(self)
+        let config_str = ConfigFormat::from_path(&config_path)
+            .serialize(self)
             .map_err(|e| SystemMonitorError::Config(format!("序列化配置失败: {}", e)))?;
 
         std::fs::write(&config_path, config_str)
@@ -207,11 +805,33 @@ impl AppConfig {
     }
 
     /// 获取配置文件路径
+    ///
+    /// 配置目录中存在 `config.toml` 时优先使用，否则使用默认的 `config.json`。
     fn get_config_path() -> Result<PathBuf> {
+        Ok(Self::resolve_config_path(&Self::config_dir()?))
+    }
+
+    /// 获取历史数据文件路径
+    pub fn history_path() -> Result<PathBuf> {
+        Ok(Self::config_dir()?.join("history.json"))
+    }
+
+    /// 获取配置文件所在目录
+    fn config_dir() -> Result<PathBuf> {
         let config_dir = dirs::config_dir()
             .ok_or_else(|| SystemMonitorError::Config("无法获取配置目录".to_string()))?;
-        
-        Ok(config_dir.join("system-monitor").join("config.json"))
+
+        Ok(config_dir.join("system-monitor"))
+    }
+
+    /// 在指定目录中选择配置文件
+    fn resolve_config_path(dir: &Path) -> PathBuf {
+        let toml_path = dir.join("config.toml");
+        if toml_path.exists() {
+            toml_path
+        } else {
+            dir.join("config.json")
+        }
     }
 
     /// 验证配置有效性
@@ -230,8 +850,22 @@ impl AppConfig {
             ));
         }
 
+        // 验证快照历史限制
+        if self.monitoring.snapshot_history_size == 0 || self.monitoring.snapshot_history_size > 10000 {
+            return Err(SystemMonitorError::Config(
+                "快照历史数量必须在1-10000之间".to_string()
+            ));
+        }
+
+        if self.monitoring.snapshot_history_max_mb == 0 || self.monitoring.snapshot_history_max_mb > 4096 {
+            return Err(SystemMonitorError::Config(
+                "快照历史内存预算必须在1-4096MB之间".to_string()
+            ));
+        }
+
         // 验证窗口尺寸
-        if self.window.width < 800.0 || self.window.height < 600.0 {
+        let [min_width, min_height] = WindowConfig::MIN_SIZE;
+        if self.window.width < min_width || self.window.height < min_height {
             return Err(SystemMonitorError::Config(
                 "窗口尺寸不能小于800x600".to_string()
             ));
@@ -244,6 +878,137 @@ impl AppConfig {
             ));
         }
 
+        // 验证预测置信度阈值
+        if !(0.0..=1.0).contains(&self.ui.prediction_min_confidence) {
+            return Err(SystemMonitorError::Config(
+                "预测置信度阈值必须在0-1之间".to_string()
+            ));
+        }
+
+        // 验证摘要平均窗口
+        if self.ui.summary_average_samples == 0 || self.ui.summary_average_samples > 30 {
+            return Err(SystemMonitorError::Config(
+                "摘要平均样本数必须在1-30之间".to_string()
+            ));
+        }
+
+        // 验证界面更新阈值
+        let change = &self.ui.change_threshold;
+        if [change.cpu, change.memory, change.disk].iter().any(|value| !(0.0..=10.0).contains(value)) {
+            return Err(SystemMonitorError::Config(
+                "界面更新阈值必须在0-10之间".to_string()
+            ));
+        }
+
+        // 验证空闲核心淡化设置
+        let dimming = &self.ui.idle_core_dimming;
+        if !(0.0..=100.0).contains(&dimming.threshold) || !(0.0..=1.0).contains(&dimming.opacity) {
+            return Err(SystemMonitorError::Config(
+                "空闲核心阈值必须在0-100之间，不透明度必须在0-1之间".to_string()
+            ));
+        }
+
+        // 验证图表线条宽度
+        if !(0.5..=5.0).contains(&self.ui.chart_line_width) {
+            return Err(SystemMonitorError::Config(
+                "图表线条宽度必须在0.5-5之间".to_string()
+            ));
+        }
+
+        // 验证图表颜色
+        if !self.ui.chart_colors.is_valid() || !self.ui.chart_colors_light.is_valid() {
+            return Err(SystemMonitorError::Config(
+                "图表颜色的各分量必须在0-1之间".to_string()
+            ));
+        }
+
+        if self.ui.source_stale_secs == 0 || self.ui.source_stale_secs > 3600 {
+            return Err(SystemMonitorError::Config(
+                "数据过期时间必须在1-3600秒之间".to_string()
+            ));
+        }
+
+        if !UiConfig::SIDEBAR_WIDTH_RANGE.contains(&self.ui.sidebar_width) {
+            return Err(SystemMonitorError::Config(
+                "侧边栏宽度必须在150-300之间".to_string()
+            ));
+        }
+
+        if self.ui.load_score_decimals > 2 {
+            return Err(SystemMonitorError::Config(
+                "负载评分小数位数必须在0-2之间".to_string()
+            ));
+        }
+
+        // 验证格式化配置
+        if self.ui.format.decimals > 3 {
+            return Err(SystemMonitorError::Config(
+                "小数位数必须在0-3之间".to_string()
+            ));
+        }
+
+        // 验证告警配置
+        for (name, threshold) in [
+            ("CPU", &self.alerts.cpu),
+            ("内存", &self.alerts.memory),
+            ("磁盘", &self.alerts.disk),
+        ] {
+            if !(0.0..=100.0).contains(&threshold.warning)
+                || !(0.0..=100.0).contains(&threshold.critical)
+                || threshold.warning > threshold.critical
+            {
+                return Err(SystemMonitorError::Config(format!(
+                    "{}告警阈值必须在0-100之间且警告阈值不能高于严重阈值",
+                    name
+                )));
+            }
+        }
+
+        if self.monitoring.sensor_interval_ms < 1000 || self.monitoring.sensor_interval_ms > 60000 {
+            return Err(SystemMonitorError::Config(
+                "传感器采样间隔必须在1000-60000毫秒之间".to_string()
+            ));
+        }
+
+        if self.monitoring.metrics_port.is_some_and(|port| !ValidationUtils::is_valid_port(port)) {
+            return Err(SystemMonitorError::Config(
+                "指标端口必须在1-65535之间".to_string()
+            ));
+        }
+
+        if self.alerts.history_size == 0 || self.alerts.history_size > 1000 {
+            return Err(SystemMonitorError::Config(
+                "告警历史数量必须在1-1000之间".to_string()
+            ));
+        }
+
+        if self.alerts.notification_window_secs > 300 || self.alerts.notification_cooldown_secs > 3600 {
+            return Err(SystemMonitorError::Config(
+                "通知聚合窗口不能超过300秒，冷却时间不能超过3600秒".to_string()
+            ));
+        }
+
+        for host in &self.remote_hosts {
+            if host.name.trim().is_empty() || host.target.trim().is_empty() || host.command.trim().is_empty() {
+                return Err(SystemMonitorError::Config(
+                    "远程主机的名称、SSH目标和采集命令不能为空".to_string()
+                ));
+            }
+            if host.target.trim_start().starts_with('-') {
+                return Err(SystemMonitorError::Config(
+                    format!("远程主机 {} 的SSH目标不能以 '-' 开头", host.name)
+                ));
+            }
+        }
+
+        // 验证导出文件名模板
+        let template = self.export.filename_template.trim();
+        if template.is_empty() || template.contains(['/', '\\']) {
+            return Err(SystemMonitorError::Config(
+                "导出文件名模板不能为空且不能包含路径分隔符".to_string()
+            ));
+        }
+
         Ok(())
     }
 
@@ -297,6 +1062,67 @@ impl ConfigManager {
     pub fn save(&self) -> Result<()> {
         self.config.save()
     }
+
+    /// 配置文件的内容是否与当前配置一致
+    ///
+    /// 自身保存配置也会改变文件的修改时间，据此区分自身保存和外部修改。
+    pub fn matches_file(&self) -> Result<bool> {
+        Ok(AppConfig::load()?.same_contents(&self.config))
+    }
+}
+
+/// 配置文件变化检测
+///
+/// 通过轮询文件的修改时间和大小检测外部修改。编辑器保存时常连续写入多次，
+/// 文件在防抖时间内不再变化后才报告一次变化，避免重复加载。
+#[derive(Debug)]
+pub struct ConfigWatcher {
+    dir: PathBuf,
+    debounce: Duration,
+    last_seen: Option<(PathBuf, SystemTime, u64)>,
+    changed_at: Option<Instant>,
+}
+
+impl ConfigWatcher {
+    /// 默认防抖时间
+    pub const DEFAULT_DEBOUNCE: Duration = Duration::from_millis(500);
+
+    /// 监视默认配置目录中的配置文件
+    pub fn for_app() -> Result<Self> {
+        Ok(Self::new(AppConfig::config_dir()?, Self::DEFAULT_DEBOUNCE))
+    }
+
+    /// 监视指定目录中的配置文件，`config.toml` 与 `config.json` 的选择规则与加载时一致
+    pub fn new(dir: PathBuf, debounce: Duration) -> Self {
+        let last_seen = Self::stat(&dir);
+        Self { dir, debounce, last_seen, changed_at: None }
+    }
+
+    /// 检查配置文件，文件变化且已稳定超过防抖时间时返回 `true`
+    ///
+    /// 文件被删除时不报告变化（编辑器替换文件的中间状态），重新出现后按变化处理。
+    pub fn poll(&mut self, now: Instant) -> bool {
+        let current = Self::stat(&self.dir);
+        if current != self.last_seen {
+            self.last_seen = current;
+            self.changed_at = self.last_seen.as_ref().map(|_| now);
+            return false;
+        }
+
+        match self.changed_at {
+            Some(changed_at) if now.saturating_duration_since(changed_at) >= self.debounce => {
+                self.changed_at = None;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn stat(dir: &Path) -> Option<(PathBuf, SystemTime, u64)> {
+        let path = AppConfig::resolve_config_path(dir);
+        let metadata = std::fs::metadata(&path).ok()?;
+        Some((path, metadata.modified().ok()?, metadata.len()))
+    }
 }
 
 // 添加num_cpus依赖到Cargo.toml中需要的功能
@@ -320,6 +1146,35 @@ mod tests {
 
         config.monitoring.refresh_interval_ms = 50; // 太小
         assert!(config.validate().is_err());
+
+        let mut config = AppConfig::default();
+        config.alerts.cpu = AlertThreshold { warning: 90.0, critical: 80.0 };
+        assert!(config.validate().is_err());
+
+        let mut config = AppConfig::default();
+        config.monitoring.metrics_port = Some(9100);
+        assert!(config.validate().is_ok());
+        config.monitoring.metrics_port = Some(0);
+        assert!(config.validate().is_err());
+
+        let mut config = AppConfig::default();
+        config.export.filename_template = "exports/{type}".to_string();
+        assert!(config.validate().is_err());
+
+        let mut config = AppConfig::default();
+        config.ui.sidebar_width = 400.0;
+        assert!(config.validate().is_err());
+
+        let mut config = AppConfig::default();
+        let host = |target: &str| RemoteHost {
+            name: "server".to_string(),
+            target: target.to_string(),
+            command: RemoteHost::default_command(),
+        };
+        config.remote_hosts = vec![host("user@server")];
+        assert!(config.validate().is_ok());
+        config.remote_hosts = vec![host("-oProxyCommand=touch /tmp/x")];
+        assert!(config.validate().is_err());
     }
 
     #[test]
@@ -330,4 +1185,188 @@ mod tests {
         
         assert_eq!(config.window.width, deserialized.window.width);
     }
+
+    #[test]
+    fn test_config_toml_format() {
+        let mut config = AppConfig::default();
+        config.window.width = 1024.0;
+        config.ui.network_aliases.insert("eth0".to_string(), "有线".to_string());
+
+        let toml_str = ConfigFormat::Toml.serialize(&config).unwrap();
+        let deserialized = ConfigFormat::Toml.parse(&toml_str).unwrap();
+        assert_eq!(deserialized.window.width, 1024.0);
+        assert_eq!(deserialized.ui.network_aliases.get("eth0").map(String::as_str), Some("有线"));
+
+        let partial = ConfigFormat::Toml.parse("[monitoring]\nrefresh_interval_ms = 500\n").unwrap();
+        assert_eq!(partial.monitoring.refresh_interval_ms, 500);
+        assert!(matches!(
+            ConfigFormat::Toml.parse("[monitoring"),
+            Err(SystemMonitorError::ConfigParsing(_))
+        ));
+
+        assert_eq!(ConfigFormat::from_path(Path::new("config.toml")), ConfigFormat::Toml);
+        assert_eq!(ConfigFormat::from_path(Path::new("config.json")), ConfigFormat::Json);
+        assert_eq!(ConfigFormat::from_path(Path::new("config")), ConfigFormat::Json);
+    }
+
+    #[test]
+    fn test_same_contents_after_round_trip() {
+        let mut config = AppConfig::default();
+        config.window.width = 1023.7;
+        config.ui.chart_colors.cpu_color = [0.1, 0.2, 0.3];
+
+        // 自身保存后重新读取的配置与内存中的一致
+        let reloaded = ConfigFormat::Toml.parse(&ConfigFormat::Toml.serialize(&config).unwrap()).unwrap();
+        assert!(reloaded.same_contents(&config));
+
+        let mut edited = reloaded.clone();
+        edited.monitoring.refresh_interval_ms = 2000;
+        assert!(!edited.same_contents(&config));
+    }
+
+    #[test]
+    fn test_resolve_config_path_prefers_toml() {
+        let dir = std::env::temp_dir().join(format!("system-monitor-config-test-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("config.json"), "{}").unwrap();
+        assert_eq!(AppConfig::resolve_config_path(&dir), dir.join("config.json"));
+
+        std::fs::write(dir.join("config.toml"), "").unwrap();
+        assert_eq!(AppConfig::resolve_config_path(&dir), dir.join("config.toml"));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_config_watcher_debounces_changes() {
+        let dir = std::env::temp_dir().join(format!("system-monitor-watcher-test-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("config.json");
+        std::fs::write(&path, "{}").unwrap();
+
+        let debounce = Duration::from_millis(500);
+        let mut watcher = ConfigWatcher::new(dir.clone(), debounce);
+        let start = Instant::now();
+        assert!(!watcher.poll(start));
+
+        // 连续两次写入只报告一次变化
+        std::fs::write(&path, "{ }").unwrap();
+        assert!(!watcher.poll(start));
+        std::fs::write(&path, "{  }").unwrap();
+        assert!(!watcher.poll(start + Duration::from_millis(100)));
+        assert!(!watcher.poll(start + Duration::from_millis(400)));
+        assert!(watcher.poll(start + Duration::from_millis(600)));
+        assert!(!watcher.poll(start + Duration::from_millis(1200)));
+
+        // 删除文件不报告变化
+        std::fs::remove_file(&path).unwrap();
+        assert!(!watcher.poll(start + Duration::from_millis(1300)));
+        assert!(!watcher.poll(start + Duration::from_secs(2)));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_config_missing_fields_use_defaults() {
+        let json = r#"{ "window": { "width": 1024.0 } }"#;
+        let config: AppConfig = serde_json::from_str(json).unwrap();
+
+        assert_eq!(config.window.width, 1024.0);
+        assert_eq!(config.window.height, 800.0);
+        assert_eq!(config.ui.raw_bytes_display, RawBytesDisplay::Off);
+    }
+
+    #[test]
+    fn test_network_display_name() {
+        let mut ui = UiConfig::default();
+        assert_eq!(ui.network_display_name("enp3s0"), "enp3s0");
+
+        ui.network_aliases.insert("enp3s0".to_string(), "有线网卡".to_string());
+        ui.network_aliases.insert("wlan0".to_string(), "  ".to_string());
+        assert_eq!(ui.network_display_name("enp3s0"), "有线网卡");
+        assert_eq!(ui.network_display_name("wlan0"), "wlan0");
+    }
+
+    #[test]
+    fn test_chart_colors_for_theme() {
+        let ui = UiConfig::default();
+        assert_eq!(ui.chart_colors_for(true).cpu_color, ChartColors::default().cpu_color);
+        assert_eq!(ui.chart_colors_for(false).cpu_color, ChartColors::light().cpu_color);
+    }
+
+    #[test]
+    fn test_chart_colors_validation() {
+        assert!(AppConfig::default().validate().is_ok());
+
+        let mut config = AppConfig::default();
+        config.ui.chart_colors.cpu_color = [5.0, -1.0, 0.0];
+        assert!(config.validate().is_err());
+
+        let mut config = AppConfig::default();
+        config.ui.chart_colors_light.grid_color[1] = f32::NAN;
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_compact_window_size() {
+        let mut window = WindowConfig::default();
+        assert_eq!(window.min_size(), WindowConfig::MIN_SIZE);
+        assert_eq!(window.initial_size(), [1200.0, 800.0]);
+
+        // 紧凑模式使用更小的窗口，常规尺寸保留到退出时恢复
+        window.compact_mode = true;
+        assert_eq!(window.min_size(), WindowConfig::COMPACT_MIN_SIZE);
+        assert_eq!(window.initial_size(), WindowConfig::COMPACT_SIZE);
+        let config = AppConfig { window, ..Default::default() };
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_disk_filter() {
+        let disk = |name: &str, file_system: &str| DiskInfo {
+            name: name.to_string(),
+            file_system: file_system.to_string(),
+            ..Default::default()
+        };
+        let mut filter = DiskFilter::default();
+        assert!(filter.allows(&disk("/dev/sda1", "ext4"), false));
+        assert!(filter.allows(&disk("/dev/loop3", "ext4"), false));
+        assert!(!filter.allows(&disk("tmpfs", "tmpfs"), false));
+        assert!(!filter.allows(&disk("/dev/loop0", "SquashFS"), false));
+
+        filter.physical_only = true;
+        assert!(filter.allows(&disk("/dev/nvme0n1p2", "btrfs"), false));
+        assert!(!filter.allows(&disk("/dev/sdb1", "vfat"), true));
+        assert!(!filter.allows(&disk("server:/export", "nfs4"), false));
+        assert!(!filter.allows(&disk("/dev/loop3", "ext4"), false));
+
+        // 屏蔽列表可由用户覆盖
+        filter.hidden_file_systems.clear();
+        filter.physical_only = false;
+        assert!(filter.allows(&disk("tmpfs", "tmpfs"), false));
+    }
+
+    #[test]
+    fn test_record_window_geometry() {
+        let mut window = WindowConfig::default();
+        window.record_geometry([1000.0, 700.0], Some([40.0, 30.0]), false);
+        assert_eq!((window.width, window.height, window.position), (1000.0, 700.0, Some([40.0, 30.0])));
+
+        // 最大化时保留常规尺寸，只记录最大化状态
+        window.record_geometry([1920.0, 1080.0], Some([0.0, 0.0]), true);
+        assert!(window.maximized);
+        assert_eq!((window.width, window.height, window.position), (1000.0, 700.0, Some([40.0, 30.0])));
+
+        // 紧凑模式下的小窗口不覆盖常规尺寸
+        window.compact_mode = true;
+        window.record_geometry([320.0, 160.0], None, false);
+        assert!(!window.maximized);
+        assert_eq!((window.width, window.height), (1000.0, 700.0));
+
+        // 应用旧的配置副本时保留已记录的几何信息，其他窗口设置照常更新
+        let mut stale = WindowConfig { always_on_top: true, ..WindowConfig::default() };
+        stale.copy_geometry_from(&window);
+        assert!(stale.always_on_top);
+        assert_eq!((stale.width, stale.height, stale.position), (1000.0, 700.0, Some([40.0, 30.0])));
+    }
 }
\ No newline at end of file
@@ -2,6 +2,8 @@
 //! 
 //! 定义了系统监控工具中使用的所有错误类型，提供统一的错误处理机制。
 
+use std::sync::Arc;
+use std::time::Duration;
 use thiserror::Error;
 
 /// 系统监控工具的主要错误类型
@@ -40,11 +42,26 @@ pub enum SystemMonitorError {
     Other(#[from] anyhow::Error),
 }
 
+impl From<toml::de::Error> for SystemMonitorError {
+    fn from(error: toml::de::Error) -> Self {
+        SystemMonitorError::ConfigParsing(config::ConfigError::Foreign(Box::new(error)))
+    }
+}
+
+impl From<toml::ser::Error> for SystemMonitorError {
+    fn from(error: toml::ser::Error) -> Self {
+        SystemMonitorError::ConfigParsing(config::ConfigError::Foreign(Box::new(error)))
+    }
+}
+
+/// 按倍数递增的重试间隔上限（毫秒），初始间隔更长时以初始间隔为准
+pub const MAX_RETRY_DELAY_MS: u64 = 30_000;
+
 /// 错误恢复策略
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum RecoveryStrategy {
-    /// 重试操作
-    Retry { max_attempts: u32, delay_ms: u64 },
+    /// 重试操作，每次重试的间隔乘以 `backoff_multiplier`（1.0 为固定间隔）
+    Retry { max_attempts: u32, delay_ms: u64, backoff_multiplier: f64 },
     /// 使用默认值
     UseDefault,
     /// 优雅降级
@@ -55,6 +72,34 @@ pub enum RecoveryStrategy {
     Terminate,
 }
 
+impl RecoveryStrategy {
+    /// 是否需要向用户显示错误
+    pub fn should_notify(&self) -> bool {
+        !matches!(self, RecoveryStrategy::Ignore)
+    }
+
+    /// 第 `attempt` 次（从1开始）失败后的重试间隔，非重试策略为 `None`
+    pub fn retry_delay(&self, attempt: u32) -> Option<Duration> {
+        let RecoveryStrategy::Retry { delay_ms, backoff_multiplier, .. } = *self else {
+            return None;
+        };
+        let exponent = attempt.saturating_sub(1).min(i32::MAX as u32) as i32;
+        let delay = delay_ms as f64 * backoff_multiplier.powi(exponent);
+        let cap = MAX_RETRY_DELAY_MS.max(delay_ms);
+        Some(Duration::from_millis(delay.min(cap as f64) as u64))
+    }
+}
+
+/// 恢复失败的结果，携带导致失败的错误和最终采用的恢复策略
+#[derive(Debug, Error)]
+#[error("{error}")]
+pub struct RecoveryFailure {
+    /// 原始错误
+    pub error: SystemMonitorError,
+    /// 采用的恢复策略，调用者据此决定后续处理
+    pub strategy: RecoveryStrategy,
+}
+
 /// 错误处理器特征
 pub trait ErrorHandler {
     /// 处理错误并返回恢复策略
@@ -73,6 +118,7 @@ impl ErrorHandler for DefaultErrorHandler {
             SystemMonitorError::SystemInfo(_) => RecoveryStrategy::Retry {
                 max_attempts: 3,
                 delay_ms: 1000,
+                backoff_multiplier: 2.0,
             },
             SystemMonitorError::Config(_) => RecoveryStrategy::UseDefault,
             SystemMonitorError::Ui(_) => RecoveryStrategy::Degrade,
@@ -80,6 +126,7 @@ impl ErrorHandler for DefaultErrorHandler {
             SystemMonitorError::Io(_) => RecoveryStrategy::Retry {
                 max_attempts: 2,
                 delay_ms: 500,
+                backoff_multiplier: 1.0,
             },
             SystemMonitorError::Serialization(_) => RecoveryStrategy::UseDefault,
             SystemMonitorError::ConfigParsing(_) => RecoveryStrategy::UseDefault,
@@ -103,60 +150,112 @@ impl ErrorHandler for DefaultErrorHandler {
 }
 
 /// 错误恢复执行器
+#[derive(Clone)]
 pub struct ErrorRecovery {
-    handler: Box<dyn ErrorHandler + Send + Sync>,
+    handler: Arc<dyn ErrorHandler + Send + Sync>,
+}
+
+impl Default for ErrorRecovery {
+    /// 创建使用默认处理器的错误恢复执行器
+    ///
+    /// 调用方式 `ErrorRecovery::default()` 保持不变。
+    fn default() -> Self {
+        Self::new(Box::new(DefaultErrorHandler))
+    }
 }
 
 impl ErrorRecovery {
     /// 创建新的错误恢复执行器
     pub fn new(handler: Box<dyn ErrorHandler + Send + Sync>) -> Self {
-        Self { handler }
+        Self { handler: Arc::from(handler) }
     }
 
-    /// 创建使用默认处理器的错误恢复执行器
-    pub fn default() -> Self {
-        Self::new(Box::new(DefaultErrorHandler))
+    /// 记录错误并返回处理器选择的恢复策略
+    pub fn strategy_for(&self, error: &SystemMonitorError, context: &str) -> RecoveryStrategy {
+        self.handler.log_error(error, context);
+        self.handler.handle_error(error)
     }
 
     /// 处理错误并执行恢复策略
+    ///
+    /// 重试在内部完成；其余策略以 [`RecoveryFailure`] 返回给调用者处理。
+    /// 需要在 `UseDefault` 策略下直接得到默认值时使用 [`Self::handle_with_default`]。
     pub async fn handle_with_recovery<T, F, Fut>(
         &self,
         operation: F,
         context: &str,
-    ) -> Result<T>
+    ) -> std::result::Result<T, RecoveryFailure>
     where
         F: Fn() -> Fut,
         Fut: std::future::Future<Output = Result<T>>,
+    {
+        self.recover(operation, context, None::<fn() -> T>).await
+    }
+
+    /// 处理错误并执行恢复策略，`UseDefault` 策略返回 `default` 提供的默认值
+    ///
+    /// 其余策略与 [`Self::handle_with_recovery`] 相同。
+    pub async fn handle_with_default<T, F, Fut, D>(
+        &self,
+        operation: F,
+        context: &str,
+        default: D,
+    ) -> std::result::Result<T, RecoveryFailure>
+    where
+        F: Fn() -> Fut,
+        Fut: std::future::Future<Output = Result<T>>,
+        D: FnOnce() -> T,
+    {
+        self.recover(operation, context, Some(default)).await
+    }
+
+    /// 执行操作和恢复策略，`default` 为 `None` 时 `UseDefault` 策略交由调用者处理
+    async fn recover<T, F, Fut, D>(
+        &self,
+        operation: F,
+        context: &str,
+        default: Option<D>,
+    ) -> std::result::Result<T, RecoveryFailure>
+    where
+        F: Fn() -> Fut,
+        Fut: std::future::Future<Output = Result<T>>,
+        D: FnOnce() -> T,
     {
         let mut attempts = 0;
-        let _max_attempts = 3;
 
         loop {
             match operation().await {
                 Ok(result) => return Ok(result),
                 Err(error) => {
-                    self.handler.log_error(&error, context);
-                    let strategy = self.handler.handle_error(&error);
+                    let strategy = self.strategy_for(&error, context);
 
                     match strategy {
-                        RecoveryStrategy::Retry { max_attempts: max_retry, delay_ms } => {
+                        RecoveryStrategy::Retry { max_attempts: max_retry, .. } => {
                             attempts += 1;
                             if attempts >= max_retry {
-                                return Err(error);
+                                return Err(RecoveryFailure { error, strategy });
+                            }
+                            if let Some(delay) = strategy.retry_delay(attempts) {
+                                tokio::time::sleep(delay).await;
                             }
-                            tokio::time::sleep(tokio::time::Duration::from_millis(delay_ms)).await;
                         }
                         RecoveryStrategy::UseDefault => {
-                            log::info!("使用默认值恢复操作: {}", context);
-                            return Err(error); // 调用者需要处理默认值逻辑
+                            return match default {
+                                Some(default) => {
+                                    log::info!("使用默认值恢复操作: {}", context);
+                                    Ok(default())
+                                }
+                                // 调用者需要处理默认值逻辑
+                                None => Err(RecoveryFailure { error, strategy }),
+                            };
                         }
                         RecoveryStrategy::Degrade => {
                             log::info!("启用降级模式: {}", context);
-                            return Err(error); // 调用者需要处理降级逻辑
+                            return Err(RecoveryFailure { error, strategy }); // 调用者需要处理降级逻辑
                         }
                         RecoveryStrategy::Ignore => {
                             log::info!("忽略错误继续执行: {}", context);
-                            return Err(error);
+                            return Err(RecoveryFailure { error, strategy });
                         }
                         RecoveryStrategy::Terminate => {
                             log::error!("严重错误，终止应用: {}", error);
@@ -188,11 +287,77 @@ mod tests {
         let error = SystemMonitorError::SystemInfo("测试".to_string());
         
         match handler.handle_error(&error) {
-            RecoveryStrategy::Retry { max_attempts, delay_ms } => {
+            RecoveryStrategy::Retry { max_attempts, delay_ms, backoff_multiplier } => {
                 assert_eq!(max_attempts, 3);
                 assert_eq!(delay_ms, 1000);
+                assert_eq!(backoff_multiplier, 2.0);
             }
             _ => panic!("期望重试策略"),
         }
     }
-}
\ No newline at end of file
+
+    #[tokio::test]
+    async fn test_handle_with_recovery_reports_strategy() {
+        let recovery = ErrorRecovery::default();
+
+        let result: std::result::Result<(), _> = recovery
+            .handle_with_recovery(|| async { Err(SystemMonitorError::Ui("测试".to_string())) }, "测试")
+            .await;
+        let failure = result.unwrap_err();
+        assert_eq!(failure.strategy, RecoveryStrategy::Degrade);
+        assert!(matches!(failure.error, SystemMonitorError::Ui(_)));
+
+        let result: std::result::Result<(), _> = recovery
+            .handle_with_recovery(|| async { Err(SystemMonitorError::Other(anyhow::anyhow!("测试"))) }, "测试")
+            .await;
+        assert!(!result.unwrap_err().strategy.should_notify());
+    }
+
+    #[tokio::test]
+    async fn test_handle_with_default_uses_default() {
+        let recovery = ErrorRecovery::default();
+
+        // 配置错误的策略为使用默认值
+        let result = recovery
+            .handle_with_default(|| async { Err(SystemMonitorError::Config("测试".to_string())) }, "测试", || 42)
+            .await;
+        assert_eq!(result.unwrap(), 42);
+
+        // 不提供默认值时仍交由调用者处理
+        let result: std::result::Result<u32, _> = recovery
+            .handle_with_recovery(|| async { Err(SystemMonitorError::Config("测试".to_string())) }, "测试")
+            .await;
+        assert_eq!(result.unwrap_err().strategy, RecoveryStrategy::UseDefault);
+
+        // 其他策略不受默认值影响
+        let result = recovery
+            .handle_with_default(|| async { Err(SystemMonitorError::Ui("测试".to_string())) }, "测试", Vec::<u8>::new)
+            .await;
+        assert_eq!(result.unwrap_err().strategy, RecoveryStrategy::Degrade);
+    }
+
+    #[test]
+    fn test_retry_delay() {
+        let fixed = RecoveryStrategy::Retry { max_attempts: 3, delay_ms: 500, backoff_multiplier: 1.0 };
+        assert_eq!(fixed.retry_delay(1), Some(Duration::from_millis(500)));
+        assert_eq!(fixed.retry_delay(5), Some(Duration::from_millis(500)));
+
+        let backoff = RecoveryStrategy::Retry { max_attempts: 10, delay_ms: 1000, backoff_multiplier: 2.0 };
+        assert_eq!(backoff.retry_delay(3), Some(Duration::from_millis(4000)));
+        assert_eq!(backoff.retry_delay(10), Some(Duration::from_millis(MAX_RETRY_DELAY_MS)));
+        assert_eq!(RecoveryStrategy::Ignore.retry_delay(1), None);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_retry_backoff_total_delay() {
+        let recovery = ErrorRecovery::default();
+        let start = tokio::time::Instant::now();
+
+        let result: std::result::Result<(), _> = recovery
+            .handle_with_recovery(|| async { Err(SystemMonitorError::SystemInfo("测试".to_string())) }, "测试")
+            .await;
+        assert!(result.is_err());
+        // 3次尝试之间等待 1s + 2s
+        assert_eq!(start.elapsed(), Duration::from_millis(3000));
+    }
+}
@@ -1,6 +1,41 @@
-pub mod app;
+//! System Monitor 库
+//!
+//! 提供不依赖图形界面的系统信息采集核心，可供其他Rust程序嵌入使用：
+//!
+//! - [`SystemInfoManager`]：采集CPU、内存、磁盘、进程等信息，生成 [`SystemSnapshot`]
+//! - [`MetricsCalculator`]：基于历史数据计算统计值和负载趋势
+//! - [`AppConfig`] 等配置类型
+//!
+//! 图形界面（`app`、`ui` 模块）由默认启用的 `gui` 特性提供，仅使用采集核心时可通过
+//! `default-features = false` 关闭。
+//!
+//! ```no_run
+//! # async fn example() -> system_monitor::Result<()> {
+//! let manager = system_monitor::SystemInfoManager::new()?;
+//! let snapshot = manager.get_snapshot().await?;
+//! println!("CPU使用率: {:.1}%", snapshot.cpu.global_usage);
+//! # Ok(())
+//! # }
+//! ```
+
 pub mod config;
 pub mod error;
 pub mod system;
+pub mod utils;
+
+#[cfg(feature = "gui")]
+pub mod app;
+#[cfg(feature = "gui")]
 pub mod ui;
-pub mod utils;
\ No newline at end of file
+
+pub use config::{
+    AlertConfig, AlertThreshold, AppConfig, ConfigFormat, ConfigManager, ConfigWatcher, ExportConfig,
+    Language, MonitoringConfig, PerformanceConfig, ProcessCollectionMode, RemoteHost, UiConfig, WindowConfig,
+};
+pub use error::{Result, SystemMonitorError};
+pub use system::metrics::{CpuStats, LoadTrend, MemoryStats, MetricsCalculator, ProcessHistory};
+pub use system::remote::{RemoteSource, SnapshotSource};
+pub use system::{
+    CpuCoreInfo, CpuInfo, DiskInfo, GpuInfo, MemoryInfo, NetworkInfo, ProcessInfo, SnapshotRequest,
+    SystemHealthStatus, SystemInfo, SystemInfoManager, SystemSnapshot,
+};
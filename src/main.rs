@@ -2,29 +2,35 @@
 //! 
 //! 这是应用程序的主入口点，负责初始化应用程序并启动GUI。
 
+use clap::{Arg, ArgAction, Command};
 use eframe::egui;
 use log::{error, info};
 use std::sync::Arc;
+use std::time::Duration;
 
-mod app;
-mod config;
-mod error;
-mod system;
-mod ui;
-mod utils;
-
-use app::SystemMonitorApp;
-use config::AppConfig;
-use error::SystemMonitorError;
+use system_monitor::app::SystemMonitorApp;
+use system_monitor::system::collector::{CachedSystemCollector, SystemInfoCollector};
+use system_monitor::system::export;
+use system_monitor::utils::{Formatter, I18n};
+use system_monitor::{AppConfig, SystemMonitorError};
 
 /// 应用程序主函数
 #[tokio::main]
 async fn main() -> Result<(), SystemMonitorError> {
+    let matches = cli().get_matches();
+
     // 初始化日志系统
     env_logger::Builder::from_default_env()
         .filter_level(log::LevelFilter::Info)
         .init();
 
+    if matches.get_flag("once") {
+        let format = matches.get_one::<String>("format").cloned().unwrap_or_default();
+        return tokio::task::spawn_blocking(move || run_once(&format))
+            .await
+            .map_err(|e| SystemMonitorError::Runtime(e.to_string()))?;
+    }
+
     info!("启动系统监控工具...");
 
     // 加载应用程序配置
@@ -40,12 +46,22 @@ async fn main() -> Result<(), SystemMonitorError> {
     };
 
     // 设置eframe选项
+    let mut viewport = egui::ViewportBuilder::default()
+        .with_inner_size(config.window.initial_size())
+        .with_min_inner_size(config.window.min_size())
+        .with_maximized(config.window.maximized)
+        .with_icon(load_icon())
+        .with_resizable(true);
+    if let Some(position) = config.window.position {
+        viewport = viewport.with_position(position);
+    }
+    if config.window.always_on_top {
+        viewport = viewport.with_always_on_top();
+    }
     let options = eframe::NativeOptions {
-        viewport: egui::ViewportBuilder::default()
-            .with_inner_size([config.window.width, config.window.height])
-            .with_min_inner_size([800.0, 600.0])
-            .with_icon(load_icon())
-            .with_resizable(true),
+        viewport,
+        // 窗口尺寸和位置由配置文件保存，不使用eframe自带的窗口持久化
+        persist_window: false,
         ..Default::default()
     };
 
@@ -84,6 +100,47 @@ async fn main() -> Result<(), SystemMonitorError> {
     }
 }
 
+/// 命令行参数定义
+fn cli() -> Command {
+    Command::new("system-monitor")
+        .version(env!("CARGO_PKG_VERSION"))
+        .about(env!("CARGO_PKG_DESCRIPTION"))
+        .arg(
+            Arg::new("once")
+                .long("once")
+                .action(ArgAction::SetTrue)
+                .help("采集一次快照并输出后退出，不启动图形界面"),
+        )
+        .arg(
+            Arg::new("format")
+                .long("format")
+                .value_parser(["table", "json"])
+                .default_value("table")
+                .requires("once")
+                .help("--once 的输出格式"),
+        )
+}
+
+/// 无界面模式：采集一次快照，输出到标准输出后退出
+fn run_once(format: &str) -> Result<(), SystemMonitorError> {
+    // 摘要沿用界面配置的语言和数值格式
+    let config = AppConfig::load().unwrap_or_default();
+    Formatter::configure(config.ui.format);
+    I18n::set_language(config.ui.language);
+
+    let collector = CachedSystemCollector::new(Duration::ZERO)?;
+    // CPU使用率需要间隔一段时间的两次采样才能计算
+    std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+    let snapshot = collector.collect_system_snapshot()?;
+
+    let output = match format {
+        "json" => serde_json::to_string_pretty(&snapshot)?,
+        _ => export::snapshot_summary(&snapshot),
+    };
+    println!("{}", output);
+    Ok(())
+}
+
 /// 加载应用程序图标
 fn load_icon() -> egui::IconData {
     // 这里可以加载自定义图标，暂时返回默认图标
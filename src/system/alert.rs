@@ -0,0 +1,365 @@
+//! 告警模块
+//!
+//! 根据配置的阈值检查系统快照，在指标越过阈值时生成告警事件。
+
+use crate::config::{AlertConfig, AlertThreshold};
+use crate::system::info::SystemSnapshot;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+/// 告警类别
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum AlertKind {
+    /// CPU使用率
+    Cpu,
+    /// 内存使用率
+    Memory,
+    /// 磁盘使用率
+    Disk,
+}
+
+impl AlertKind {
+    /// 获取类别名称
+    pub fn label(&self) -> &'static str {
+        match self {
+            AlertKind::Cpu => "CPU",
+            AlertKind::Memory => "内存",
+            AlertKind::Disk => "磁盘",
+        }
+    }
+}
+
+/// 告警级别
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum AlertLevel {
+    /// 警告
+    Warning,
+    /// 严重
+    Critical,
+}
+
+impl AlertLevel {
+    /// 获取级别描述
+    pub fn description(&self) -> &'static str {
+        match self {
+            AlertLevel::Warning => "警告",
+            AlertLevel::Critical => "严重",
+        }
+    }
+}
+
+/// 告警事件
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Alert {
+    /// 触发时间
+    pub timestamp: chrono::DateTime<chrono::Utc>,
+    /// 告警类别
+    pub kind: AlertKind,
+    /// 告警来源（磁盘为挂载点，其余与类别相同）
+    pub source: String,
+    /// 触发时的数值（百分比）
+    pub value: f64,
+    /// 被越过的阈值（百分比）
+    pub threshold: f64,
+    /// 告警级别
+    pub level: AlertLevel,
+}
+
+impl Alert {
+    /// 获取告警描述文本
+    pub fn message(&self) -> String {
+        format!(
+            "{} 使用率 {:.1}% 超过{}阈值 {:.0}%",
+            self.source,
+            self.value,
+            self.level.description(),
+            self.threshold
+        )
+    }
+}
+
+/// 告警监视器
+///
+/// 记录每个来源当前所处的告警级别，只在级别升高时生成新告警，
+/// 避免指标持续超限时每个采样周期都重复告警。
+#[derive(Debug, Default)]
+pub struct AlertMonitor {
+    active: HashMap<(AlertKind, String), AlertLevel>,
+}
+
+impl AlertMonitor {
+    /// 创建新的告警监视器
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 检查快照并返回新触发的告警
+    pub fn evaluate(&mut self, snapshot: &SystemSnapshot, config: &AlertConfig) -> Vec<Alert> {
+        if !config.enabled {
+            self.active.clear();
+            return Vec::new();
+        }
+
+        let mut alerts = Vec::new();
+
+        // 未采集的类别不参与判定
+        if let Some(cpu) = snapshot.cpu_usage() {
+            self.check(
+                AlertKind::Cpu,
+                AlertKind::Cpu.label(),
+                cpu as f64,
+                &config.cpu,
+                snapshot,
+                &mut alerts,
+            );
+        }
+        if let Some(memory) = snapshot.memory_usage() {
+            self.check(
+                AlertKind::Memory,
+                AlertKind::Memory.label(),
+                memory,
+                &config.memory,
+                snapshot,
+                &mut alerts,
+            );
+        }
+        for disk in &snapshot.disks {
+            self.check(
+                AlertKind::Disk,
+                &disk.mount_point,
+                disk.usage_percent,
+                &config.disk,
+                snapshot,
+                &mut alerts,
+            );
+        }
+
+        alerts
+    }
+
+    /// 检查单个指标
+    fn check(
+        &mut self,
+        kind: AlertKind,
+        source: &str,
+        value: f64,
+        threshold: &AlertThreshold,
+        snapshot: &SystemSnapshot,
+        alerts: &mut Vec<Alert>,
+    ) {
+        let key = (kind, source.to_string());
+        let level = threshold.level_for(value);
+        let previous = self.active.get(&key).copied();
+
+        match level {
+            Some(level) => {
+                if previous.is_none_or(|previous| level > previous) {
+                    alerts.push(Alert {
+                        timestamp: snapshot.timestamp,
+                        kind,
+                        source: source.to_string(),
+                        value,
+                        threshold: threshold.value_for(level),
+                        level,
+                    });
+                }
+                self.active.insert(key, level);
+            }
+            None => {
+                self.active.remove(&key);
+            }
+        }
+    }
+}
+
+/// 通知节流器
+///
+/// 将聚合窗口内到达的告警合并为一条通知（如 "CPU 95%, 内存 92%"），
+/// 并保证两条通知之间至少间隔冷却时间，冷却期间的告警会累积到下一条通知。
+#[derive(Debug)]
+pub struct NotificationThrottler {
+    window: Duration,
+    cooldown: Duration,
+    pending: Vec<Alert>,
+    batch_started: Option<Instant>,
+    last_sent: Option<Instant>,
+}
+
+impl NotificationThrottler {
+    /// 创建新的通知节流器
+    pub fn new(window: Duration, cooldown: Duration) -> Self {
+        Self {
+            window,
+            cooldown,
+            pending: Vec::new(),
+            batch_started: None,
+            last_sent: None,
+        }
+    }
+
+    /// 按告警配置创建通知节流器
+    pub fn from_config(config: &AlertConfig) -> Self {
+        let mut throttler = Self::new(Duration::ZERO, Duration::ZERO);
+        throttler.configure(config);
+        throttler
+    }
+
+    /// 更新聚合窗口和冷却时间，不影响已累积的告警
+    pub fn configure(&mut self, config: &AlertConfig) {
+        self.window = Duration::from_secs(config.notification_window_secs);
+        self.cooldown = Duration::from_secs(config.notification_cooldown_secs);
+    }
+
+    /// 加入一条告警，同一来源只保留最新的一条
+    pub fn push(&mut self, alert: Alert, now: Instant) {
+        if self.pending.is_empty() {
+            self.batch_started = Some(now);
+        }
+
+        match self.pending.iter_mut().find(|pending| pending.kind == alert.kind && pending.source == alert.source) {
+            Some(pending) => *pending = alert,
+            None => self.pending.push(alert),
+        }
+    }
+
+    /// 聚合窗口结束且不在冷却期内时，取出合并后的通知文本
+    pub fn poll(&mut self, now: Instant) -> Option<String> {
+        let batch_started = self.batch_started?;
+        if now.duration_since(batch_started) < self.window {
+            return None;
+        }
+        if self.last_sent.is_some_and(|last_sent| now.duration_since(last_sent) < self.cooldown) {
+            return None;
+        }
+
+        let message = self.pending.iter()
+            .map(|alert| format!("{} {:.0}%", alert.source, alert.value))
+            .collect::<Vec<_>>()
+            .join(", ");
+        self.pending.clear();
+        self.batch_started = None;
+        self.last_sent = Some(now);
+        Some(message)
+    }
+
+    /// 等待发送的告警数量
+    pub fn pending_len(&self) -> usize {
+        self.pending.len()
+    }
+}
+
+impl AlertThreshold {
+    /// 获取数值所处的告警级别
+    pub fn level_for(&self, value: f64) -> Option<AlertLevel> {
+        if value >= self.critical {
+            Some(AlertLevel::Critical)
+        } else if value >= self.warning {
+            Some(AlertLevel::Warning)
+        } else {
+            None
+        }
+    }
+
+    /// 获取告警级别对应的阈值
+    pub fn value_for(&self, level: AlertLevel) -> f64 {
+        match level {
+            AlertLevel::Warning => self.warning,
+            AlertLevel::Critical => self.critical,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::system::info::{CpuInfo, MemoryInfo, SystemInfo};
+
+    fn snapshot_with_cpu(usage: f32) -> SystemSnapshot {
+        SystemSnapshot::new(
+            CpuInfo { global_usage: usage, ..Default::default() },
+            MemoryInfo::default(),
+            vec![],
+            SystemInfo::default(),
+            None,
+        )
+    }
+
+    #[test]
+    fn test_alert_fires_once_per_crossing() {
+        let config = AlertConfig::default();
+        let mut monitor = AlertMonitor::new();
+
+        assert!(monitor.evaluate(&snapshot_with_cpu(50.0), &config).is_empty());
+
+        let alerts = monitor.evaluate(&snapshot_with_cpu(85.0), &config);
+        assert_eq!(alerts.len(), 1);
+        assert_eq!(alerts[0].kind, AlertKind::Cpu);
+        assert_eq!(alerts[0].level, AlertLevel::Warning);
+        assert_eq!(alerts[0].threshold, config.cpu.warning);
+
+        // 持续超限不重复告警
+        assert!(monitor.evaluate(&snapshot_with_cpu(86.0), &config).is_empty());
+
+        // 升级为严重时再次告警
+        let alerts = monitor.evaluate(&snapshot_with_cpu(99.0), &config);
+        assert_eq!(alerts.len(), 1);
+        assert_eq!(alerts[0].level, AlertLevel::Critical);
+
+        // 恢复后再次越限会重新告警
+        assert!(monitor.evaluate(&snapshot_with_cpu(10.0), &config).is_empty());
+        assert_eq!(monitor.evaluate(&snapshot_with_cpu(90.0), &config).len(), 1);
+    }
+
+    #[test]
+    fn test_uncollected_category_is_skipped() {
+        let config = AlertConfig::default();
+        let mut monitor = AlertMonitor::new();
+
+        assert_eq!(monitor.evaluate(&snapshot_with_cpu(90.0), &config).len(), 1);
+        // 暂停采集期间的默认值不视为恢复，重新采集时不重复告警
+        assert!(monitor.evaluate(&snapshot_with_cpu(0.0).without_cpu(), &config).is_empty());
+        assert!(monitor.evaluate(&snapshot_with_cpu(90.0), &config).is_empty());
+    }
+
+    fn alert(kind: AlertKind, value: f64) -> Alert {
+        Alert {
+            timestamp: chrono::Utc::now(),
+            kind,
+            source: kind.label().to_string(),
+            value,
+            threshold: 80.0,
+            level: AlertLevel::Warning,
+        }
+    }
+
+    #[test]
+    fn test_notifications_are_batched_and_throttled() {
+        let mut throttler = NotificationThrottler::new(Duration::from_secs(5), Duration::from_secs(60));
+        let start = Instant::now();
+
+        throttler.push(alert(AlertKind::Cpu, 90.0), start);
+        throttler.push(alert(AlertKind::Memory, 92.0), start + Duration::from_secs(2));
+        // 同一来源只保留最新数值
+        throttler.push(alert(AlertKind::Cpu, 95.0), start + Duration::from_secs(3));
+        assert_eq!(throttler.pending_len(), 2);
+
+        // 聚合窗口内不发送
+        assert!(throttler.poll(start + Duration::from_secs(4)).is_none());
+        let sent = start + Duration::from_secs(5);
+        assert_eq!(throttler.poll(sent).as_deref(), Some("CPU 95%, 内存 92%"));
+        assert!(throttler.poll(sent).is_none());
+
+        // 冷却期间累积，冷却结束后合并发送
+        throttler.push(alert(AlertKind::Cpu, 97.0), sent + Duration::from_secs(10));
+        assert!(throttler.poll(sent + Duration::from_secs(30)).is_none());
+        assert_eq!(throttler.poll(sent + Duration::from_secs(60)).as_deref(), Some("CPU 97%"));
+    }
+
+    #[test]
+    fn test_alerts_disabled() {
+        let config = AlertConfig { enabled: false, ..Default::default() };
+        let mut monitor = AlertMonitor::new();
+        assert!(monitor.evaluate(&snapshot_with_cpu(100.0), &config).is_empty());
+    }
+}
@@ -2,9 +2,12 @@
 //! 
 //! 提供高级的系统信息采集接口，支持缓存和批量操作。
 
+use crate::config::DiskFilter;
 use crate::error::{Result, SystemMonitorError};
 use crate::system::info::*;
-use sysinfo::{System, ProcessRefreshKind, ProcessesToUpdate, Disks, Networks};
+use crate::system::process::ProcessSortKey;
+use crate::utils::MathUtils;
+use sysinfo::{System, ProcessRefreshKind, ProcessesToUpdate, Disks, Users};
 use std::sync::{Arc, RwLock};
 use std::time::{Duration, Instant};
 use tokio::sync::Notify;
@@ -28,6 +31,15 @@ pub trait SystemInfoCollector: Send + Sync {
     
     /// 收集网络信息
     fn collect_network_info(&self) -> Result<Vec<NetworkInfo>>;
+
+    /// 收集GPU信息（无可用GPU时为空列表）
+    fn collect_gpu_info(&self) -> Result<Vec<GpuInfo>>;
+
+    /// 收集温度传感器信息（无可用传感器时为空列表）
+    fn collect_temperatures(&self) -> Result<Vec<SensorInfo>>;
+
+    /// 结束进程，进程不存在时返回 `Ok(false)`，无权结束时返回错误
+    fn kill_process(&self, pid: u32) -> Result<bool>;
     
     /// 收集完整的系统快照
     fn collect_system_snapshot(&self) -> Result<SystemSnapshot>;
@@ -40,6 +52,16 @@ pub struct CachedSystemCollector {
     cache_duration: Duration,
     last_refresh: Arc<RwLock<Instant>>,
     refresh_notify: Arc<Notify>,
+    disk_io: Arc<RwLock<super::DiskIoTracker>>,
+    networks: Arc<RwLock<super::NetworkSampler>>,
+    /// 采集的进程数上限，0表示不限制
+    process_limit: usize,
+    /// 选取进程的排序字段
+    process_sort_by: ProcessSortKey,
+    /// 磁盘过滤
+    disk_filter: DiskFilter,
+    /// CPU型号、厂商和物理核心数（运行期间不变，创建时获取一次）
+    cpu_identity: Arc<super::CpuIdentity>,
 }
 
 /// 采集器缓存
@@ -77,14 +99,33 @@ impl CachedSystemCollector {
         system.refresh_all();
 
         Ok(Self {
+            cpu_identity: Arc::new(super::cpu_identity(&system)),
             system: Arc::new(RwLock::new(system)),
             cache: Arc::new(RwLock::new(CollectorCache::new())),
             cache_duration,
             last_refresh: Arc::new(RwLock::new(Instant::now())),
             refresh_notify: Arc::new(Notify::new()),
+            disk_io: Arc::new(RwLock::new(super::DiskIoTracker::default())),
+            networks: Arc::new(RwLock::new(super::NetworkSampler::new())),
+            process_limit: 50,
+            process_sort_by: ProcessSortKey::Cpu,
+            disk_filter: DiskFilter::default(),
         })
     }
 
+    /// 设置进程的选取方式：按 `sort_by` 保留前 `limit` 个（0表示全部）
+    pub fn with_process_list(mut self, sort_by: ProcessSortKey, limit: usize) -> Self {
+        self.process_sort_by = sort_by;
+        self.process_limit = limit;
+        self
+    }
+
+    /// 设置磁盘过滤
+    pub fn with_disk_filter(mut self, filter: DiskFilter) -> Self {
+        self.disk_filter = filter;
+        self
+    }
+
     /// 强制刷新系统信息
     pub fn force_refresh(&self) -> Result<()> {
         let mut system = self.system.write()
@@ -150,6 +191,9 @@ impl CachedSystemCollector {
             global_usage: global_cpu,
             cores,
             core_count: system.cpus().len(),
+            physical_core_count: self.cpu_identity.physical_core_count,
+            brand: self.cpu_identity.brand.clone(),
+            vendor: self.cpu_identity.vendor.clone(),
         };
         
         drop(system);
@@ -185,7 +229,11 @@ impl CachedSystemCollector {
             used: system.used_memory(),
             available: system.available_memory(),
             free: system.free_memory(),
-            usage_percent: (system.used_memory() as f64 / system.total_memory() as f64) * 100.0,
+            usage_percent: MathUtils::percentage(system.used_memory() as f64, system.total_memory() as f64),
+            reclaimable: system.available_memory().saturating_sub(system.free_memory()),
+            swap_total: system.total_swap(),
+            swap_used: system.used_swap(),
+            swap_usage_percent: MathUtils::percentage(system.used_swap() as f64, system.total_swap() as f64),
         };
         
         drop(system);
@@ -215,22 +263,21 @@ impl CachedSystemCollector {
         let system = self.system.read()
             .map_err(|_| SystemMonitorError::SystemInfo("无法获取系统读锁".to_string()))?;
         
-        let disks: Vec<DiskInfo> = Disks::new_with_refreshed_list().iter().map(|disk| {
-            let total = disk.total_space();
-            let available = disk.available_space();
-            let used = total - available;
-            
-            DiskInfo {
-                name: disk.name().to_string_lossy().to_string(),
-                mount_point: disk.mount_point().to_string_lossy().to_string(),
-                file_system: String::from_utf8_lossy(disk.file_system().as_encoded_bytes()).to_string(),
-                total_space: total,
-                available_space: available,
-                used_space: used,
-                usage_percent: if total > 0 { (used as f64 / total as f64) * 100.0 } else { 0.0 },
-            }
+        let mut disk_io = self.disk_io.write()
+            .map_err(|_| SystemMonitorError::SystemInfo("无法获取磁盘速率写锁".to_string()))?;
+        let now = Instant::now();
+        let disks: Vec<DiskInfo> = Disks::new_with_refreshed_list().iter().filter_map(|disk| {
+            let info = super::disk_info(disk);
+            self.disk_filter.allows(&info, disk.is_removable()).then(|| {
+                let usage = disk.usage();
+                let (read_bytes_per_sec, write_bytes_per_sec) =
+                    disk_io.rates(&info.mount_point, usage.total_read_bytes, usage.total_written_bytes, now);
+                DiskInfo { read_bytes_per_sec, write_bytes_per_sec, ..info }
+            })
         }).collect();
+        disk_io.retain(&disks);
         
+        drop(disk_io);
         drop(system);
         
         let mut cache = self.cache.write()
@@ -276,18 +323,26 @@ impl SystemInfoCollector for CachedSystemCollector {
         
         system.refresh_processes_specifics(ProcessesToUpdate::All, true, ProcessRefreshKind::everything());
         
+        let users = Users::new_with_refreshed_list();
         let mut processes: Vec<ProcessInfo> = system.processes().iter().map(|(pid, process)| {
+            let (start_time, run_time) = super::process_times(process);
+            let (name, name_lossy) = super::process_name(process.name());
             ProcessInfo {
                 pid: pid.as_u32(),
-                name: process.name().to_string_lossy().into_owned(),
+                name,
                 cpu_usage: process.cpu_usage(),
                 memory_usage: process.memory(),
                 status: format!("{:?}", process.status()),
+                start_time,
+                run_time,
+                name_lossy,
+                user: super::process_user(&users, process),
+                exe_path: process.exe().map(|path| path.to_string_lossy().into_owned()),
+                cmd: super::process_cmd(process),
             }
         }).collect();
 
-        processes.sort_by(|a, b| b.cpu_usage.partial_cmp(&a.cpu_usage).unwrap_or(std::cmp::Ordering::Equal));
-        processes.truncate(50);
+        super::process::select_top(&mut processes, self.process_sort_by, self.process_limit);
         
         Ok(processes)
     }
@@ -303,27 +358,31 @@ impl SystemInfoCollector for CachedSystemCollector {
             hostname: System::host_name().unwrap_or_else(|| "Unknown".to_string()),
             uptime: System::uptime(),
             boot_time: System::boot_time(),
+            load_average: super::current_load_average(),
         })
     }
 
     fn collect_network_info(&self) -> Result<Vec<NetworkInfo>> {
-        let _system = self.system.write()
-            .map_err(|_| SystemMonitorError::SystemInfo("无法获取系统写锁".to_string()))?;
+        let mut networks = self.networks.write()
+            .map_err(|_| SystemMonitorError::SystemInfo("无法获取网络采样写锁".to_string()))?;
         
-        
-        let networks: Vec<NetworkInfo> = Networks::new_with_refreshed_list().iter().map(|(name, network)| {
-            NetworkInfo {
-                name: name.clone(),
-                bytes_received: network.received(),
-                bytes_sent: network.transmitted(),
-                packets_received: network.packets_received(),
-                packets_sent: network.packets_transmitted(),
-                errors_received: network.errors_on_received(),
-                errors_sent: network.errors_on_transmitted(),
-            }
-        }).collect();
-        
-        Ok(networks)
+        Ok(networks.sample())
+    }
+
+    fn collect_gpu_info(&self) -> Result<Vec<GpuInfo>> {
+        Ok(super::gpu::collect_gpus())
+    }
+
+    fn collect_temperatures(&self) -> Result<Vec<SensorInfo>> {
+        Ok(super::read_sensors())
+    }
+
+    fn kill_process(&self, pid: u32) -> Result<bool> {
+        let report = super::process::kill_processes(&[super::process::ProcessTarget { pid, start_time: None }]);
+        if !report.failed.is_empty() {
+            return Err(SystemMonitorError::SystemInfo(format!("无法结束进程 {}（可能权限不足）", pid)));
+        }
+        Ok(!report.killed.is_empty())
     }
 
     fn collect_system_snapshot(&self) -> Result<SystemSnapshot> {
@@ -332,8 +391,12 @@ impl SystemInfoCollector for CachedSystemCollector {
         let disks = self.collect_disk_info()?;
         let system = self.collect_system_info()?;
         let networks = self.collect_network_info().ok();
+        let gpus = self.collect_gpu_info()?;
+        let sensors = self.collect_temperatures()?;
         
-        Ok(SystemSnapshot::new(cpu, memory, disks, system, networks))
+        Ok(SystemSnapshot::new(cpu, memory, disks, system, networks)
+            .with_gpus(gpus)
+            .with_sensors(sensors))
     }
 }
 
@@ -395,6 +458,12 @@ mod tests {
         let snapshot = collector.collect_system_snapshot();
         assert!(snapshot.is_ok());
     }
+
+    #[tokio::test]
+    async fn test_kill_missing_process() {
+        let collector = CachedSystemCollector::new(Duration::from_secs(1)).unwrap();
+        assert!(!collector.kill_process(u32::MAX - 1).unwrap());
+    }
 }
 #[tokio::test]
     async fn test_cache_works() {
@@ -0,0 +1,415 @@
+//! 网络连接模块
+//!
+//! 列出TCP/UDP监听端口和连接。Linux下读取 `/proc/net` 中的套接字表，并通过
+//! `/proc/<pid>/fd` 将套接字关联到所属进程；Windows下通过 `GetExtendedTcpTable`/
+//! `GetExtendedUdpTable` 获取带所属进程的套接字表；其他平台暂不支持。
+
+use crate::error::Result;
+use crate::utils::t;
+#[cfg(not(windows))]
+use crate::error::SystemMonitorError;
+use serde::{Deserialize, Serialize};
+use std::net::SocketAddr;
+
+/// 传输层协议
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Protocol {
+    Tcp,
+    Udp,
+}
+
+impl Protocol {
+    /// 协议名称
+    pub fn label(&self) -> &'static str {
+        match self {
+            Protocol::Tcp => "TCP",
+            Protocol::Udp => "UDP",
+        }
+    }
+}
+
+/// 网络连接信息
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConnectionInfo {
+    /// 协议
+    pub protocol: Protocol,
+    /// 本地地址
+    pub local_addr: SocketAddr,
+    /// 远端地址，监听中或未连接的套接字为 `None`
+    pub remote_addr: Option<SocketAddr>,
+    /// 连接状态，如 `LISTEN`、`ESTABLISHED`；未连接的UDP套接字为 `UNCONN`
+    pub state: String,
+    /// 所属进程ID，无权查看该进程时为 `None`
+    pub owning_pid: Option<u32>,
+}
+
+impl ConnectionInfo {
+    /// 是否为监听中的端口（TCP监听或未连接的UDP套接字）
+    pub fn is_listening(&self) -> bool {
+        matches!(self.state.as_str(), "LISTEN" | "UNCONN")
+    }
+
+    /// 是否为已建立的连接
+    pub fn is_established(&self) -> bool {
+        self.state == "ESTABLISHED"
+    }
+}
+
+/// 连接列表的状态过滤
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ConnectionFilter {
+    /// 全部连接
+    #[default]
+    All,
+    /// 仅监听端口
+    Listen,
+    /// 仅已建立的连接
+    Established,
+}
+
+impl ConnectionFilter {
+    /// 所有过滤选项
+    pub const ALL: [ConnectionFilter; 3] = [ConnectionFilter::All, ConnectionFilter::Listen, ConnectionFilter::Established];
+
+    /// 过滤选项名称
+    pub fn label(&self) -> &'static str {
+        match self {
+            ConnectionFilter::All => t("全部"),
+            ConnectionFilter::Listen => "LISTEN",
+            ConnectionFilter::Established => "ESTABLISHED",
+        }
+    }
+
+    /// 判断连接是否符合过滤条件
+    pub fn matches(&self, connection: &ConnectionInfo) -> bool {
+        match self {
+            ConnectionFilter::All => true,
+            ConnectionFilter::Listen => connection.is_listening(),
+            ConnectionFilter::Established => connection.is_established(),
+        }
+    }
+}
+
+/// 当前平台是否支持列出网络连接，不支持时界面不显示连接列表
+pub const fn is_supported() -> bool {
+    cfg!(any(target_os = "linux", windows))
+}
+
+/// 采集当前的TCP/UDP连接
+///
+/// 无权读取套接字表时返回错误；无权查看的进程只是不关联所属进程。
+#[cfg(target_os = "linux")]
+pub fn collect_connections() -> Result<Vec<ConnectionInfo>> {
+    let owners = linux::socket_owners();
+    let mut connections = Vec::new();
+    for (path, protocol) in [
+        ("/proc/net/tcp", Protocol::Tcp),
+        ("/proc/net/tcp6", Protocol::Tcp),
+        ("/proc/net/udp", Protocol::Udp),
+        ("/proc/net/udp6", Protocol::Udp),
+    ] {
+        let content = match std::fs::read_to_string(path) {
+            Ok(content) => content,
+            // 未启用IPv6时没有对应的表
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => continue,
+            Err(e) if e.kind() == std::io::ErrorKind::PermissionDenied => {
+                return Err(SystemMonitorError::SystemInfo(format!("无权读取网络连接（{}），请以更高权限运行", path)));
+            }
+            Err(e) => return Err(e.into()),
+        };
+        connections.extend(content.lines().skip(1).filter_map(|line| {
+            let (mut connection, inode) = linux::parse_socket_line(line, protocol)?;
+            connection.owning_pid = owners.get(&inode).copied();
+            Some(connection)
+        }));
+    }
+    Ok(connections)
+}
+
+/// 采集当前的TCP/UDP连接
+#[cfg(windows)]
+pub fn collect_connections() -> Result<Vec<ConnectionInfo>> {
+    let mut connections = windows::tcp_connections()?;
+    connections.extend(windows::udp_connections()?);
+    Ok(connections)
+}
+
+/// 采集当前的TCP/UDP连接（当前平台不支持）
+#[cfg(not(any(target_os = "linux", windows)))]
+pub fn collect_connections() -> Result<Vec<ConnectionInfo>> {
+    Err(SystemMonitorError::SystemInfo("当前平台暂不支持列出网络连接".to_string()))
+}
+
+#[cfg(windows)]
+mod windows {
+    use super::{ConnectionInfo, Protocol};
+    use crate::error::{Result, SystemMonitorError};
+    use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+    use winapi::shared::iprtrmib::{TCP_TABLE_OWNER_PID_ALL, UDP_TABLE_OWNER_PID};
+    use winapi::shared::minwindef::{DWORD, FALSE};
+    use winapi::shared::tcpmib::{MIB_TCP6TABLE_OWNER_PID, MIB_TCPTABLE_OWNER_PID};
+    use winapi::shared::udpmib::{MIB_UDP6TABLE_OWNER_PID, MIB_UDPTABLE_OWNER_PID};
+    use winapi::shared::winerror::{ERROR_INSUFFICIENT_BUFFER, NO_ERROR};
+    use winapi::shared::ws2def::{AF_INET, AF_INET6};
+    use winapi::um::iphlpapi::{GetExtendedTcpTable, GetExtendedUdpTable};
+
+    /// `MIB_TCP_STATE` 对应的名称，与Linux下的名称保持一致
+    fn tcp_state(state: DWORD) -> &'static str {
+        match state {
+            1 => "CLOSE",
+            2 => "LISTEN",
+            3 => "SYN_SENT",
+            4 => "SYN_RECV",
+            5 => "ESTABLISHED",
+            6 => "FIN_WAIT1",
+            7 => "FIN_WAIT2",
+            8 => "CLOSE_WAIT",
+            9 => "CLOSING",
+            10 => "LAST_ACK",
+            11 => "TIME_WAIT",
+            12 => "DELETE_TCB",
+            _ => "UNKNOWN",
+        }
+    }
+
+    /// 端口以网络字节序保存在低16位
+    fn port(value: DWORD) -> u16 {
+        u16::from_be(value as u16)
+    }
+
+    /// IPv4地址以网络字节序保存
+    fn ipv4(value: DWORD) -> IpAddr {
+        IpAddr::V4(Ipv4Addr::from(value.to_ne_bytes()))
+    }
+
+    /// 按 `query` 报告的大小分配缓冲区并读取套接字表
+    ///
+    /// 两次调用之间表可能变大，缓冲区不足时重试。缓冲区按 `u32` 分配以满足表结构的对齐要求。
+    fn read_table<F>(name: &str, query: F) -> Result<Vec<u32>>
+    where
+        F: Fn(*mut u32, &mut DWORD) -> DWORD,
+    {
+        let mut size: DWORD = 0;
+        let mut buffer: Vec<u32> = Vec::new();
+        loop {
+            match query(buffer.as_mut_ptr(), &mut size) {
+                NO_ERROR => return Ok(buffer),
+                ERROR_INSUFFICIENT_BUFFER => {
+                    buffer = vec![0; (size as usize).div_ceil(std::mem::size_of::<u32>())];
+                }
+                code => {
+                    return Err(SystemMonitorError::SystemInfo(format!("读取{}失败（错误码 {}）", name, code)));
+                }
+            }
+        }
+    }
+
+    /// 读取TCP连接表（IPv4和IPv6）
+    pub(super) fn tcp_connections() -> Result<Vec<ConnectionInfo>> {
+        let mut connections = Vec::new();
+
+        let buffer = read_table("TCP连接表", |table, size| unsafe {
+            GetExtendedTcpTable(table.cast(), size, FALSE, AF_INET as u32, TCP_TABLE_OWNER_PID_ALL, 0)
+        })?;
+        // SAFETY: 调用成功后缓冲区中是完整的 MIB_TCPTABLE_OWNER_PID，行数由 dwNumEntries 给出
+        let rows = unsafe {
+            let table = &*(buffer.as_ptr() as *const MIB_TCPTABLE_OWNER_PID);
+            std::slice::from_raw_parts(table.table.as_ptr(), table.dwNumEntries as usize)
+        };
+        connections.extend(rows.iter().map(|row| {
+            let state = tcp_state(row.dwState);
+            ConnectionInfo {
+                protocol: Protocol::Tcp,
+                local_addr: SocketAddr::new(ipv4(row.dwLocalAddr), port(row.dwLocalPort)),
+                remote_addr: (state != "LISTEN")
+                    .then(|| SocketAddr::new(ipv4(row.dwRemoteAddr), port(row.dwRemotePort))),
+                state: state.to_string(),
+                owning_pid: Some(row.dwOwningPid),
+            }
+        }));
+
+        let buffer = read_table("TCP连接表", |table, size| unsafe {
+            GetExtendedTcpTable(table.cast(), size, FALSE, AF_INET6 as u32, TCP_TABLE_OWNER_PID_ALL, 0)
+        })?;
+        // SAFETY: 同上，缓冲区中是完整的 MIB_TCP6TABLE_OWNER_PID
+        let rows = unsafe {
+            let table = &*(buffer.as_ptr() as *const MIB_TCP6TABLE_OWNER_PID);
+            std::slice::from_raw_parts(table.table.as_ptr(), table.dwNumEntries as usize)
+        };
+        connections.extend(rows.iter().map(|row| {
+            let state = tcp_state(row.dwState);
+            ConnectionInfo {
+                protocol: Protocol::Tcp,
+                local_addr: SocketAddr::new(IpAddr::V6(Ipv6Addr::from(row.ucLocalAddr)), port(row.dwLocalPort)),
+                remote_addr: (state != "LISTEN").then(|| {
+                    SocketAddr::new(IpAddr::V6(Ipv6Addr::from(row.ucRemoteAddr)), port(row.dwRemotePort))
+                }),
+                state: state.to_string(),
+                owning_pid: Some(row.dwOwningPid),
+            }
+        }));
+
+        Ok(connections)
+    }
+
+    /// 读取UDP套接字表（IPv4和IPv6），Windows不提供UDP远端地址，均按未连接处理
+    pub(super) fn udp_connections() -> Result<Vec<ConnectionInfo>> {
+        let mut connections = Vec::new();
+
+        let buffer = read_table("UDP套接字表", |table, size| unsafe {
+            GetExtendedUdpTable(table.cast(), size, FALSE, AF_INET as u32, UDP_TABLE_OWNER_PID, 0)
+        })?;
+        // SAFETY: 调用成功后缓冲区中是完整的 MIB_UDPTABLE_OWNER_PID
+        let rows = unsafe {
+            let table = &*(buffer.as_ptr() as *const MIB_UDPTABLE_OWNER_PID);
+            std::slice::from_raw_parts(table.table.as_ptr(), table.dwNumEntries as usize)
+        };
+        connections.extend(rows.iter().map(|row| ConnectionInfo {
+            protocol: Protocol::Udp,
+            local_addr: SocketAddr::new(ipv4(row.dwLocalAddr), port(row.dwLocalPort)),
+            remote_addr: None,
+            state: "UNCONN".to_string(),
+            owning_pid: Some(row.dwOwningPid),
+        }));
+
+        let buffer = read_table("UDP套接字表", |table, size| unsafe {
+            GetExtendedUdpTable(table.cast(), size, FALSE, AF_INET6 as u32, UDP_TABLE_OWNER_PID, 0)
+        })?;
+        // SAFETY: 同上，缓冲区中是完整的 MIB_UDP6TABLE_OWNER_PID
+        let rows = unsafe {
+            let table = &*(buffer.as_ptr() as *const MIB_UDP6TABLE_OWNER_PID);
+            std::slice::from_raw_parts(table.table.as_ptr(), table.dwNumEntries as usize)
+        };
+        connections.extend(rows.iter().map(|row| ConnectionInfo {
+            protocol: Protocol::Udp,
+            local_addr: SocketAddr::new(IpAddr::V6(Ipv6Addr::from(row.ucLocalAddr)), port(row.dwLocalPort)),
+            remote_addr: None,
+            state: "UNCONN".to_string(),
+            owning_pid: Some(row.dwOwningPid),
+        }));
+
+        Ok(connections)
+    }
+}
+
+#[cfg(target_os = "linux")]
+mod linux {
+    use super::{ConnectionInfo, Protocol};
+    use std::collections::HashMap;
+    use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+
+    /// 内核TCP状态码对应的名称
+    fn tcp_state(code: u8) -> &'static str {
+        match code {
+            0x01 => "ESTABLISHED",
+            0x02 => "SYN_SENT",
+            0x03 => "SYN_RECV",
+            0x04 => "FIN_WAIT1",
+            0x05 => "FIN_WAIT2",
+            0x06 => "TIME_WAIT",
+            0x07 => "CLOSE",
+            0x08 => "CLOSE_WAIT",
+            0x09 => "LAST_ACK",
+            0x0A => "LISTEN",
+            0x0B => "CLOSING",
+            _ => "UNKNOWN",
+        }
+    }
+
+    /// 解析 `地址:端口` 形式的十六进制套接字地址，地址按主机字节序逐个32位字输出
+    fn parse_address(value: &str) -> Option<SocketAddr> {
+        let (address, port) = value.split_once(':')?;
+        let port = u16::from_str_radix(port, 16).ok()?;
+        let mut bytes = Vec::with_capacity(16);
+        for chunk in address.as_bytes().chunks(8) {
+            let word = u32::from_str_radix(std::str::from_utf8(chunk).ok()?, 16).ok()?;
+            bytes.extend_from_slice(&word.to_ne_bytes());
+        }
+        let ip = match bytes.len() {
+            4 => IpAddr::V4(Ipv4Addr::from(<[u8; 4]>::try_from(bytes).ok()?)),
+            16 => IpAddr::V6(Ipv6Addr::from(<[u8; 16]>::try_from(bytes).ok()?)),
+            _ => return None,
+        };
+        Some(SocketAddr::new(ip, port))
+    }
+
+    /// 解析套接字表中的一行，返回连接信息和套接字inode
+    pub(super) fn parse_socket_line(line: &str, protocol: Protocol) -> Option<(ConnectionInfo, u64)> {
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        let local_addr = parse_address(fields.get(1)?)?;
+        let remote_addr = parse_address(fields.get(2)?)?;
+        let state_code = u8::from_str_radix(fields.get(3)?, 16).ok()?;
+        let inode = fields.get(9)?.parse().ok()?;
+
+        let unspecified = remote_addr.ip().is_unspecified() && remote_addr.port() == 0;
+        let state = match protocol {
+            Protocol::Tcp => tcp_state(state_code),
+            Protocol::Udp if unspecified => "UNCONN",
+            Protocol::Udp => "ESTABLISHED",
+        };
+        let connection = ConnectionInfo {
+            protocol,
+            local_addr,
+            remote_addr: (!unspecified).then_some(remote_addr),
+            state: state.to_string(),
+            owning_pid: None,
+        };
+        Some((connection, inode))
+    }
+
+    /// 遍历 `/proc/<pid>/fd` 建立套接字inode到进程ID的映射，跳过无权查看的进程
+    pub(super) fn socket_owners() -> HashMap<u64, u32> {
+        let mut owners = HashMap::new();
+        let Ok(entries) = std::fs::read_dir("/proc") else {
+            return owners;
+        };
+        for entry in entries.flatten() {
+            let Some(pid) = entry.file_name().to_str().and_then(|name| name.parse::<u32>().ok()) else {
+                continue;
+            };
+            let Ok(fds) = std::fs::read_dir(entry.path().join("fd")) else {
+                continue;
+            };
+            for fd in fds.flatten() {
+                let Ok(target) = std::fs::read_link(fd.path()) else {
+                    continue;
+                };
+                let inode = target.to_str()
+                    .and_then(|target| target.strip_prefix("socket:["))
+                    .and_then(|target| target.strip_suffix(']'))
+                    .and_then(|inode| inode.parse().ok());
+                if let Some(inode) = inode {
+                    owners.entry(inode).or_insert(pid);
+                }
+            }
+        }
+        owners
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn test_parse_socket_line() {
+            let listen = "   0: 0100007F:0277 00000000:0000 0A 00000000:00000000 00:00000000 00000000     0        0 12345 1 0000000000000000 100 0 0 10 0";
+            let (connection, inode) = parse_socket_line(listen, Protocol::Tcp).unwrap();
+            assert_eq!(inode, 12345);
+            assert_eq!(connection.local_addr, "127.0.0.1:631".parse().unwrap());
+            assert_eq!(connection.remote_addr, None);
+            assert!(connection.is_listening());
+
+            let established = "   1: 0100007F:1F90 0100007F:C350 01 00000000:00000000 00:00000000 00000000  1000        0 23456 1";
+            let (connection, _) = parse_socket_line(established, Protocol::Tcp).unwrap();
+            assert_eq!(connection.remote_addr, Some("127.0.0.1:50000".parse().unwrap()));
+            assert!(connection.is_established());
+
+            let udp6 = "  2: 00000000000000000000000001000000:0035 00000000000000000000000000000000:0000 07 00000000:00000000 00:00000000 00000000     0        0 34567 2";
+            let (connection, _) = parse_socket_line(udp6, Protocol::Udp).unwrap();
+            assert_eq!(connection.local_addr, "[::1]:53".parse().unwrap());
+            assert_eq!(connection.state, "UNCONN");
+
+            assert!(parse_socket_line("  sl  local_address rem_address   st", Protocol::Tcp).is_none());
+        }
+    }
+}
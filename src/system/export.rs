@@ -0,0 +1,443 @@
+//! 数据导出模块
+//!
+//! 将采集到的数据写出为CSV、JSON等文件格式。
+
+use crate::config::{AppConfig, ExportConfig, ProcessCollectionMode};
+use crate::error::{Result, SystemMonitorError};
+use crate::system::info::{NetworkInfo, ProcessInfo, SystemSnapshot};
+use crate::system::metrics::{CpuStats, MemoryStats, MetricsCalculator};
+use crate::utils::{t, Formatter};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+/// 进程列表CSV表头
+pub const PROCESS_CSV_HEADER: &str = "pid,name,cpu,memory_bytes,status";
+
+/// 转义CSV字段
+///
+/// 字段中包含逗号、引号或换行时用双引号包裹，内部引号加倍。
+pub fn csv_escape(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// 将进程列表以CSV格式写入
+///
+/// 列表为空时只写入表头。
+pub fn write_processes_csv<W: Write>(writer: &mut W, processes: &[&ProcessInfo]) -> Result<()> {
+    writeln!(writer, "{}", PROCESS_CSV_HEADER)?;
+    for process in processes {
+        writeln!(
+            writer,
+            "{},{},{:.1},{},{}",
+            process.pid,
+            csv_escape(&process.name),
+            process.cpu_usage,
+            process.memory_usage,
+            csv_escape(&process.status)
+        )?;
+    }
+    Ok(())
+}
+
+/// 将进程列表导出到CSV文件
+pub fn export_processes_csv(path: &Path, processes: &[&ProcessInfo]) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        if !parent.as_os_str().is_empty() {
+            std::fs::create_dir_all(parent)?;
+        }
+    }
+
+    let mut writer = BufWriter::new(File::create(path)?);
+    write_processes_csv(&mut writer, processes)?;
+    writer.flush()?;
+    Ok(())
+}
+
+/// 快照历史CSV中磁盘列之前的固定列
+pub const SNAPSHOT_CSV_HEADER: &str = "timestamp,cpu_global,memory_percent";
+
+/// 将快照序列以CSV格式写入，每个快照一行
+///
+/// 每个出现过的挂载点对应一列 `disk:<挂载点>`（使用率），某个快照中没有该磁盘时留空；
+/// 最后两列为所有网络接口累计接收/发送的字节数，未采集网络时留空。快照为空时只写入表头。
+pub fn write_snapshots_csv<W: Write>(writer: &mut W, snapshots: &[SystemSnapshot]) -> Result<()> {
+    let mount_points: BTreeSet<&str> = snapshots.iter()
+        .flat_map(|snapshot| snapshot.disks.iter().map(|disk| disk.mount_point.as_str()))
+        .collect();
+
+    write!(writer, "{}", SNAPSHOT_CSV_HEADER)?;
+    for mount_point in &mount_points {
+        write!(writer, ",{}", csv_escape(&format!("disk:{}", mount_point)))?;
+    }
+    writeln!(writer, ",net_received_bytes,net_sent_bytes")?;
+
+    for snapshot in snapshots {
+        // 未采集的类别留空
+        write!(writer, "{}", snapshot.timestamp.to_rfc3339())?;
+        match snapshot.cpu_usage() {
+            Some(cpu) => write!(writer, ",{:.1}", cpu)?,
+            None => write!(writer, ",")?,
+        }
+        match snapshot.memory_usage() {
+            Some(memory) => write!(writer, ",{:.1}", memory)?,
+            None => write!(writer, ",")?,
+        }
+        for mount_point in &mount_points {
+            match snapshot.disks.iter().find(|disk| disk.mount_point == *mount_point) {
+                Some(disk) => write!(writer, ",{:.1}", disk.usage_percent)?,
+                None => write!(writer, ",")?,
+            }
+        }
+        match snapshot.networks.as_deref() {
+            Some(networks) => {
+                let total = NetworkInfo::aggregate(networks);
+                writeln!(writer, ",{},{}", total.bytes_received, total.bytes_sent)?;
+            }
+            None => writeln!(writer, ",,")?,
+        }
+    }
+    Ok(())
+}
+
+/// 将快照序列导出到CSV文件
+pub fn export_csv(path: &Path, snapshots: &[SystemSnapshot]) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        if !parent.as_os_str().is_empty() {
+            std::fs::create_dir_all(parent)?;
+        }
+    }
+
+    let mut writer = BufWriter::new(File::create(path)?);
+    write_snapshots_csv(&mut writer, snapshots)?;
+    writer.flush()?;
+    Ok(())
+}
+
+/// 生成快照的文本摘要，每行一项，用于命令行输出
+pub fn snapshot_summary(snapshot: &SystemSnapshot) -> String {
+    let mut lines = vec![
+        format!("{}: {}", t("时间"), snapshot.timestamp.with_timezone(&chrono::Local).format("%Y-%m-%d %H:%M:%S")),
+        format!("{}: {} ({} {})", t("主机"), snapshot.system.hostname, snapshot.system.os_name, snapshot.system.os_version),
+    ];
+    if let Some(cpu) = snapshot.cpu_usage() {
+        lines.push(format!("CPU: {} ({} {})", Formatter::percent(cpu as f64), snapshot.cpu.core_count, t("个核心")));
+    }
+    if let Some(memory) = snapshot.memory_usage() {
+        lines.push(format!(
+            "{}: {} ({} / {})",
+            t("内存"),
+            Formatter::percent(memory),
+            Formatter::bytes(snapshot.memory.used),
+            Formatter::bytes(snapshot.memory.total)
+        ));
+    }
+    if let Some(load) = snapshot.system.load_average {
+        lines.push(format!("{}: {}", t("平均负载"), load.display()));
+    }
+    for disk in &snapshot.disks {
+        lines.push(format!(
+            "{} {}: {} ({} / {})",
+            t("磁盘"),
+            disk.mount_point,
+            Formatter::percent(disk.usage_percent),
+            Formatter::bytes(disk.used_space),
+            Formatter::bytes(disk.total_space)
+        ));
+    }
+    if let Some(networks) = snapshot.networks.as_deref() {
+        let total = NetworkInfo::aggregate(networks);
+        lines.push(format!(
+            "{}: {} {} / {} {}",
+            t("网络"),
+            t("接收"),
+            Formatter::bytes(total.bytes_received),
+            t("发送"),
+            Formatter::bytes(total.bytes_sent)
+        ));
+    }
+    lines.join("\n")
+}
+
+/// 快照报告中的配置摘要
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConfigSummary {
+    /// 刷新间隔（毫秒）
+    pub refresh_interval_ms: u64,
+    /// 进程采集模式
+    pub process_collection_mode: ProcessCollectionMode,
+    /// CPU历史数据点数量
+    pub cpu_history_points: usize,
+    /// 内存历史数据点数量
+    pub memory_history_points: usize,
+    /// 是否启用告警
+    pub alerts_enabled: bool,
+}
+
+impl ConfigSummary {
+    /// 从应用程序配置提取摘要
+    pub fn from_config(config: &AppConfig) -> Self {
+        Self {
+            refresh_interval_ms: config.monitoring.refresh_interval_ms,
+            process_collection_mode: config.monitoring.process_collection_mode,
+            cpu_history_points: config.monitoring.cpu_history_points,
+            memory_history_points: config.monitoring.memory_history_points,
+            alerts_enabled: config.alerts.enabled,
+        }
+    }
+}
+
+/// 快照报告
+///
+/// 在快照之外附带保留窗口内的统计值、应用版本和配置摘要，便于问题反馈。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SnapshotReport {
+    /// 应用程序版本
+    pub app_version: String,
+    /// 报告生成时间
+    pub generated_at: chrono::DateTime<chrono::Utc>,
+    /// 配置摘要
+    pub config: ConfigSummary,
+    /// CPU统计
+    pub cpu_stats: CpuStats,
+    /// 内存统计
+    pub memory_stats: MemoryStats,
+    /// 系统快照
+    pub snapshot: SystemSnapshot,
+}
+
+impl SnapshotReport {
+    /// 由快照、统计和配置生成报告
+    pub fn new(snapshot: SystemSnapshot, metrics: &MetricsCalculator, config: &AppConfig) -> Self {
+        Self {
+            app_version: env!("CARGO_PKG_VERSION").to_string(),
+            generated_at: chrono::Utc::now(),
+            config: ConfigSummary::from_config(config),
+            cpu_stats: metrics.calculate_cpu_stats(),
+            memory_stats: metrics.calculate_memory_stats(),
+            snapshot,
+        }
+    }
+}
+
+/// 将数据以格式化的JSON写入文件
+pub fn export_json<T: Serialize>(path: &Path, value: &T) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        if !parent.as_os_str().is_empty() {
+            std::fs::create_dir_all(parent)?;
+        }
+    }
+
+    let mut writer = BufWriter::new(File::create(path)?);
+    serde_json::to_writer_pretty(&mut writer, value)?;
+    writer.flush()?;
+    Ok(())
+}
+
+/// 获取默认导出路径
+///
+/// 位于用户文档目录（不可用时依次退回主目录、当前目录），文件名带时间戳。
+pub fn default_export_path(kind: &str, extension: &str) -> PathBuf {
+    let directory = dirs::document_dir()
+        .or_else(dirs::home_dir)
+        .unwrap_or_else(|| PathBuf::from("."));
+    let timestamp = chrono::Local::now().format("%Y%m%d-%H%M%S");
+    directory.join(format!("{}-{}.{}", kind, timestamp, extension))
+}
+
+/// 展开文件名模板
+///
+/// 支持 `{hostname}`、`{timestamp}`、`{type}` 占位符，未知占位符原样保留。
+/// 替换值中的路径分隔符等非法字符会被替换为 `_`。
+pub fn expand_filename_template(template: &str, hostname: &str, timestamp: &str, kind: &str) -> String {
+    template
+        .replace("{hostname}", &sanitize_file_name(hostname))
+        .replace("{timestamp}", &sanitize_file_name(timestamp))
+        .replace("{type}", &sanitize_file_name(kind))
+}
+
+/// 替换文件名中不允许的字符
+fn sanitize_file_name(value: &str) -> String {
+    value
+        .chars()
+        .map(|c| if matches!(c, '/' | '\\' | ':' | '*' | '?' | '"' | '<' | '>' | '|') { '_' } else { c })
+        .collect()
+}
+
+/// 确保导出目录存在且可写
+pub fn ensure_writable_dir(directory: &Path) -> Result<()> {
+    std::fs::create_dir_all(directory)?;
+
+    // 通过创建临时文件确认目录可写
+    let probe = directory.join(format!(".system-monitor-write-test-{}", std::process::id()));
+    File::create(&probe).map_err(|e| {
+        SystemMonitorError::Config(format!("导出目录不可写: {} ({})", directory.display(), e))
+    })?;
+    let _ = std::fs::remove_file(&probe);
+    Ok(())
+}
+
+/// 按导出配置生成导出路径
+///
+/// 配置了导出目录时使用文件名模板并检查目录可写，否则退回默认导出路径。
+pub fn configured_export_path(config: &ExportConfig, hostname: &str, kind: &str, extension: &str) -> Result<PathBuf> {
+    let Some(ref directory) = config.directory else {
+        return Ok(default_export_path(kind, extension));
+    };
+
+    ensure_writable_dir(directory)?;
+    let timestamp = chrono::Local::now().format("%Y%m%d-%H%M%S").to_string();
+    let file_name = expand_filename_template(&config.filename_template, hostname, &timestamp, kind);
+    Ok(directory.join(format!("{}.{}", file_name, extension)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_snapshot_summary() {
+        use crate::system::info::{CpuInfo, DiskInfo, MemoryInfo, SystemInfo};
+
+        let cpu = CpuInfo { global_usage: 12.5, core_count: 8, ..Default::default() };
+        let memory = MemoryInfo { usage_percent: 40.0, ..Default::default() };
+        let disk = DiskInfo { mount_point: "/".to_string(), usage_percent: 70.0, ..Default::default() };
+        let system = SystemInfo { hostname: "server".to_string(), ..Default::default() };
+        let summary = snapshot_summary(&SystemSnapshot::new(cpu, memory, vec![disk], system, None));
+
+        let lines: Vec<&str> = summary.lines().collect();
+        assert!(lines[0].starts_with("时间: "));
+        assert!(lines[1].starts_with("主机: server"));
+        assert_eq!(lines[2], format!("CPU: {} (8 个核心)", Formatter::percent(12.5)));
+        assert!(lines.iter().any(|line| line.starts_with(&format!("磁盘 /: {}", Formatter::percent(70.0)))));
+        assert!(!summary.contains("网络"));
+    }
+
+    #[test]
+    fn test_csv_escape() {
+        assert_eq!(csv_escape("bash"), "bash");
+        assert_eq!(csv_escape("a,b"), "\"a,b\"");
+        assert_eq!(csv_escape("say \"hi\""), "\"say \"\"hi\"\"\"");
+    }
+
+    #[test]
+    fn test_expand_filename_template() {
+        assert_eq!(
+            expand_filename_template("{hostname}-{type}-{timestamp}", "box", "20240101-120000", "processes"),
+            "box-processes-20240101-120000"
+        );
+        assert_eq!(expand_filename_template("{type}-{unknown}", "box", "t", "history"), "history-{unknown}");
+        assert_eq!(expand_filename_template("{hostname}", "a/b:c", "t", "x"), "a_b_c");
+    }
+
+    #[test]
+    fn test_configured_export_path() {
+        let directory = std::env::temp_dir().join(format!("system-monitor-export-test-{}", std::process::id()));
+        let config = ExportConfig {
+            directory: Some(directory.clone()),
+            filename_template: "{hostname}-{type}".to_string(),
+        };
+
+        let path = configured_export_path(&config, "box", "processes", "csv").unwrap();
+        assert_eq!(path, directory.join("box-processes.csv"));
+        assert!(directory.is_dir());
+
+        let path = configured_export_path(&ExportConfig::default(), "box", "processes", "csv").unwrap();
+        assert_eq!(path.extension().and_then(|e| e.to_str()), Some("csv"));
+
+        let _ = std::fs::remove_dir_all(&directory);
+    }
+
+    #[test]
+    fn test_snapshot_report_json() {
+        use crate::system::info::{CpuInfo, MemoryInfo, SystemInfo};
+        use std::time::Duration;
+
+        let mut metrics = MetricsCalculator::new(100, Duration::from_secs(3600));
+        metrics.add_cpu_data(20.0);
+        metrics.add_cpu_data(40.0);
+        let snapshot = SystemSnapshot::new(CpuInfo::default(), MemoryInfo::default(), vec![], SystemInfo::default(), None);
+        let report = SnapshotReport::new(snapshot, &metrics, &AppConfig::default());
+
+        let path = std::env::temp_dir().join(format!("system-monitor-report-test-{}.json", std::process::id()));
+        export_json(&path, &report).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(value["app_version"], env!("CARGO_PKG_VERSION"));
+        assert_eq!(value["cpu_stats"]["average"], 30.0);
+        assert_eq!(value["config"]["refresh_interval_ms"], 1000);
+        assert!(value["snapshot"]["cpu"].is_object());
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_write_snapshots_csv() {
+        use crate::system::info::{CpuInfo, DiskInfo, MemoryInfo, SystemInfo};
+
+        let mut output = Vec::new();
+        write_snapshots_csv(&mut output, &[]).unwrap();
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            format!("{},net_received_bytes,net_sent_bytes\n", SNAPSHOT_CSV_HEADER)
+        );
+
+        let disk = |mount_point: &str, usage_percent: f64| DiskInfo {
+            mount_point: mount_point.to_string(),
+            usage_percent,
+            ..Default::default()
+        };
+        let network = |bytes_received: u64, bytes_sent: u64| NetworkInfo {
+            bytes_received,
+            bytes_sent,
+            ..Default::default()
+        };
+        let cpu = CpuInfo { global_usage: 12.5, ..Default::default() };
+        let first = SystemSnapshot::new(cpu, MemoryInfo::default(), vec![disk("/", 40.0)], SystemInfo::default(), None);
+        let second = SystemSnapshot::new(
+            CpuInfo::default(),
+            MemoryInfo::default(),
+            vec![disk("/", 41.0), disk("/data", 70.0)],
+            SystemInfo::default(),
+            Some(vec![network(100, 10), network(200, 20)]),
+        );
+
+        let mut output = Vec::new();
+        write_snapshots_csv(&mut output, &[first, second]).unwrap();
+        let output = String::from_utf8(output).unwrap();
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines[0], "timestamp,cpu_global,memory_percent,disk:/,disk:/data,net_received_bytes,net_sent_bytes");
+        assert!(lines[1].ends_with(",12.5,0.0,40.0,,,"));
+        assert!(lines[2].ends_with(",0.0,0.0,41.0,70.0,300,30"));
+    }
+
+    #[test]
+    fn test_write_processes_csv() {
+        let mut output = Vec::new();
+        write_processes_csv(&mut output, &[]).unwrap();
+        assert_eq!(String::from_utf8(output).unwrap(), format!("{}\n", PROCESS_CSV_HEADER));
+
+        let process = ProcessInfo {
+            pid: 7,
+            name: "web, server".to_string(),
+            cpu_usage: 12.34,
+            memory_usage: 2048,
+            status: "Run".to_string(),
+            start_time: None,
+            run_time: None,
+            name_lossy: false,
+            user: None,
+            exe_path: None,
+            cmd: Vec::new(),
+        };
+        let mut output = Vec::new();
+        write_processes_csv(&mut output, &[&process]).unwrap();
+        let text = String::from_utf8(output).unwrap();
+        assert_eq!(text.lines().nth(1), Some("7,\"web, server\",12.3,2048,Run"));
+    }
+}
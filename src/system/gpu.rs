@@ -0,0 +1,90 @@
+//! GPU信息采集模块
+//!
+//! sysinfo 不提供GPU信息，这里通过NVIDIA驱动附带的 `nvidia-smi` 查询。
+//! 没有NVIDIA显卡或驱动时返回空列表，不影响快照中的其他数据。
+
+use crate::system::info::GpuInfo;
+use std::process::Command;
+
+#[cfg(windows)]
+use std::os::windows::process::CommandExt;
+
+/// 启动控制台程序时不创建控制台窗口，避免每次查询都闪出一个窗口
+#[cfg(windows)]
+const CREATE_NO_WINDOW: u32 = 0x0800_0000;
+
+/// `nvidia-smi` 查询的字段，顺序与 [`parse_nvidia_smi`] 的解析顺序一致
+const QUERY_FIELDS: &str = "name,utilization.gpu,memory.total,memory.used,temperature.gpu,driver_version";
+
+/// 采集所有GPU的信息，无可用GPU或驱动时返回空列表
+pub fn collect_gpus() -> Vec<GpuInfo> {
+    let mut command = Command::new("nvidia-smi");
+    command
+        .arg(format!("--query-gpu={}", QUERY_FIELDS))
+        .arg("--format=csv,noheader,nounits");
+    #[cfg(windows)]
+    command.creation_flags(CREATE_NO_WINDOW);
+
+    let output = command.output();
+
+    match output {
+        Ok(output) if output.status.success() => parse_nvidia_smi(&String::from_utf8_lossy(&output.stdout)),
+        Ok(output) => {
+            log::debug!("nvidia-smi 执行失败（{}）", output.status);
+            Vec::new()
+        }
+        Err(e) => {
+            log::debug!("无法执行 nvidia-smi: {}", e);
+            Vec::new()
+        }
+    }
+}
+
+/// 解析 `nvidia-smi --format=csv,noheader,nounits` 的输出，每行一块GPU
+///
+/// 显存单位为MiB；不支持的字段显示为 `[N/A]`，数值字段按0处理，温度为 `None`。
+pub fn parse_nvidia_smi(output: &str) -> Vec<GpuInfo> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+            let [name, usage, memory_total, memory_used, temperature, driver_version] = fields[..] else {
+                return None;
+            };
+            let mib = |value: &str| value.parse::<u64>().unwrap_or(0) * 1024 * 1024;
+            Some(GpuInfo {
+                name: name.to_string(),
+                usage_percent: usage.parse().unwrap_or(0.0),
+                memory_total: mib(memory_total),
+                memory_used: mib(memory_used),
+                temperature_celsius: temperature.parse().ok(),
+                driver_version: driver_version.to_string(),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_nvidia_smi() {
+        let output = "NVIDIA GeForce RTX 3080, 42, 10240, 2048, 61, 550.54.14\n\
+                      Tesla T4, [N/A], 15360, 0, [N/A], 550.54.14\n\
+                      malformed line\n";
+        let gpus = parse_nvidia_smi(output);
+        assert_eq!(gpus.len(), 2);
+
+        assert_eq!(gpus[0].name, "NVIDIA GeForce RTX 3080");
+        assert_eq!(gpus[0].usage_percent, 42.0);
+        assert_eq!(gpus[0].memory_total, 10240 * 1024 * 1024);
+        assert_eq!(gpus[0].memory_used, 2048 * 1024 * 1024);
+        assert_eq!(gpus[0].temperature_celsius, Some(61.0));
+        assert_eq!(gpus[0].driver_version, "550.54.14");
+
+        assert_eq!(gpus[1].usage_percent, 0.0);
+        assert_eq!(gpus[1].temperature_celsius, None);
+        assert!(parse_nvidia_smi("").is_empty());
+    }
+}
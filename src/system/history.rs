@@ -0,0 +1,213 @@
+//! 快照历史模块
+//!
+//! 保留最近的完整系统快照，同时受数量上限和近似内存预算约束，
+//! 避免进程较多的系统上历史记录无限增长。
+
+use crate::system::info::{CpuCoreInfo, DiskInfo, GpuInfo, NetworkInfo, ProcessInfo, SensorInfo, SystemSnapshot};
+use std::collections::VecDeque;
+use std::mem::size_of;
+
+/// 估算快照占用的内存（字节）
+///
+/// 只统计结构体本身和堆上的字符串、列表，结果为近似值。
+pub fn estimate_snapshot_size(snapshot: &SystemSnapshot) -> usize {
+    let system = &snapshot.system;
+    let system_strings = system.os_name.capacity()
+        + system.os_version.capacity()
+        + system.kernel_version.capacity()
+        + system.hostname.capacity();
+
+    let cores: usize = snapshot.cpu.cores.iter()
+        .map(|core| size_of::<CpuCoreInfo>() + core.name.capacity())
+        .sum();
+    let disks: usize = snapshot.disks.iter()
+        .map(|disk| {
+            size_of::<DiskInfo>() + disk.name.capacity() + disk.mount_point.capacity() + disk.file_system.capacity()
+        })
+        .sum();
+    let networks: usize = snapshot.networks.iter()
+        .flatten()
+        .map(|network| size_of::<NetworkInfo>() + network.name.capacity())
+        .sum();
+    let processes: usize = snapshot.processes.iter()
+        .flatten()
+        .map(|process| size_of::<ProcessInfo>() + process.name.capacity() + process.status.capacity())
+        .sum();
+
+    let sensors: usize = snapshot.sensors.iter()
+        .map(|sensor| size_of::<SensorInfo>() + sensor.label.capacity())
+        .sum();
+
+    let gpus: usize = snapshot.gpus.iter()
+        .flatten()
+        .map(|gpu| size_of::<GpuInfo>() + gpu.name.capacity() + gpu.driver_version.capacity())
+        .sum();
+
+    size_of::<SystemSnapshot>() + system_strings + cores + disks + networks + processes + sensors + gpus
+}
+
+/// 快照历史（最旧的在前）
+#[derive(Debug, Clone)]
+pub struct SnapshotHistory {
+    entries: VecDeque<(usize, SystemSnapshot)>,
+    max_entries: usize,
+    max_bytes: usize,
+    total_bytes: usize,
+}
+
+impl SnapshotHistory {
+    /// 创建新的快照历史
+    pub fn new(max_entries: usize, max_bytes: usize) -> Self {
+        Self {
+            entries: VecDeque::new(),
+            max_entries,
+            max_bytes,
+            total_bytes: 0,
+        }
+    }
+
+    /// 按MB设置内存预算创建快照历史
+    pub fn with_budget_mb(max_entries: usize, max_mb: usize) -> Self {
+        Self::new(max_entries, max_mb.saturating_mul(1024 * 1024))
+    }
+
+    /// 添加快照，超出数量上限或内存预算时淘汰最旧的快照
+    pub fn push(&mut self, snapshot: SystemSnapshot) {
+        let size = estimate_snapshot_size(&snapshot);
+        self.entries.push_back((size, snapshot));
+        self.total_bytes += size;
+
+        while self.entries.len() > self.max_entries {
+            self.pop_oldest();
+        }
+
+        // 至少保留最新的快照，即使它本身超出预算
+        let mut evicted = 0;
+        while self.total_bytes > self.max_bytes && self.entries.len() > 1 {
+            self.pop_oldest();
+            evicted += 1;
+        }
+        if evicted > 0 {
+            log::info!(
+                "快照历史超出内存预算 {} 字节，已淘汰 {} 个最旧的快照（当前约 {} 字节）",
+                self.max_bytes,
+                evicted,
+                self.total_bytes
+            );
+        }
+    }
+
+    /// 移除最旧的快照
+    fn pop_oldest(&mut self) {
+        if let Some((size, _)) = self.entries.pop_front() {
+            self.total_bytes -= size;
+        }
+    }
+
+    /// 更新数量上限和内存预算，立即按新限制淘汰
+    pub fn set_limits(&mut self, max_entries: usize, max_bytes: usize) {
+        self.max_entries = max_entries;
+        self.max_bytes = max_bytes;
+
+        while self.entries.len() > self.max_entries
+            || (self.total_bytes > self.max_bytes && self.entries.len() > 1)
+        {
+            self.pop_oldest();
+        }
+    }
+
+    /// 按MB更新数量上限和内存预算
+    pub fn set_limits_mb(&mut self, max_entries: usize, max_mb: usize) {
+        self.set_limits(max_entries, max_mb.saturating_mul(1024 * 1024));
+    }
+
+    /// 遍历快照（最旧的在前）
+    pub fn iter(&self) -> impl Iterator<Item = &SystemSnapshot> {
+        self.entries.iter().map(|(_, snapshot)| snapshot)
+    }
+
+    /// 最新的快照
+    pub fn latest(&self) -> Option<&SystemSnapshot> {
+        self.entries.back().map(|(_, snapshot)| snapshot)
+    }
+
+    /// 快照数量
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// 是否为空
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// 估算的总内存占用（字节）
+    pub fn estimated_bytes(&self) -> usize {
+        self.total_bytes
+    }
+
+    /// 清空历史
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.total_bytes = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::system::info::{CpuInfo, MemoryInfo, SystemInfo};
+
+    fn snapshot(process_count: usize) -> SystemSnapshot {
+        let processes = (0..process_count as u32)
+            .map(|pid| ProcessInfo {
+                pid,
+                name: format!("process-{}", pid),
+                cpu_usage: 0.0,
+                memory_usage: 0,
+                status: "Run".to_string(),
+                start_time: None,
+                run_time: None,
+                name_lossy: false,
+                user: None,
+                exe_path: None,
+                cmd: Vec::new(),
+            })
+            .collect();
+        SystemSnapshot::new(CpuInfo::default(), MemoryInfo::default(), vec![], SystemInfo::default(), None)
+            .with_processes(processes)
+    }
+
+    #[test]
+    fn test_estimate_grows_with_processes() {
+        assert!(estimate_snapshot_size(&snapshot(100)) > estimate_snapshot_size(&snapshot(0)));
+    }
+
+    #[test]
+    fn test_count_cap() {
+        let mut history = SnapshotHistory::new(2, usize::MAX);
+        for _ in 0..3 {
+            history.push(snapshot(0));
+        }
+        assert_eq!(history.len(), 2);
+    }
+
+    #[test]
+    fn test_memory_budget_evicts_oldest() {
+        let size = estimate_snapshot_size(&snapshot(50));
+        let mut history = SnapshotHistory::new(100, size * 3);
+        for _ in 0..5 {
+            history.push(snapshot(50));
+        }
+        assert_eq!(history.len(), 3);
+        assert!(history.estimated_bytes() <= size * 3);
+
+        // 单个快照超出预算时仍保留最新的一个
+        history.set_limits(100, 1);
+        assert_eq!(history.len(), 1);
+
+        history.clear();
+        assert!(history.is_empty());
+        assert_eq!(history.estimated_bytes(), 0);
+    }
+}
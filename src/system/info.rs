@@ -2,6 +2,7 @@
 //! 
 //! 定义了各种系统信息的数据结构，包括CPU、内存、磁盘、进程等信息。
 
+use crate::utils::{t, Formatter, MathUtils};
 use serde::{Deserialize, Serialize};
 
 /// CPU信息
@@ -11,8 +12,50 @@ pub struct CpuInfo {
     pub global_usage: f32,
     /// CPU核心信息列表
     pub cores: Vec<CpuCoreInfo>,
-    /// CPU核心数量
+    /// CPU核心数量（逻辑核心）
     pub core_count: usize,
+    /// 物理核心数量（无法获取时为 `None`）
+    #[serde(default)]
+    pub physical_core_count: Option<usize>,
+    /// CPU型号（无法获取时为空）
+    #[serde(default)]
+    pub brand: String,
+    /// CPU厂商标识（无法获取时为空）
+    #[serde(default)]
+    pub vendor: String,
+}
+
+impl CpuInfo {
+    /// 用于显示的CPU型号，无法获取时为"未知"
+    pub fn brand_display(&self) -> &str {
+        if self.brand.is_empty() { t("未知") } else { &self.brand }
+    }
+
+    /// 用于显示的CPU厂商，无法获取时为"未知"
+    pub fn vendor_display(&self) -> &str {
+        if self.vendor.is_empty() { t("未知") } else { &self.vendor }
+    }
+
+    /// 用于显示的核心数，如 "8 物理核心 / 16 逻辑核心"，物理核心数未知时只显示逻辑核心数
+    pub fn core_count_display(&self) -> String {
+        match self.physical_core_count {
+            Some(physical) => format!("{} {} / {} {}", physical, t("物理核心"), self.core_count, t("逻辑核心")),
+            None => format!("{} {}", self.core_count, t("逻辑核心")),
+        }
+    }
+
+    /// 计算等效繁忙核心数（全局使用率 × 核心数）
+    pub fn busy_cores(&self) -> f32 {
+        self.global_usage / 100.0 * self.core_count as f32
+    }
+
+    /// 将全局和各核心使用率限制在0-100%之间
+    pub fn clamp_usage(&mut self) {
+        self.global_usage = self.global_usage.clamp(0.0, 100.0);
+        for core in &mut self.cores {
+            core.usage = core.usage.clamp(0.0, 100.0);
+        }
+    }
 }
 
 /// CPU核心信息
@@ -39,6 +82,18 @@ pub struct MemoryInfo {
     pub free: u64,
     /// 使用率百分比
     pub usage_percent: f64,
+    /// 可回收内存 (bytes)，即缓存/缓冲区的估算值（可用 - 空闲），无法获取时为0
+    #[serde(default)]
+    pub reclaimable: u64,
+    /// 交换空间/页面文件总量 (bytes)，未启用时为0
+    #[serde(default)]
+    pub swap_total: u64,
+    /// 已使用的交换空间 (bytes)
+    #[serde(default)]
+    pub swap_used: u64,
+    /// 交换空间使用率百分比（未启用交换空间时为0）
+    #[serde(default)]
+    pub swap_usage_percent: f64,
 }
 
 /// 磁盘信息
@@ -58,6 +113,23 @@ pub struct DiskInfo {
     pub used_space: u64,
     /// 使用率百分比
     pub usage_percent: f64,
+    /// 读取速率 (bytes/s)，首次采样为0
+    #[serde(default)]
+    pub read_bytes_per_sec: f64,
+    /// 写入速率 (bytes/s)，首次采样为0
+    #[serde(default)]
+    pub write_bytes_per_sec: f64,
+}
+
+impl DiskInfo {
+    /// 由总空间和可用空间计算已用空间和使用率
+    ///
+    /// 部分文件系统（保留块、网络挂载）报告的可用空间可能短暂超过总空间，此时已用空间按0计算，
+    /// 使用率限制在0-100之间。
+    pub fn space_usage(total: u64, available: u64) -> (u64, f64) {
+        let used = total.saturating_sub(available);
+        (used, MathUtils::percentage(used as f64, total as f64).clamp(0.0, 100.0))
+    }
 }
 
 /// 进程信息
@@ -73,6 +145,35 @@ pub struct ProcessInfo {
     pub memory_usage: u64,
     /// 进程状态
     pub status: String,
+    /// 启动时间（Unix时间戳，秒），未知时为 `None`
+    #[serde(default)]
+    pub start_time: Option<u64>,
+    /// 已运行时长（秒），启动时间未知时为 `None`
+    #[serde(default)]
+    pub run_time: Option<u64>,
+    /// 原始名称不是有效UTF-8，`name` 中的无效字节已被替换
+    #[serde(default)]
+    pub name_lossy: bool,
+    /// 所属用户名，无法解析时为 `None`
+    #[serde(default)]
+    pub user: Option<String>,
+    /// 可执行文件路径，无法获取时为 `None`
+    #[serde(default)]
+    pub exe_path: Option<String>,
+    /// 命令行参数（含程序名），无权限读取时为空
+    #[serde(default)]
+    pub cmd: Vec<String>,
+}
+
+impl ProcessInfo {
+    /// 完整命令行，参数以空格连接；无法获取命令行时使用可执行文件路径
+    pub fn command_line(&self) -> String {
+        if self.cmd.is_empty() {
+            self.exe_path.clone().unwrap_or_default()
+        } else {
+            self.cmd.join(" ")
+        }
+    }
 }
 
 /// 系统基本信息
@@ -90,10 +191,41 @@ pub struct SystemInfo {
     pub uptime: u64,
     /// 启动时间 (Unix时间戳)
     pub boot_time: u64,
+    /// 平均负载（Windows不提供时为 `None`）
+    #[serde(default)]
+    pub load_average: Option<LoadAverage>,
+}
+
+/// 1、5、15分钟平均负载
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Default)]
+pub struct LoadAverage {
+    /// 1分钟平均负载
+    pub one: f64,
+    /// 5分钟平均负载
+    pub five: f64,
+    /// 15分钟平均负载
+    pub fifteen: f64,
+}
+
+impl LoadAverage {
+    /// 生成显示文本，如 `0.52 / 0.58 / 0.59`
+    pub fn display(&self) -> String {
+        format!("{:.2} / {:.2} / {:.2}", self.one, self.five, self.fifteen)
+    }
+}
+
+impl SystemInfo {
+    /// 生成启动时间（按指定格式的本地时间）和运行时间字符串
+    pub fn boot_time_and_uptime(&self, datetime_format: &str) -> (String, String) {
+        (
+            TimeFormatter::timestamp_to_local_string_with_format(self.boot_time, datetime_format),
+            Formatter::duration(self.uptime),
+        )
+    }
 }
 
 /// 网络接口信息
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
 pub struct NetworkInfo {
     /// 接口名称
     pub name: String,
@@ -109,6 +241,97 @@ pub struct NetworkInfo {
     pub errors_received: u64,
     /// 发送错误数
     pub errors_sent: u64,
+    /// 接收速率 (bytes/s)
+    #[serde(default)]
+    pub recv_rate_bps: f64,
+    /// 发送速率 (bytes/s)
+    #[serde(default)]
+    pub send_rate_bps: f64,
+}
+
+/// 虚拟接口的名称前缀（回环、容器、虚拟机网桥和VPN隧道）
+const VIRTUAL_INTERFACE_PREFIXES: &[&str] = &[
+    "lo", "docker", "veth", "br-", "virbr", "vmnet", "vboxnet", "tun", "tap", "utun", "wg", "zt",
+];
+
+impl NetworkInfo {
+    /// 是否为虚拟接口
+    ///
+    /// 按名称前缀判断，这些接口的流量通常也会经过物理接口，计入总计会重复统计。
+    pub fn is_virtual(&self) -> bool {
+        let name = self.name.to_ascii_lowercase();
+        name.contains("loopback") || VIRTUAL_INTERFACE_PREFIXES.iter().any(|prefix| name.starts_with(prefix))
+    }
+
+    /// 汇总多个接口的统计数据
+    pub fn aggregate<'a>(networks: impl IntoIterator<Item = &'a NetworkInfo>) -> NetworkInfo {
+        networks.into_iter().fold(
+            NetworkInfo { name: t("总计").to_string(), ..Default::default() },
+            |mut total, network| {
+                total.bytes_received += network.bytes_received;
+                total.bytes_sent += network.bytes_sent;
+                total.packets_received += network.packets_received;
+                total.packets_sent += network.packets_sent;
+                total.errors_received += network.errors_received;
+                total.errors_sent += network.errors_sent;
+                total.recv_rate_bps += network.recv_rate_bps;
+                total.send_rate_bps += network.send_rate_bps;
+                total
+            },
+        )
+    }
+}
+
+/// 温度传感器信息
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct SensorInfo {
+    /// 传感器名称
+    pub label: String,
+    /// 当前温度（摄氏度，无法读取时为 `None`）
+    pub temperature: Option<f32>,
+    /// 临界温度（摄氏度）
+    pub critical: Option<f32>,
+    /// 运行期间记录到的最高温度（摄氏度）
+    #[serde(default)]
+    pub max: Option<f32>,
+}
+
+impl SensorInfo {
+    /// 当前温度最高的传感器，没有可读温度时为 `None`
+    pub fn hottest(sensors: &[SensorInfo]) -> Option<&SensorInfo> {
+        sensors.iter()
+            .filter(|sensor| sensor.temperature.is_some_and(f32::is_finite))
+            .max_by(|a, b| a.temperature.partial_cmp(&b.temperature).unwrap_or(std::cmp::Ordering::Equal))
+    }
+
+    /// 当前温度是否已达到临界温度
+    pub fn is_critical(&self) -> bool {
+        matches!((self.temperature, self.critical), (Some(temperature), Some(critical)) if temperature >= critical)
+    }
+}
+
+/// GPU信息
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct GpuInfo {
+    /// GPU名称
+    pub name: String,
+    /// GPU使用率
+    pub usage_percent: f32,
+    /// 显存总量 (bytes)
+    pub memory_total: u64,
+    /// 已用显存 (bytes)
+    pub memory_used: u64,
+    /// 温度（摄氏度，无法读取时为 `None`）
+    pub temperature_celsius: Option<f32>,
+    /// 驱动版本
+    pub driver_version: String,
+}
+
+impl GpuInfo {
+    /// 显存使用率
+    pub fn memory_usage_percent(&self) -> f64 {
+        crate::utils::MathUtils::percentage(self.memory_used as f64, self.memory_total as f64)
+    }
 }
 
 /// 系统性能快照
@@ -126,6 +349,26 @@ pub struct SystemSnapshot {
     pub system: SystemInfo,
     /// 网络信息列表（可选）
     pub networks: Option<Vec<NetworkInfo>>,
+    /// 进程信息列表（仅在需要时采集）
+    #[serde(default)]
+    pub processes: Option<Vec<ProcessInfo>>,
+    /// 温度传感器（按较慢的独立间隔采样，取最近一次的结果）
+    #[serde(default)]
+    pub sensors: Vec<SensorInfo>,
+    /// GPU信息列表（可选）
+    #[serde(default)]
+    pub gpus: Option<Vec<GpuInfo>>,
+    /// 是否采集了CPU信息，未采集时 `cpu` 为默认值，不参与统计和告警
+    #[serde(default = "default_collected")]
+    pub cpu_collected: bool,
+    /// 是否采集了内存信息，未采集时 `memory` 为默认值，不参与统计和告警
+    #[serde(default = "default_collected")]
+    pub memory_collected: bool,
+}
+
+/// 旧版本导出的快照没有采集标记，视为全部已采集
+fn default_collected() -> bool {
+    true
 }
 
 impl SystemSnapshot {
@@ -144,22 +387,71 @@ impl SystemSnapshot {
             disks,
             system,
             networks,
+            processes: None,
+            sensors: Vec::new(),
+            gpus: None,
+            cpu_collected: true,
+            memory_collected: true,
         }
     }
 
+    /// 标记CPU信息未采集
+    pub fn without_cpu(mut self) -> Self {
+        self.cpu_collected = false;
+        self
+    }
+
+    /// 标记内存信息未采集
+    pub fn without_memory(mut self) -> Self {
+        self.memory_collected = false;
+        self
+    }
+
+    /// 全局CPU使用率，未采集时为 `None`
+    pub fn cpu_usage(&self) -> Option<f32> {
+        self.cpu_collected.then_some(self.cpu.global_usage)
+    }
+
+    /// 内存使用率，未采集时为 `None`
+    pub fn memory_usage(&self) -> Option<f64> {
+        self.memory_collected.then_some(self.memory.usage_percent)
+    }
+
+    /// 附加GPU信息
+    pub fn with_gpus(mut self, gpus: Vec<GpuInfo>) -> Self {
+        self.gpus = Some(gpus);
+        self
+    }
+
+    /// 附加温度传感器信息
+    pub fn with_sensors(mut self, sensors: Vec<SensorInfo>) -> Self {
+        self.sensors = sensors;
+        self
+    }
+
+    /// 附加进程信息
+    pub fn with_processes(mut self, processes: Vec<ProcessInfo>) -> Self {
+        self.processes = Some(processes);
+        self
+    }
+
     /// 计算总体系统负载评分 (0-100)
+    ///
+    /// CPU、内存和磁盘按 4:4:2 加权，未采集的项不计入，其余项按比例分摊权重。
     pub fn calculate_system_load_score(&self) -> f32 {
-        let cpu_weight = 0.4;
-        let memory_weight = 0.4;
-        let disk_weight = 0.2;
-
-        let cpu_score = self.cpu.global_usage;
-        let memory_score = self.memory.usage_percent as f32;
         let disk_score = self.disks.iter()
             .map(|d| d.usage_percent as f32)
-            .fold(0.0f32, |acc, x| acc.max(x)); // 使用最高的磁盘使用率
-
-        cpu_score * cpu_weight + memory_score * memory_weight + disk_score * disk_weight
+            .reduce(f32::max); // 使用最高的磁盘使用率
+        let components = [
+            (self.cpu_usage(), 0.4),
+            (self.memory_usage().map(|usage| usage as f32), 0.4),
+            (disk_score, 0.2),
+        ];
+
+        let (weighted, total_weight) = components.iter()
+            .filter_map(|(score, weight)| score.map(|score| (score * weight, *weight)))
+            .fold((0.0, 0.0), |(sum, total), (score, weight)| (sum + score, total + weight));
+        if total_weight > 0.0 { weighted / total_weight } else { 0.0 }
     }
 
     /// 获取系统健康状态
@@ -206,11 +498,11 @@ impl SystemHealthStatus {
     /// 获取状态描述
     pub fn description(&self) -> &'static str {
         match self {
-            SystemHealthStatus::Excellent => "系统运行优秀",
-            SystemHealthStatus::Good => "系统运行良好",
-            SystemHealthStatus::Fair => "系统运行一般",
-            SystemHealthStatus::Poor => "系统负载较高",
-            SystemHealthStatus::Critical => "系统负载严重",
+            SystemHealthStatus::Excellent => t("系统运行优秀"),
+            SystemHealthStatus::Good => t("系统运行良好"),
+            SystemHealthStatus::Fair => t("系统运行一般"),
+            SystemHealthStatus::Poor => t("系统负载较高"),
+            SystemHealthStatus::Critical => t("系统负载严重"),
         }
     }
 }
@@ -221,26 +513,7 @@ pub struct MemoryUnit;
 impl MemoryUnit {
     /// 字节转换为人类可读格式
     pub fn bytes_to_human_readable(bytes: u64) -> String {
-        const UNITS: &[&str] = &["B", "KB", "MB", "GB", "TB"];
-        const THRESHOLD: f64 = 1024.0;
-
-        if bytes == 0 {
-            return "0 B".to_string();
-        }
-
-        let mut size = bytes as f64;
-        let mut unit_index = 0;
-
-        while size >= THRESHOLD && unit_index < UNITS.len() - 1 {
-            size /= THRESHOLD;
-            unit_index += 1;
-        }
-
-        if unit_index == 0 {
-            format!("{} {}", bytes, UNITS[unit_index])
-        } else {
-            format!("{:.1} {}", size, UNITS[unit_index])
-        }
+        Formatter::bytes(bytes)
     }
 
     /// 字节转换为MB
@@ -260,28 +533,35 @@ pub struct TimeFormatter;
 impl TimeFormatter {
     /// 将秒数转换为人类可读的时间格式
     pub fn seconds_to_human_readable(seconds: u64) -> String {
-        let days = seconds / 86400;
-        let hours = (seconds % 86400) / 3600;
-        let minutes = (seconds % 3600) / 60;
-        let secs = seconds % 60;
-
-        if days > 0 {
-            format!("{}天 {}小时 {}分钟", days, hours, minutes)
-        } else if hours > 0 {
-            format!("{}小时 {}分钟", hours, minutes)
-        } else if minutes > 0 {
-            format!("{}分钟 {}秒", minutes, secs)
-        } else {
-            format!("{}秒", secs)
-        }
+        Formatter::duration(seconds)
+    }
+
+    /// 将可能未知的秒数转换为人类可读格式，未知时显示 "—"
+    pub fn optional_seconds_to_human_readable(seconds: Option<u64>) -> String {
+        seconds.map_or_else(|| "—".to_string(), Self::seconds_to_human_readable)
     }
 
+    /// 默认日期时间格式
+    pub const DEFAULT_DATETIME_FORMAT: &'static str = "%Y-%m-%d %H:%M:%S";
+
     /// Unix时间戳转换为本地时间字符串
     pub fn timestamp_to_local_string(timestamp: u64) -> String {
+        Self::timestamp_to_local_string_with_format(timestamp, Self::DEFAULT_DATETIME_FORMAT)
+    }
+
+    /// 按指定格式将Unix时间戳转换为本地时间字符串，格式无效时使用默认格式
+    pub fn timestamp_to_local_string_with_format(timestamp: u64, format: &str) -> String {
+        use std::fmt::Write;
+
         let datetime = chrono::DateTime::from_timestamp(timestamp as i64, 0)
             .unwrap_or_else(chrono::Utc::now);
         let local_datetime = datetime.with_timezone(&chrono::Local);
-        local_datetime.format("%Y-%m-%d %H:%M:%S").to_string()
+
+        let mut formatted = String::new();
+        if write!(formatted, "{}", local_datetime.format(format)).is_err() {
+            return local_datetime.format(Self::DEFAULT_DATETIME_FORMAT).to_string();
+        }
+        formatted
     }
 }
 
@@ -289,12 +569,118 @@ impl TimeFormatter {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_disk_space_usage() {
+        assert_eq!(DiskInfo::space_usage(100, 25), (75, 75.0));
+        // 可用空间超过总空间时不下溢
+        assert_eq!(DiskInfo::space_usage(100, 120), (0, 0.0));
+        assert_eq!(DiskInfo::space_usage(0, 0), (0, 0.0));
+    }
+
+    #[test]
+    fn test_hottest_sensor() {
+        let sensor = |label: &str, temperature: Option<f32>| SensorInfo {
+            label: label.to_string(),
+            temperature,
+            critical: Some(90.0),
+            ..Default::default()
+        };
+        let sensors = vec![sensor("acpitz", Some(45.0)), sensor("coretemp", Some(92.0)), sensor("nvme", None)];
+
+        let hottest = SensorInfo::hottest(&sensors).unwrap();
+        assert_eq!(hottest.label, "coretemp");
+        assert!(hottest.is_critical());
+        assert!(!sensors[0].is_critical());
+        assert!(SensorInfo::hottest(&sensors[2..]).is_none());
+    }
+
+    #[test]
+    fn test_cpu_clamp_usage_keeps_averages_in_range() {
+        use crate::system::metrics::MetricsCalculator;
+        use std::time::Duration;
+
+        let core = |usage: f32| CpuCoreInfo { usage, ..Default::default() };
+        let mut cpu = CpuInfo { global_usage: 100.3, cores: vec![core(100.5), core(50.0)], core_count: 2, ..Default::default() };
+        cpu.clamp_usage();
+        assert_eq!(cpu.global_usage, 100.0);
+        assert_eq!(cpu.cores[0].usage, 100.0);
+        assert_eq!(cpu.cores[1].usage, 50.0);
+
+        let mut calculator = MetricsCalculator::new(100, Duration::from_secs(3600));
+        calculator.add_cpu_data(cpu.global_usage);
+        calculator.add_cpu_data(80.0);
+        let stats = calculator.calculate_cpu_stats();
+        assert_eq!(stats.average, 90.0);
+        assert_eq!(stats.max, 100.0);
+    }
+
     #[test]
     fn test_memory_unit_conversion() {
         assert_eq!(MemoryUnit::bytes_to_human_readable(0), "0 B");
-        assert_eq!(MemoryUnit::bytes_to_human_readable(1024), "1.0 KB");
-        assert_eq!(MemoryUnit::bytes_to_human_readable(1048576), "1.0 MB");
-        assert_eq!(MemoryUnit::bytes_to_human_readable(1073741824), "1.0 GB");
+        assert_eq!(MemoryUnit::bytes_to_human_readable(1024), "1.0 KiB");
+        assert_eq!(MemoryUnit::bytes_to_human_readable(1048576), "1.0 MiB");
+        assert_eq!(MemoryUnit::bytes_to_human_readable(1073741824), "1.0 GiB");
+    }
+
+    #[test]
+    fn test_optional_seconds_to_human_readable() {
+        assert_eq!(TimeFormatter::optional_seconds_to_human_readable(None), "—");
+        assert_eq!(
+            TimeFormatter::optional_seconds_to_human_readable(Some(90)),
+            TimeFormatter::seconds_to_human_readable(90)
+        );
+    }
+
+    #[test]
+    fn test_busy_cores() {
+        let cpu = CpuInfo {
+            global_usage: 40.0,
+            cores: vec![],
+            core_count: 8,
+            ..Default::default()
+        };
+        assert!((cpu.busy_cores() - 3.2).abs() < 1e-5);
+        assert_eq!(CpuInfo::default().busy_cores(), 0.0);
+    }
+
+    #[test]
+    fn test_network_aggregate_skips_virtual() {
+        let network = |name: &str, received: u64| NetworkInfo {
+            name: name.to_string(),
+            bytes_received: received,
+            bytes_sent: received / 2,
+            recv_rate_bps: received as f64 / 10.0,
+            ..Default::default()
+        };
+        let networks = [network("eth0", 1000), network("wlan0", 500), network("docker0", 300), network("lo", 50)];
+
+        assert!(networks[2].is_virtual() && networks[3].is_virtual());
+        assert!(!networks[0].is_virtual());
+
+        let total = NetworkInfo::aggregate(networks.iter().filter(|network| !network.is_virtual()));
+        assert_eq!(total.bytes_received, 1500);
+        assert_eq!(total.bytes_sent, 750);
+        assert_eq!(total.recv_rate_bps, 150.0);
+        assert_eq!(NetworkInfo::aggregate(&networks).bytes_received, 1850);
+    }
+
+    #[test]
+    fn test_cpu_brand_display() {
+        assert_eq!(CpuInfo::default().brand_display(), "未知");
+        let cpu = CpuInfo { brand: "Example CPU".to_string(), vendor: "GenuineExample".to_string(), ..Default::default() };
+        assert_eq!(cpu.brand_display(), "Example CPU");
+        assert_eq!(cpu.vendor_display(), "GenuineExample");
+
+        let cpu = CpuInfo { core_count: 16, physical_core_count: Some(8), ..Default::default() };
+        assert_eq!(cpu.core_count_display(), "8 物理核心 / 16 逻辑核心");
+        let cpu = CpuInfo { core_count: 16, ..Default::default() };
+        assert_eq!(cpu.core_count_display(), "16 逻辑核心");
+
+        crate::utils::I18n::with_language(crate::config::Language::English, || {
+            assert_eq!(CpuInfo::default().brand_display(), "Unknown");
+            let cpu = CpuInfo { core_count: 16, physical_core_count: Some(8), ..Default::default() };
+            assert_eq!(cpu.core_count_display(), "8 physical cores / 16 logical cores");
+        });
     }
 
     #[test]
@@ -311,6 +697,7 @@ mod tests {
             global_usage: 20.0,
             cores: vec![],
             core_count: 4,
+            ..Default::default()
         };
         let memory = MemoryInfo {
             total: 8589934592, // 8GB
@@ -318,6 +705,8 @@ mod tests {
             available: 6442450944,
             free: 6442450944,
             usage_percent: 25.0,
+            reclaimable: 0,
+            ..Default::default()
         };
         let snapshot = SystemSnapshot::new(
             cpu,
@@ -330,10 +719,84 @@ mod tests {
                 hostname: "test".to_string(),
                 uptime: 3600,
                 boot_time: 1640995200,
+                load_average: None,
             },
             None,
         );
 
         assert_eq!(snapshot.get_health_status(), SystemHealthStatus::Excellent);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_boot_time_and_uptime() {
+        let info = SystemInfo {
+            uptime: 90,
+            boot_time: 1656633600, // 2022-07-01
+            ..SystemInfo::default()
+        };
+        assert_eq!(info.boot_time_and_uptime("%Y"), ("2022".to_string(), "1分钟 30秒".to_string()));
+
+        // 无效格式回退到默认格式
+        let (boot_time, _) = info.boot_time_and_uptime("%Q");
+        assert_eq!(boot_time, TimeFormatter::timestamp_to_local_string(info.boot_time));
+    }
+
+    #[test]
+    fn test_process_command_line() {
+        let mut process = ProcessInfo {
+            pid: 1,
+            name: "python".to_string(),
+            cpu_usage: 0.0,
+            memory_usage: 0,
+            status: "Run".to_string(),
+            start_time: None,
+            run_time: None,
+            name_lossy: false,
+            user: None,
+            exe_path: None,
+            cmd: Vec::new(),
+        };
+        assert_eq!(process.command_line(), "");
+
+        process.exe_path = Some("/usr/bin/python3".to_string());
+        assert_eq!(process.command_line(), "/usr/bin/python3");
+
+        process.cmd = vec!["python3".to_string(), "server.py".to_string(), "--port=8000".to_string()];
+        assert_eq!(process.command_line(), "python3 server.py --port=8000");
+    }
+
+    #[test]
+    fn test_load_average_display() {
+        let load = LoadAverage { one: 0.5, five: 1.234, fifteen: 12.0 };
+        assert_eq!(load.display(), "0.50 / 1.23 / 12.00");
+    }
+
+    #[test]
+    fn test_load_score_without_disks() {
+        let cpu = CpuInfo { global_usage: 60.0, ..CpuInfo::default() };
+        let memory = MemoryInfo { usage_percent: 40.0, ..MemoryInfo::default() };
+        let snapshot = SystemSnapshot::new(cpu, memory, vec![], SystemInfo::default(), None);
+
+        // 磁盘项被移除，不再按0%计入
+        assert!((snapshot.calculate_system_load_score() - 50.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_uncollected_categories() {
+        let cpu = CpuInfo { global_usage: 60.0, ..CpuInfo::default() };
+        let disk = DiskInfo { usage_percent: 90.0, ..DiskInfo::default() };
+        let snapshot = SystemSnapshot::new(cpu, MemoryInfo::default(), vec![disk], SystemInfo::default(), None)
+            .without_memory();
+
+        assert_eq!(snapshot.cpu_usage(), Some(60.0));
+        assert_eq!(snapshot.memory_usage(), None);
+        // 未采集的内存不按0%拉低评分：(60*0.4 + 90*0.2) / 0.6
+        assert!((snapshot.calculate_system_load_score() - 70.0).abs() < 1e-4);
+
+        // 旧版本导出的快照没有采集标记
+        let mut json = serde_json::to_value(&snapshot).unwrap();
+        json.as_object_mut().unwrap().remove("memory_collected");
+        let parsed: SystemSnapshot = serde_json::from_value(json).unwrap();
+        assert!(parsed.memory_collected);
+    }
+}
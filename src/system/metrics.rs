@@ -2,10 +2,19 @@
 //! 
 //! 提供系统性能指标的计算、分析和历史数据管理功能。
 
+use crate::error::Result;
+use crate::system::ProcessInfo;
 use serde::{Deserialize, Serialize};
-use std::collections::VecDeque;
+use std::collections::{HashMap, VecDeque};
+use std::path::Path;
 use std::time::{Duration, Instant};
 
+/// 每个进程保留的历史采样数
+pub const PROCESS_HISTORY_SAMPLES: usize = 60;
+
+/// 同时记录历史的进程数上限
+pub const PROCESS_HISTORY_MAX_PROCESSES: usize = 512;
+
 /// 性能指标计算器
 pub struct MetricsCalculator {
     cpu_history: VecDeque<(Instant, f32)>,
@@ -13,6 +22,12 @@ pub struct MetricsCalculator {
     disk_history: VecDeque<(Instant, Vec<f64>)>,
     max_history_size: usize,
     history_duration: Duration,
+    /// 各进程的CPU和内存历史
+    process_history: ProcessHistory,
+    /// 创建时的单调时钟，与 `wall_clock_base` 对应
+    instant_base: Instant,
+    /// 创建时的墙上时间，用于将 `Instant` 换算为绝对时间
+    wall_clock_base: chrono::DateTime<chrono::Utc>,
 }
 
 impl MetricsCalculator {
@@ -24,38 +39,128 @@ impl MetricsCalculator {
             disk_history: VecDeque::with_capacity(max_history_size),
             max_history_size,
             history_duration,
+            process_history: ProcessHistory::new(PROCESS_HISTORY_SAMPLES, PROCESS_HISTORY_MAX_PROCESSES),
+            instant_base: Instant::now(),
+            wall_clock_base: chrono::Utc::now(),
+        }
+    }
+
+    /// 将采样时刻换算为墙上时间
+    pub fn to_wall_clock(&self, instant: Instant) -> chrono::DateTime<chrono::Utc> {
+        let offset = |duration: Duration| chrono::Duration::from_std(duration).ok();
+        match instant.checked_duration_since(self.instant_base) {
+            Some(elapsed) => offset(elapsed)
+                .and_then(|offset| self.wall_clock_base.checked_add_signed(offset))
+                .unwrap_or(chrono::DateTime::<chrono::Utc>::MAX_UTC),
+            None => offset(self.instant_base.duration_since(instant))
+                .and_then(|offset| self.wall_clock_base.checked_sub_signed(offset))
+                .unwrap_or(chrono::DateTime::<chrono::Utc>::MIN_UTC),
         }
     }
 
+    /// 将墙上时间换算为单调时钟时刻，超出 `Instant` 可表示范围时为 `None`
+    fn to_instant(&self, time: chrono::DateTime<chrono::Utc>) -> Option<Instant> {
+        let offset = time.signed_duration_since(self.wall_clock_base);
+        match offset.to_std() {
+            Ok(after) => self.instant_base.checked_add(after),
+            Err(_) => self.instant_base.checked_sub((-offset).to_std().ok()?),
+        }
+    }
+
+    /// 将CPU和内存历史保存为JSON文件，时间以墙上时间保存
+    pub fn save_history(&self, path: &Path) -> Result<()> {
+        let history = PersistedHistory {
+            cpu: self.get_cpu_history_wall_clock(),
+            memory: self.get_memory_history_wall_clock(),
+        };
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, serde_json::to_string(&history)?)?;
+        Ok(())
+    }
+
+    /// 从JSON文件恢复CPU和内存历史，返回恢复的数据点数
+    ///
+    /// 只恢复保留时长内的数据，恢复的数据排在现有数据之前。文件不存在时不恢复任何数据；
+    /// 文件损坏时返回错误，现有历史保持不变。
+    pub fn load_history(&mut self, path: &Path) -> Result<usize> {
+        let content = match std::fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(0),
+            Err(e) => return Err(e.into()),
+        };
+        let history: PersistedHistory = serde_json::from_str(&content)?;
+
+        let cpu = self.restore_points(history.cpu);
+        let memory = self.restore_points(history.memory);
+        let restored = cpu.len() + memory.len();
+
+        for point in cpu.into_iter().rev() {
+            self.cpu_history.push_front(point);
+        }
+        for point in memory.into_iter().rev() {
+            self.memory_history.push_front(point);
+        }
+        Self::cleanup_old_data(&mut self.cpu_history, self.history_duration, self.max_history_size);
+        Self::cleanup_old_data(&mut self.memory_history, self.history_duration, self.max_history_size);
+        Ok(restored)
+    }
+
+    /// 将持久化的数据点换算为单调时钟时刻，丢弃超出保留时长的数据并按时间排序
+    fn restore_points<T>(&self, points: Vec<(chrono::DateTime<chrono::Utc>, T)>) -> Vec<(Instant, T)> {
+        let cutoff = Instant::now().checked_sub(self.history_duration);
+        let mut restored: Vec<(Instant, T)> = points.into_iter()
+            .filter_map(|(time, value)| Some((self.to_instant(time)?, value)))
+            .filter(|(instant, _)| cutoff.is_none_or(|cutoff| *instant >= cutoff))
+            .collect();
+        restored.sort_by_key(|(instant, _)| *instant);
+        restored
+    }
+
+    /// 获取带墙上时间的CPU历史数据
+    pub fn get_cpu_history_wall_clock(&self) -> Vec<(chrono::DateTime<chrono::Utc>, f32)> {
+        self.cpu_history.iter().map(|(instant, usage)| (self.to_wall_clock(*instant), *usage)).collect()
+    }
+
+    /// 获取带墙上时间的内存历史数据
+    pub fn get_memory_history_wall_clock(&self) -> Vec<(chrono::DateTime<chrono::Utc>, f64)> {
+        self.memory_history.iter().map(|(instant, usage)| (self.to_wall_clock(*instant), *usage)).collect()
+    }
+
     /// 添加CPU使用率数据点
     pub fn add_cpu_data(&mut self, usage: f32) {
         let now = Instant::now();
         self.cpu_history.push_back((now, usage));
-        Self::cleanup_old_data_static(&mut self.cpu_history, self.history_duration);
+        Self::cleanup_old_data(&mut self.cpu_history, self.history_duration, self.max_history_size);
     }
 
     /// 添加内存使用率数据点
     pub fn add_memory_data(&mut self, usage_percent: f64) {
         let now = Instant::now();
         self.memory_history.push_back((now, usage_percent));
-        Self::cleanup_old_data_static(&mut self.memory_history, self.history_duration);
+        Self::cleanup_old_data(&mut self.memory_history, self.history_duration, self.max_history_size);
     }
 
     /// 添加磁盘使用率数据点
     pub fn add_disk_data(&mut self, disk_usages: Vec<f64>) {
         let now = Instant::now();
         self.disk_history.push_back((now, disk_usages));
-        Self::cleanup_old_data_static(&mut self.disk_history, self.history_duration);
+        Self::cleanup_old_data(&mut self.disk_history, self.history_duration, self.max_history_size);
     }
 
-    /// 清理过期数据
-    fn cleanup_old_data<T>(&mut self, history: &mut VecDeque<(Instant, T)>) {
-        let _cutoff_time = Instant::now() - self.history_duration;
-        Self::cleanup_old_data_static(history, self.history_duration);
+    /// 记录一次进程采样
+    pub fn add_process_data(&mut self, processes: &[ProcessInfo]) {
+        self.process_history.record(Instant::now(), processes);
     }
 
-    /// 静态方法清理过期数据
-    fn cleanup_old_data_static<T>(history: &mut VecDeque<(Instant, T)>, history_duration: Duration) {
+    /// 各进程的历史数据
+    pub fn process_history(&self) -> &ProcessHistory {
+        &self.process_history
+    }
+
+    /// 清理过期数据，并将历史限制在 `max_history_size` 个数据点以内
+    fn cleanup_old_data<T>(history: &mut VecDeque<(Instant, T)>, history_duration: Duration, max_history_size: usize) {
         let cutoff_time = Instant::now() - history_duration;
         
         // 移除过期数据
@@ -68,13 +173,19 @@ impl MetricsCalculator {
         }
         
         // 限制历史数据大小
-        // 限制历史数据大小（使用默认最大值）
-        let max_history_size = 1000; // 默认最大历史记录数
         while history.len() > max_history_size {
             history.pop_front();
         }
     }
 
+    /// 清空所有历史数据，统计从头开始
+    pub fn clear(&mut self) {
+        self.cpu_history.clear();
+        self.memory_history.clear();
+        self.disk_history.clear();
+        self.process_history.clear();
+    }
+
     /// 计算CPU使用率统计
     pub fn calculate_cpu_stats(&self) -> CpuStats {
         if self.cpu_history.is_empty() {
@@ -103,6 +214,7 @@ impl MetricsCalculator {
             .sum::<f32>() / count;
         let std_deviation = variance.sqrt();
 
+        let percentile = |p: f64| Self::percentile(&sorted_values, p) as f32;
         CpuStats {
             current: values.last().copied().unwrap_or(0.0),
             average,
@@ -110,6 +222,9 @@ impl MetricsCalculator {
             max,
             median,
             std_deviation,
+            p90: percentile(90.0),
+            p95: percentile(95.0),
+            p99: percentile(99.0),
             sample_count: count as usize,
         }
     }
@@ -148,10 +263,29 @@ impl MetricsCalculator {
             max,
             median,
             std_deviation,
+            p90: Self::percentile(&sorted_values, 90.0),
+            p95: Self::percentile(&sorted_values, 95.0),
+            p99: Self::percentile(&sorted_values, 99.0),
             sample_count: count as usize,
         }
     }
 
+    /// 计算已排序数据的百分位数，在相邻排名之间线性插值
+    fn percentile<T: Copy + Into<f64>>(sorted_values: &[T], percent: f64) -> f64 {
+        let Some(&first) = sorted_values.first() else {
+            return 0.0;
+        };
+        if sorted_values.len() == 1 {
+            return first.into();
+        }
+
+        let rank = (percent / 100.0).clamp(0.0, 1.0) * (sorted_values.len() - 1) as f64;
+        let lower = rank.floor() as usize;
+        let upper = rank.ceil() as usize;
+        let (low, high): (f64, f64) = (sorted_values[lower].into(), sorted_values[upper].into());
+        low + (high - low) * (rank - lower as f64)
+    }
+
     /// 获取CPU历史数据
     pub fn get_cpu_history(&self) -> Vec<f32> {
         self.cpu_history.iter().map(|(_, usage)| *usage).collect()
@@ -162,6 +296,40 @@ impl MetricsCalculator {
         self.memory_history.iter().map(|(_, usage)| *usage).collect()
     }
 
+    /// 获取带采样时刻的CPU历史数据
+    pub fn get_cpu_history_timed(&self) -> Vec<(Instant, f32)> {
+        self.cpu_history.iter().copied().collect()
+    }
+
+    /// 获取带采样时刻的内存历史数据
+    pub fn get_memory_history_timed(&self) -> Vec<(Instant, f32)> {
+        self.memory_history.iter().map(|(instant, usage)| (*instant, *usage as f32)).collect()
+    }
+
+    /// 最近 `samples` 个CPU样本的平均值，没有样本时返回 `None`
+    pub fn recent_cpu_average(&self, samples: usize) -> Option<f32> {
+        Self::recent_average(&self.cpu_history, samples).map(|average| average as f32)
+    }
+
+    /// 最近 `samples` 个内存样本的平均值，没有样本时返回 `None`
+    pub fn recent_memory_average(&self, samples: usize) -> Option<f64> {
+        Self::recent_average(&self.memory_history, samples)
+    }
+
+    /// 计算历史数据末尾若干样本的平均值
+    fn recent_average<T>(history: &VecDeque<(Instant, T)>, samples: usize) -> Option<f64>
+    where
+        T: Copy + Into<f64>,
+    {
+        let count = samples.min(history.len());
+        if count == 0 {
+            return None;
+        }
+
+        let sum: f64 = history.iter().rev().take(count).map(|(_, value)| (*value).into()).sum();
+        Some(sum / count as f64)
+    }
+
     /// 检测CPU使用率异常
     pub fn detect_cpu_anomalies(&self, threshold_multiplier: f32) -> Vec<CpuAnomaly> {
         let stats = self.calculate_cpu_stats();
@@ -175,11 +343,7 @@ impl MetricsCalculator {
                     timestamp: *timestamp,
                     usage: *usage,
                     threshold,
-                    severity: if *usage > threshold * 1.5 {
-                        AnomalySeverity::High
-                    } else {
-                        AnomalySeverity::Medium
-                    },
+                    severity: AnomalySeverity::classify(*usage as f64, threshold as f64),
                 });
             }
         }
@@ -187,6 +351,24 @@ impl MetricsCalculator {
         anomalies
     }
 
+    /// 检测内存使用率异常
+    ///
+    /// 与CPU异常检测相同，使用率超过平均值加 `threshold_multiplier` 倍标准差的数据点视为异常。
+    pub fn detect_memory_anomalies(&self, threshold_multiplier: f64) -> Vec<MemoryAnomaly> {
+        let stats = self.calculate_memory_stats();
+        let threshold = stats.average + (stats.std_deviation * threshold_multiplier);
+
+        self.memory_history.iter()
+            .filter(|(_, usage_percent)| *usage_percent > threshold)
+            .map(|(timestamp, usage_percent)| MemoryAnomaly {
+                timestamp: *timestamp,
+                usage_percent: *usage_percent,
+                threshold,
+                severity: AnomalySeverity::classify(*usage_percent, threshold),
+            })
+            .collect()
+    }
+
     /// 预测系统负载趋势
     pub fn predict_load_trend(&self, prediction_window: Duration) -> LoadTrend {
         let cpu_stats = self.calculate_cpu_stats();
@@ -262,6 +444,90 @@ impl MetricsCalculator {
     }
 }
 
+/// 持久化的CPU和内存历史
+#[derive(Debug, Serialize, Deserialize)]
+struct PersistedHistory {
+    cpu: Vec<(chrono::DateTime<chrono::Utc>, f32)>,
+    memory: Vec<(chrono::DateTime<chrono::Utc>, f64)>,
+}
+
+/// 单个进程的历史采样
+#[derive(Debug, Clone, Default)]
+struct ProcessSamples {
+    /// 进程启动时间，用于识别PID被新进程复用
+    start_time: Option<u64>,
+    cpu: VecDeque<(Instant, f32)>,
+    memory: VecDeque<(Instant, u64)>,
+}
+
+/// 按PID记录的进程CPU和内存历史
+///
+/// 已退出的进程在下一次采样时移除；记录的进程数达到上限后不再记录新进程，
+/// 避免频繁创建进程时历史无限增长。
+#[derive(Debug, Clone)]
+pub struct ProcessHistory {
+    samples: HashMap<u32, ProcessSamples>,
+    max_samples: usize,
+    max_processes: usize,
+}
+
+impl ProcessHistory {
+    /// 创建进程历史，`max_samples` 为每个进程保留的采样数
+    pub fn new(max_samples: usize, max_processes: usize) -> Self {
+        Self { samples: HashMap::new(), max_samples, max_processes }
+    }
+
+    /// 记录一次进程采样，并移除已不存在的进程
+    pub fn record(&mut self, at: Instant, processes: &[ProcessInfo]) {
+        let alive: HashMap<u32, Option<u64>> = processes.iter()
+            .map(|process| (process.pid, process.start_time))
+            .collect();
+        // PID被新进程复用时丢弃旧进程的历史
+        self.samples.retain(|pid, samples| alive.get(pid).is_some_and(|start_time| *start_time == samples.start_time));
+
+        for process in processes {
+            if !self.samples.contains_key(&process.pid) && self.samples.len() >= self.max_processes {
+                continue;
+            }
+            let samples = self.samples.entry(process.pid).or_insert_with(|| ProcessSamples {
+                start_time: process.start_time,
+                ..Default::default()
+            });
+            if samples.cpu.len() >= self.max_samples {
+                samples.cpu.pop_front();
+                samples.memory.pop_front();
+            }
+            samples.cpu.push_back((at, process.cpu_usage));
+            samples.memory.push_back((at, process.memory_usage));
+        }
+    }
+
+    /// 获取进程的CPU使用率历史，未记录时为空
+    pub fn cpu_history(&self, pid: u32) -> Vec<(Instant, f32)> {
+        self.samples.get(&pid).map(|samples| samples.cpu.iter().copied().collect()).unwrap_or_default()
+    }
+
+    /// 获取进程的内存使用量历史，未记录时为空
+    pub fn memory_history(&self, pid: u32) -> Vec<(Instant, u64)> {
+        self.samples.get(&pid).map(|samples| samples.memory.iter().copied().collect()).unwrap_or_default()
+    }
+
+    /// 正在记录历史的进程数
+    pub fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    /// 是否没有任何进程历史
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+
+    /// 清空所有进程历史
+    pub fn clear(&mut self) {
+        self.samples.clear();
+    }
+}
+
 /// CPU统计信息
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CpuStats {
@@ -271,6 +537,12 @@ pub struct CpuStats {
     pub max: f32,
     pub median: f32,
     pub std_deviation: f32,
+    #[serde(default)]
+    pub p90: f32,
+    #[serde(default)]
+    pub p95: f32,
+    #[serde(default)]
+    pub p99: f32,
     pub sample_count: usize,
 }
 
@@ -283,6 +555,9 @@ impl Default for CpuStats {
             max: 0.0,
             median: 0.0,
             std_deviation: 0.0,
+            p90: 0.0,
+            p95: 0.0,
+            p99: 0.0,
             sample_count: 0,
         }
     }
@@ -297,6 +572,12 @@ pub struct MemoryStats {
     pub max: f64,
     pub median: f64,
     pub std_deviation: f64,
+    #[serde(default)]
+    pub p90: f64,
+    #[serde(default)]
+    pub p95: f64,
+    #[serde(default)]
+    pub p99: f64,
     pub sample_count: usize,
 }
 
@@ -309,6 +590,9 @@ impl Default for MemoryStats {
             max: 0.0,
             median: 0.0,
             std_deviation: 0.0,
+            p90: 0.0,
+            p95: 0.0,
+            p99: 0.0,
             sample_count: 0,
         }
     }
@@ -323,6 +607,15 @@ pub struct CpuAnomaly {
     pub severity: AnomalySeverity,
 }
 
+/// 内存异常检测结果
+#[derive(Debug, Clone)]
+pub struct MemoryAnomaly {
+    pub timestamp: Instant,
+    pub usage_percent: f64,
+    pub threshold: f64,
+    pub severity: AnomalySeverity,
+}
+
 /// 异常严重程度
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum AnomalySeverity {
@@ -332,6 +625,19 @@ pub enum AnomalySeverity {
     Critical,
 }
 
+impl AnomalySeverity {
+    /// 按超出阈值的程度分级：超过2倍为严重，超过1.5倍为高，其余为中
+    pub fn classify(value: f64, threshold: f64) -> Self {
+        if value > threshold * 2.0 {
+            AnomalySeverity::Critical
+        } else if value > threshold * 1.5 {
+            AnomalySeverity::High
+        } else {
+            AnomalySeverity::Medium
+        }
+    }
+}
+
 /// 负载趋势预测
 #[derive(Debug, Clone)]
 pub struct LoadTrend {
@@ -344,6 +650,13 @@ pub struct LoadTrend {
     pub confidence: f64,
 }
 
+impl LoadTrend {
+    /// 置信度是否达到显示预测所需的阈值
+    pub fn is_confident(&self, min_confidence: f64) -> bool {
+        self.confidence >= min_confidence
+    }
+}
+
 /// 性能基准测试
 pub struct PerformanceBenchmark {
     baseline_cpu: f32,
@@ -363,8 +676,8 @@ impl PerformanceBenchmark {
 
     /// 计算性能变化
     pub fn calculate_performance_change(&self, current_cpu: f32, current_memory: f64) -> PerformanceChange {
-        let cpu_change = ((current_cpu - self.baseline_cpu) / self.baseline_cpu) * 100.0;
-        let memory_change = ((current_memory - self.baseline_memory) / self.baseline_memory) * 100.0;
+        let cpu_change = Self::relative_change(current_cpu as f64, self.baseline_cpu as f64) as f32;
+        let memory_change = Self::relative_change(current_memory, self.baseline_memory);
         let time_elapsed = self.baseline_timestamp.elapsed();
 
         PerformanceChange {
@@ -382,6 +695,23 @@ impl PerformanceBenchmark {
     }
 }
 
+impl PerformanceBenchmark {
+    /// 计算相对变化百分比
+    ///
+    /// 基准为零时相对变化无意义，退化为两者的差值（百分点），避免产生 inf/NaN。
+    fn relative_change(current: f64, baseline: f64) -> f64 {
+        if !current.is_finite() || !baseline.is_finite() {
+            return 0.0;
+        }
+
+        if baseline.abs() < f64::EPSILON {
+            current - baseline
+        } else {
+            ((current - baseline) / baseline) * 100.0
+        }
+    }
+}
+
 /// 性能变化信息
 #[derive(Debug, Clone)]
 pub struct PerformanceChange {
@@ -403,6 +733,146 @@ pub enum TrendDirection {
 mod tests {
     use super::*;
 
+    fn process(pid: u32, cpu_usage: f32, start_time: u64) -> ProcessInfo {
+        ProcessInfo {
+            pid,
+            name: format!("proc-{}", pid),
+            cpu_usage,
+            memory_usage: pid as u64 * 1024,
+            status: "Run".to_string(),
+            start_time: Some(start_time),
+            run_time: None,
+            name_lossy: false,
+            user: None,
+            exe_path: None,
+            cmd: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_max_history_size() {
+        let mut calculator = MetricsCalculator::new(5, Duration::from_secs(3600));
+        for i in 0..20 {
+            calculator.add_cpu_data(i as f32);
+            assert!(calculator.get_cpu_history().len() <= 5);
+        }
+        assert_eq!(calculator.get_cpu_history(), vec![15.0, 16.0, 17.0, 18.0, 19.0]);
+    }
+
+    #[test]
+    fn test_history_persistence() {
+        let path = std::env::temp_dir().join(format!("system-monitor-history-test-{}.json", std::process::id()));
+        let mut calculator = MetricsCalculator::new(100, Duration::from_secs(3600));
+        calculator.add_cpu_data(10.0);
+        calculator.add_cpu_data(20.0);
+        calculator.add_memory_data(55.0);
+        calculator.save_history(&path).unwrap();
+
+        let mut restored = MetricsCalculator::new(100, Duration::from_secs(3600));
+        restored.add_cpu_data(30.0);
+        assert_eq!(restored.load_history(&path).unwrap(), 3);
+        assert_eq!(restored.get_cpu_history(), vec![10.0, 20.0, 30.0]);
+        assert_eq!(restored.calculate_memory_stats().current, 55.0);
+
+        // 超出保留时长的数据不恢复
+        let mut short = MetricsCalculator::new(100, Duration::from_secs(3600));
+        let old = chrono::Utc::now() - chrono::Duration::hours(2);
+        let history = PersistedHistory { cpu: vec![(old, 1.0)], memory: Vec::new() };
+        std::fs::write(&path, serde_json::to_string(&history).unwrap()).unwrap();
+        assert_eq!(short.load_history(&path).unwrap(), 0);
+
+        // 损坏的文件返回错误，缺失的文件视为没有历史
+        std::fs::write(&path, "not json").unwrap();
+        assert!(short.load_history(&path).is_err());
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(short.load_history(&path).unwrap(), 0);
+        assert!(short.get_cpu_history().is_empty());
+    }
+
+    #[test]
+    fn test_percentiles() {
+        let mut calculator = MetricsCalculator::new(100, Duration::from_secs(3600));
+        // 乱序加入1..=100，统计前排序
+        for usage in (1..=100).rev() {
+            calculator.add_cpu_data(usage as f32);
+            calculator.add_memory_data(usage as f64);
+        }
+
+        let cpu = calculator.calculate_cpu_stats();
+        assert!((cpu.p90 - 90.1).abs() < 1e-4);
+        assert!((cpu.p95 - 95.05).abs() < 1e-4);
+        assert!((cpu.p99 - 99.01).abs() < 1e-4);
+
+        let memory = calculator.calculate_memory_stats();
+        assert!((memory.p90 - 90.1).abs() < 1e-9);
+        assert!((memory.p95 - 95.05).abs() < 1e-9);
+        assert!((memory.p99 - 99.01).abs() < 1e-9);
+
+        assert_eq!(MetricsCalculator::percentile::<f64>(&[], 90.0), 0.0);
+        assert_eq!(MetricsCalculator::percentile(&[42.0], 99.0), 42.0);
+        assert_eq!(MetricsCalculator::percentile(&[1.0, 3.0], 50.0), 2.0);
+        assert_eq!(MetricsCalculator::new(10, Duration::from_secs(60)).calculate_cpu_stats().p99, 0.0);
+    }
+
+    #[test]
+    fn test_detect_memory_anomalies() {
+        // 缓慢上升的序列只有末尾超出阈值
+        let mut calculator = MetricsCalculator::new(100, Duration::from_secs(3600));
+        for usage in 1..=20 {
+            calculator.add_memory_data(usage as f64);
+        }
+        let anomalies = calculator.detect_memory_anomalies(1.0);
+        let values: Vec<f64> = anomalies.iter().map(|anomaly| anomaly.usage_percent).collect();
+        assert_eq!(values, vec![17.0, 18.0, 19.0, 20.0]);
+        assert!(anomalies.iter().all(|anomaly| anomaly.severity == AnomalySeverity::Medium));
+        assert!(anomalies.iter().all(|anomaly| (anomaly.threshold - 16.27).abs() < 0.01));
+
+        // 平稳序列末尾的突增超过阈值两倍
+        let mut calculator = MetricsCalculator::new(100, Duration::from_secs(3600));
+        for _ in 0..19 {
+            calculator.add_memory_data(10.0);
+        }
+        calculator.add_memory_data(90.0);
+        let anomalies = calculator.detect_memory_anomalies(1.0);
+        assert_eq!(anomalies.len(), 1);
+        assert_eq!(anomalies[0].severity, AnomalySeverity::Critical);
+
+        assert!(MetricsCalculator::new(100, Duration::from_secs(3600)).detect_memory_anomalies(1.0).is_empty());
+    }
+
+    #[test]
+    fn test_anomaly_severity_classify() {
+        assert_eq!(AnomalySeverity::classify(11.0, 10.0), AnomalySeverity::Medium);
+        assert_eq!(AnomalySeverity::classify(16.0, 10.0), AnomalySeverity::High);
+        assert_eq!(AnomalySeverity::classify(21.0, 10.0), AnomalySeverity::Critical);
+    }
+
+    #[test]
+    fn test_process_history() {
+        let mut history = ProcessHistory::new(3, 2);
+        let start = Instant::now();
+
+        for i in 0..5 {
+            let at = start + Duration::from_secs(i);
+            history.record(at, &[process(1, i as f32, 100), process(2, 50.0, 100), process(3, 10.0, 100)]);
+        }
+        // 只保留最近3个采样，进程数达到上限后不再记录新进程
+        let cpu: Vec<f32> = history.cpu_history(1).iter().map(|(_, usage)| *usage).collect();
+        assert_eq!(cpu, vec![2.0, 3.0, 4.0]);
+        assert_eq!(history.memory_history(2).len(), 3);
+        assert_eq!(history.len(), 2);
+        assert!(history.cpu_history(3).is_empty());
+
+        // 退出的进程被移除，空出的位置可记录新进程；PID复用时历史重新开始
+        history.record(start + Duration::from_secs(5), &[process(2, 60.0, 200), process(3, 10.0, 100)]);
+        assert!(history.cpu_history(1).is_empty());
+        assert_eq!(history.cpu_history(2).len(), 1);
+        assert_eq!(history.cpu_history(3).len(), 1);
+
+        history.clear();
+        assert!(history.is_empty());
+    }
+
     #[test]
     fn test_metrics_calculator() {
         let mut calculator = MetricsCalculator::new(100, Duration::from_secs(3600));
@@ -419,6 +889,81 @@ mod tests {
         assert_eq!(stats.max, 35.0);
     }
 
+    #[test]
+    fn test_to_wall_clock() {
+        let calculator = MetricsCalculator::new(100, Duration::from_secs(3600));
+        let base = calculator.wall_clock_base;
+
+        let later = calculator.instant_base + Duration::from_secs(90);
+        assert_eq!(calculator.to_wall_clock(later), base + chrono::Duration::seconds(90));
+        assert_eq!(calculator.to_wall_clock(calculator.instant_base), base);
+
+        if let Some(earlier) = calculator.instant_base.checked_sub(Duration::from_secs(5)) {
+            assert_eq!(calculator.to_wall_clock(earlier), base - chrono::Duration::seconds(5));
+        }
+    }
+
+    #[test]
+    fn test_clear_resets_stats() {
+        let mut calculator = MetricsCalculator::new(100, Duration::from_secs(3600));
+        calculator.add_cpu_data(50.0);
+        calculator.add_memory_data(60.0);
+        calculator.add_disk_data(vec![70.0]);
+
+        calculator.clear();
+        assert_eq!(calculator.calculate_cpu_stats().sample_count, 0);
+        assert_eq!(calculator.calculate_memory_stats().sample_count, 0);
+        assert!(calculator.get_cpu_history().is_empty());
+    }
+
+    #[test]
+    fn test_recent_average() {
+        let mut calculator = MetricsCalculator::new(100, Duration::from_secs(3600));
+        assert_eq!(calculator.recent_cpu_average(3), None);
+
+        for usage in [10.0, 20.0, 30.0, 40.0] {
+            calculator.add_cpu_data(usage);
+            calculator.add_memory_data(usage as f64);
+        }
+        assert_eq!(calculator.recent_cpu_average(3), Some(30.0));
+        assert_eq!(calculator.recent_cpu_average(1), Some(40.0));
+        assert_eq!(calculator.recent_memory_average(10), Some(25.0));
+    }
+
+    #[test]
+    fn test_load_trend_confidence_threshold() {
+        let calculator = MetricsCalculator::new(100, Duration::from_secs(3600));
+        let trend = calculator.predict_load_trend(Duration::from_secs(60));
+
+        // 没有样本时置信度来自稳定性，样本置信度为零
+        assert!(trend.confidence < 1.0);
+        assert!(trend.is_confident(0.0));
+        assert!(!trend.is_confident(0.9));
+    }
+
+    #[test]
+    fn test_performance_change_with_zero_baseline() {
+        let benchmark = PerformanceBenchmark::new(0.0, 0.0);
+
+        let change = benchmark.calculate_performance_change(0.0, 0.0);
+        assert_eq!(change.cpu_change_percent, 0.0);
+        assert_eq!(change.memory_change_percent, 0.0);
+        assert_eq!(change.overall_trend, TrendDirection::Stable);
+
+        let change = benchmark.calculate_performance_change(25.0, 40.0);
+        assert!(change.cpu_change_percent.is_finite());
+        assert!(change.memory_change_percent.is_finite());
+        assert_eq!(change.overall_trend, TrendDirection::Increasing);
+    }
+
+    #[test]
+    fn test_performance_change_relative() {
+        let benchmark = PerformanceBenchmark::new(50.0, 20.0);
+        let change = benchmark.calculate_performance_change(25.0, 30.0);
+        assert_eq!(change.cpu_change_percent, -50.0);
+        assert_eq!(change.memory_change_percent, 50.0);
+    }
+
     #[test]
     fn test_moving_average() {
         let values = vec![1.0, 2.0, 3.0, 4.0, 5.0];
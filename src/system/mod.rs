@@ -2,20 +2,134 @@
 //! 
 //! 负责收集CPU、内存、磁盘等系统信息，提供实时监控数据。
 
+pub mod alert;
 pub mod collector;
+pub mod connections;
+pub mod export;
+pub mod gpu;
+pub mod history;
 pub mod info;
 pub mod metrics;
+pub mod process;
+#[cfg(feature = "prometheus")]
+pub mod prometheus;
+pub mod remote;
 
 pub use info::*;
 
+use crate::config::{DiskFilter, MonitoringConfig, ProcessCollectionMode};
 use crate::error::{Result, SystemMonitorError};
-use sysinfo::{System, Disks};
+use crate::system::process::ProcessSortKey;
+use crate::utils::MathUtils;
+use sysinfo::{System, Components, Disks, Networks, ProcessRefreshKind, ProcessesToUpdate, UpdateKind, Users};
+use std::collections::HashMap;
+use std::ffi::OsStr;
 use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::sync::{mpsc, watch, Notify};
+use tokio_util::sync::CancellationToken;
+
+/// 快照流的通道容量，消费者处理不及时时采集循环会等待
+const SNAPSHOT_STREAM_CAPACITY: usize = 8;
+
+/// 快照采集请求，描述本次需要采集的数据类别
+///
+/// 未请求的类别不会被采集，快照中对应字段为默认值或空列表。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SnapshotRequest {
+    /// 是否采集CPU信息
+    pub cpu: bool,
+    /// 是否采集内存信息
+    pub memory: bool,
+    /// 是否采集磁盘信息
+    pub disks: bool,
+    /// 是否采集进程信息（开销较大）
+    pub processes: bool,
+    /// 是否采集GPU信息（需要启动外部命令，开销较大）
+    pub gpus: bool,
+    /// 是否采集网络接口信息
+    pub networks: bool,
+    /// 采集的进程数上限，0表示不限制
+    pub process_limit: usize,
+    /// 选取进程的排序字段
+    pub process_sort_by: ProcessSortKey,
+    /// 磁盘过滤
+    pub disk_filter: DiskFilter,
+}
+
+impl Default for SnapshotRequest {
+    fn default() -> Self {
+        Self {
+            cpu: true,
+            memory: true,
+            disks: true,
+            processes: false,
+            gpus: false,
+            networks: true,
+            process_limit: 50,
+            process_sort_by: ProcessSortKey::Cpu,
+            disk_filter: DiskFilter::default(),
+        }
+    }
+}
+
+impl SnapshotRequest {
+    /// 仅采集CPU和内存等摘要信息，用于快速启动时的首次采集
+    pub fn summary() -> Self {
+        Self {
+            disks: false,
+            networks: false,
+            ..Self::default()
+        }
+    }
+
+    /// 窗口最小化时的采集请求：保留CPU和内存（用于告警），暂停磁盘、进程、GPU和网络采集
+    pub fn minimized(self) -> Self {
+        Self {
+            disks: false,
+            processes: false,
+            gpus: false,
+            networks: false,
+            ..self
+        }
+    }
+
+    /// 根据监控配置生成采集请求，禁用的类别不采集
+    ///
+    /// 进程信息按采集模式决定，按需模式下仅在进程标签页可见时采集。
+    pub fn from_monitoring(monitoring: &MonitoringConfig, process_tab_active: bool) -> Self {
+        Self {
+            cpu: monitoring.enable_cpu_monitoring,
+            memory: monitoring.enable_memory_monitoring,
+            disks: monitoring.enable_disk_monitoring,
+            processes: match monitoring.process_collection_mode {
+                ProcessCollectionMode::Always => true,
+                ProcessCollectionMode::OnDemand => process_tab_active,
+                ProcessCollectionMode::Never => false,
+            },
+            gpus: monitoring.enable_gpu_monitoring,
+            networks: monitoring.enable_network_monitoring,
+            process_limit: monitoring.process_list_limit,
+            process_sort_by: monitoring.process_sort_by,
+            disk_filter: monitoring.disk_filter.clone(),
+        }
+    }
+}
 
 /// 系统信息管理器
 #[derive(Clone)]
 pub struct SystemInfoManager {
     system: Arc<Mutex<System>>,
+    /// CPU型号、厂商和物理核心数（运行期间不变，创建时获取一次）
+    cpu_identity: Arc<CpuIdentity>,
+    /// 最近一次采样的温度传感器（由独立的慢速定时器更新）
+    sensors: Arc<Mutex<Vec<SensorInfo>>>,
+    /// 最近一次采样的GPU信息（与传感器同节拍更新，尚未采样时为 `None`）
+    gpus: Arc<Mutex<Option<Vec<GpuInfo>>>>,
+    /// 磁盘读写速率计算状态
+    disk_io: Arc<Mutex<DiskIoTracker>>,
+    /// 网络接口采样器（保留上次的计数以计算速率）
+    networks: Arc<Mutex<NetworkSampler>>,
 }
 
 impl SystemInfoManager {
@@ -23,25 +137,210 @@ impl SystemInfoManager {
     pub fn new() -> Result<Self> {
         let system = System::new_all();
         Ok(Self {
+            cpu_identity: Arc::new(cpu_identity(&system)),
+            sensors: Arc::new(Mutex::new(Vec::new())),
+            gpus: Arc::new(Mutex::new(None)),
+            disk_io: Arc::new(Mutex::new(DiskIoTracker::default())),
+            networks: Arc::new(Mutex::new(NetworkSampler::new())),
             system: Arc::new(Mutex::new(system)),
         })
     }
 
     /// 异步获取系统快照
     pub async fn get_snapshot(&self) -> Result<SystemSnapshot> {
+        self.get_snapshot_with(SnapshotRequest::default()).await
+    }
+
+    /// 按请求异步获取系统快照
+    pub async fn get_snapshot_with(&self, request: SnapshotRequest) -> Result<SystemSnapshot> {
+        let cpu = async {
+            if request.cpu {
+                self.get_cpu_info_async().await
+            } else {
+                Ok(CpuInfo::default())
+            }
+        };
+        let memory = async {
+            if request.memory {
+                self.get_memory_info_async().await
+            } else {
+                Ok(MemoryInfo::default())
+            }
+        };
+        let disks = async {
+            if request.disks {
+                self.get_disk_info_async(request.disk_filter.clone()).await
+            } else {
+                Ok(Vec::new())
+            }
+        };
         let (cpu_info, memory_info, disk_info, system_info) = tokio::try_join!(
-            self.get_cpu_info_async(),
-            self.get_memory_info_async(),
-            self.get_disk_info_async(),
+            cpu,
+            memory,
+            disks,
             self.get_system_info_async()
         )?;
 
-        Ok(SystemSnapshot::new(cpu_info, memory_info, disk_info, system_info, None))
+        let networks = if request.networks {
+            Some(self.get_network_info_async().await?)
+        } else {
+            None
+        };
+
+        let mut snapshot = SystemSnapshot::new(cpu_info, memory_info, disk_info, system_info, networks)
+            .with_sensors(self.cached_sensors());
+        if !request.cpu {
+            snapshot = snapshot.without_cpu();
+        }
+        if !request.memory {
+            snapshot = snapshot.without_memory();
+        }
+        if request.gpus {
+            if let Some(gpus) = self.cached_gpus() {
+                snapshot = snapshot.with_gpus(gpus);
+            }
+        }
+        if request.processes {
+            let processes = self.get_process_info_async(request.process_sort_by, request.process_limit).await?;
+            Ok(snapshot.with_processes(processes))
+        } else {
+            Ok(snapshot)
+        }
+    }
+
+    /// 按固定间隔采集快照，返回快照流的接收端
+    ///
+    /// 取消令牌被触发或接收端被丢弃时采集循环结束，接收端随之返回 `None`。
+    pub fn snapshot_stream(&self, interval: Duration, cancel_token: CancellationToken) -> mpsc::Receiver<Result<SystemSnapshot>> {
+        let (_, interval) = watch::channel(interval);
+        self.snapshot_stream_with(interval, cancel_token, None, || Some(SnapshotRequest::default()))
+    }
+
+    /// 按可调整的间隔采集快照，每次采集前调用 `request` 决定采集内容，返回 `None` 时跳过本次采集
+    ///
+    /// 通过 `interval` 对应的发送端更新间隔后，下一次采集按新间隔计时。
+    /// 提供 `trigger` 时，收到通知会立即额外采集一次。
+    pub fn snapshot_stream_with<F>(
+        &self,
+        mut interval_rx: watch::Receiver<Duration>,
+        cancel_token: CancellationToken,
+        trigger: Option<Arc<Notify>>,
+        request: F,
+    ) -> mpsc::Receiver<Result<SystemSnapshot>>
+    where
+        F: Fn() -> Option<SnapshotRequest> + Send + 'static,
+    {
+        let (sender, receiver) = mpsc::channel(SNAPSHOT_STREAM_CAPACITY);
+        let manager = self.clone();
+        let trigger = trigger.unwrap_or_default();
+
+        tokio::spawn(async move {
+            let mut period = *interval_rx.borrow_and_update();
+            let mut interval = tokio::time::interval(period);
+            // 发送端丢弃后间隔不再变化
+            let mut interval_adjustable = true;
+            loop {
+                tokio::select! {
+                    _ = cancel_token.cancelled() => break,
+                    _ = interval.tick() => {}
+                    _ = trigger.notified() => {}
+                    changed = interval_rx.changed(), if interval_adjustable => {
+                        if changed.is_err() {
+                            interval_adjustable = false;
+                        } else if *interval_rx.borrow_and_update() != period {
+                            period = *interval_rx.borrow();
+                            log::info!("采集间隔调整为 {:?}", period);
+                            interval = tokio::time::interval_at(tokio::time::Instant::now() + period, period);
+                        }
+                        continue;
+                    }
+                }
+
+                let Some(request) = request() else {
+                    continue;
+                };
+                let started = std::time::Instant::now();
+                let snapshot = manager.get_snapshot_with(request.clone()).await;
+                log::debug!("采集耗时 {:?}（{:?}）", started.elapsed(), request);
+                tokio::select! {
+                    _ = cancel_token.cancelled() => break,
+                    sent = sender.send(snapshot) => {
+                        if sent.is_err() {
+                            break; // 接收端已丢弃
+                        }
+                    }
+                }
+            }
+        });
+
+        receiver
+    }
+
+    /// 最近一次采样的温度传感器
+    pub fn cached_sensors(&self) -> Vec<SensorInfo> {
+        self.sensors.lock().map(|sensors| sensors.clone()).unwrap_or_default()
+    }
+
+    /// 读取温度传感器并更新缓存
+    ///
+    /// 传感器读取较慢，不在每次快照采集时进行，由 [`Self::sample_sensors_with`] 定时调用。
+    pub async fn refresh_sensors_async(&self) -> Result<()> {
+        let sensors = tokio::task::spawn_blocking(read_sensors)
+            .await
+            .map_err(|e| SystemMonitorError::Runtime(e.to_string()))?;
+
+        *self.sensors.lock().map_err(|_| SystemMonitorError::SystemInfo("无法获取传感器缓存锁".to_string()))? = sensors;
+        Ok(())
+    }
+
+    /// 最近一次采样的GPU信息，尚未采样时返回 `None`
+    pub fn cached_gpus(&self) -> Option<Vec<GpuInfo>> {
+        self.gpus.lock().ok().and_then(|gpus| gpus.clone())
+    }
+
+    /// 查询GPU信息并更新缓存
+    ///
+    /// 每次查询都会启动一次 `nvidia-smi` 进程，由 [`Self::sample_sensors_with`] 按传感器间隔调用。
+    pub async fn refresh_gpus_async(&self) -> Result<()> {
+        let gpus = tokio::task::spawn_blocking(gpu::collect_gpus)
+            .await
+            .map_err(|e| SystemMonitorError::Runtime(e.to_string()))?;
+
+        *self.gpus.lock().map_err(|_| SystemMonitorError::SystemInfo("无法获取GPU缓存锁".to_string()))? = Some(gpus);
+        Ok(())
+    }
+
+    /// 按独立于快照采集的间隔定时采样传感器，每次采样前调用 `interval` 获取间隔
+    ///
+    /// `sample_gpus` 返回 `true` 时同时查询GPU。采样结果写入缓存，之后的快照会带上最近一次的数据。
+    pub fn sample_sensors_with<F, G>(&self, cancel_token: CancellationToken, interval: F, sample_gpus: G)
+    where
+        F: Fn() -> Duration + Send + 'static,
+        G: Fn() -> bool + Send + 'static,
+    {
+        let manager = self.clone();
+        tokio::spawn(async move {
+            loop {
+                if let Err(e) = manager.refresh_sensors_async().await {
+                    log::warn!("传感器采样失败: {}", e);
+                }
+                if sample_gpus() {
+                    if let Err(e) = manager.refresh_gpus_async().await {
+                        log::warn!("GPU采样失败: {}", e);
+                    }
+                }
+                tokio::select! {
+                    _ = cancel_token.cancelled() => break,
+                    _ = tokio::time::sleep(interval()) => {}
+                }
+            }
+        });
     }
 
     /// 异步获取当前CPU信息
     pub async fn get_cpu_info_async(&self) -> Result<CpuInfo> {
         let system_clone = self.system.clone();
+        let cpu_identity = self.cpu_identity.clone();
         tokio::task::spawn_blocking(move || {
             let mut system = system_clone.lock().map_err(|_| SystemMonitorError::SystemInfo("无法获取系统信息锁".to_string()))?;
             system.refresh_cpu_all();
@@ -57,47 +356,120 @@ impl SystemInfoManager {
                 global_usage: global_cpu,
                 cores: cpus,
                 core_count: system.cpus().len(),
+                physical_core_count: cpu_identity.physical_core_count,
+                brand: cpu_identity.brand.clone(),
+                vendor: cpu_identity.vendor.clone(),
             })
         }).await.map_err(|e| SystemMonitorError::Runtime(e.to_string()))?
     }
 
+    /// 同步获取当前内存信息
+    pub fn get_memory_info(&self) -> Result<MemoryInfo> {
+        let mut system = self.system.lock().map_err(|_| SystemMonitorError::SystemInfo("无法获取系统信息锁".to_string()))?;
+        system.refresh_memory();
+
+        Ok(MemoryInfo {
+            total: system.total_memory(),
+            used: system.used_memory(),
+            available: system.available_memory(),
+            free: system.free_memory(),
+            usage_percent: MathUtils::percentage(system.used_memory() as f64, system.total_memory() as f64),
+            reclaimable: system.available_memory().saturating_sub(system.free_memory()),
+            swap_total: system.total_swap(),
+            swap_used: system.used_swap(),
+            swap_usage_percent: MathUtils::percentage(system.used_swap() as f64, system.total_swap() as f64),
+        })
+    }
+
     /// 异步获取当前内存信息
     pub async fn get_memory_info_async(&self) -> Result<MemoryInfo> {
-        let system_clone = self.system.clone();
-        tokio::task::spawn_blocking(move || {
-            let mut system = system_clone.lock().map_err(|_| SystemMonitorError::SystemInfo("无法获取系统信息锁".to_string()))?;
-            system.refresh_memory();
-
-            Ok(MemoryInfo {
-                total: system.total_memory(),
-                used: system.used_memory(),
-                available: system.available_memory(),
-                free: system.free_memory(),
-                usage_percent: (system.used_memory() as f64 / system.total_memory() as f64) * 100.0,
+        let manager = self.clone();
+        tokio::task::spawn_blocking(move || manager.get_memory_info())
+            .await
+            .map_err(|e| SystemMonitorError::Runtime(e.to_string()))?
+    }
+
+    /// 同步获取磁盘信息
+    pub fn get_disk_info(&self, filter: &DiskFilter) -> Result<Vec<DiskInfo>> {
+        let disks = Disks::new_with_refreshed_list();
+        let mut disk_io = self.disk_io.lock()
+            .map_err(|_| SystemMonitorError::SystemInfo("无法获取磁盘速率锁".to_string()))?;
+        let now = Instant::now();
+        let disk_info: Vec<DiskInfo> = disks.iter().filter_map(|disk| {
+            let info = disk_info(disk);
+            filter.allows(&info, disk.is_removable()).then(|| {
+                let usage = disk.usage();
+                let (read_bytes_per_sec, write_bytes_per_sec) =
+                    disk_io.rates(&info.mount_point, usage.total_read_bytes, usage.total_written_bytes, now);
+                DiskInfo { read_bytes_per_sec, write_bytes_per_sec, ..info }
             })
-        }).await.map_err(|e| SystemMonitorError::Runtime(e.to_string()))?
+        }).collect();
+        disk_io.retain(&disk_info);
+        Ok(disk_info)
     }
 
     /// 异步获取磁盘信息
-    pub async fn get_disk_info_async(&self) -> Result<Vec<DiskInfo>> {
+    pub async fn get_disk_info_async(&self, filter: DiskFilter) -> Result<Vec<DiskInfo>> {
+        let manager = self.clone();
+        tokio::task::spawn_blocking(move || manager.get_disk_info(&filter))
+            .await
+            .map_err(|e| SystemMonitorError::Runtime(e.to_string()))?
+    }
+
+    /// 异步获取网络接口信息
+    pub async fn get_network_info_async(&self) -> Result<Vec<NetworkInfo>> {
+        let networks = self.networks.clone();
         tokio::task::spawn_blocking(move || {
-            let disks = Disks::new_with_refreshed_list();
-            let disk_info: Vec<DiskInfo> = disks.iter().map(|disk| {
-                let total = disk.total_space();
-                let available = disk.available_space();
-                let used = total - available;
-                
-                DiskInfo {
-                    name: disk.name().to_string_lossy().to_string(),
-                    mount_point: disk.mount_point().to_string_lossy().to_string(),
-                    file_system: String::from_utf8_lossy(disk.file_system().as_encoded_bytes()).to_string(),
-                    total_space: total,
-                    available_space: available,
-                    used_space: used,
-                    usage_percent: if total > 0 { (used as f64 / total as f64) * 100.0 } else { 0.0 },
+            let mut networks = networks.lock()
+                .map_err(|_| SystemMonitorError::SystemInfo("无法获取网络采样锁".to_string()))?;
+            Ok(networks.sample())
+        }).await.map_err(|e| SystemMonitorError::Runtime(e.to_string()))?
+    }
+
+    /// 异步获取TCP/UDP连接列表
+    pub async fn get_connections_async(&self) -> Result<Vec<connections::ConnectionInfo>> {
+        tokio::task::spawn_blocking(connections::collect_connections)
+            .await
+            .map_err(|e| SystemMonitorError::Runtime(e.to_string()))?
+    }
+
+    /// 异步获取进程信息，按 `sort_by` 选取前 `limit` 个（0表示全部）
+    pub async fn get_process_info_async(&self, sort_by: ProcessSortKey, limit: usize) -> Result<Vec<ProcessInfo>> {
+        let system_clone = self.system.clone();
+        tokio::task::spawn_blocking(move || {
+            let mut system = system_clone.lock().map_err(|_| SystemMonitorError::SystemInfo("无法获取系统信息锁".to_string()))?;
+            system.refresh_processes_specifics(
+                ProcessesToUpdate::All,
+                true,
+                ProcessRefreshKind::nothing()
+                    .with_cpu()
+                    .with_memory()
+                    .with_user(UpdateKind::OnlyIfNotSet)
+                    .with_exe(UpdateKind::OnlyIfNotSet)
+                    .with_cmd(UpdateKind::OnlyIfNotSet),
+            );
+
+            let users = Users::new_with_refreshed_list();
+            let mut processes: Vec<ProcessInfo> = system.processes().iter().map(|(pid, process)| {
+                let (start_time, run_time) = process_times(process);
+                let (name, name_lossy) = process_name(process.name());
+                ProcessInfo {
+                    pid: pid.as_u32(),
+                    name,
+                    cpu_usage: process.cpu_usage(),
+                    memory_usage: process.memory(),
+                    status: format!("{:?}", process.status()),
+                    start_time,
+                    run_time,
+                    name_lossy,
+                    user: process_user(&users, process),
+                    exe_path: process.exe().map(|path| path.to_string_lossy().into_owned()),
+                    cmd: process_cmd(process),
                 }
             }).collect();
-            Ok(disk_info)
+
+            process::select_top(&mut processes, sort_by, limit);
+            Ok(processes)
         }).await.map_err(|e| SystemMonitorError::Runtime(e.to_string()))?
     }
 
@@ -111,11 +483,176 @@ impl SystemInfoManager {
                 hostname: System::host_name().unwrap_or_else(|| "Unknown".to_string()),
                 uptime: System::uptime(),
                 boot_time: System::boot_time(),
+                load_average: current_load_average(),
             })
         }).await.map_err(|e| SystemMonitorError::Runtime(e.to_string()))?
     }
 }
 
+/// 读取1、5、15分钟平均负载，Windows不提供时为 `None`
+pub(crate) fn current_load_average() -> Option<LoadAverage> {
+    if cfg!(windows) {
+        return None;
+    }
+    let load = System::load_average();
+    Some(LoadAverage { one: load.one, five: load.five, fifteen: load.fifteen })
+}
+
+/// 由sysinfo的磁盘生成磁盘信息，读写速率需另行计算
+pub(crate) fn disk_info(disk: &sysinfo::Disk) -> DiskInfo {
+    let total = disk.total_space();
+    let available = disk.available_space();
+    let (used_space, usage_percent) = DiskInfo::space_usage(total, available);
+    DiskInfo {
+        name: disk.name().to_string_lossy().to_string(),
+        mount_point: disk.mount_point().to_string_lossy().to_string(),
+        file_system: String::from_utf8_lossy(disk.file_system().as_encoded_bytes()).to_string(),
+        total_space: total,
+        available_space: available,
+        used_space,
+        usage_percent,
+        read_bytes_per_sec: 0.0,
+        write_bytes_per_sec: 0.0,
+    }
+}
+
+/// 磁盘读写速率计算器
+///
+/// 按挂载点记录上一次采样的累计读写字节数，与本次的差值除以采样间隔得到速率，
+/// 热插拔的磁盘不会互相影响。首次出现的磁盘速率为0；计数器重置时差值按0处理。
+#[derive(Debug, Default)]
+pub(crate) struct DiskIoTracker {
+    /// 挂载点 -> (采样时间, 累计读取字节数, 累计写入字节数)
+    samples: HashMap<String, (Instant, u64, u64)>,
+}
+
+impl DiskIoTracker {
+    /// 记录一次采样，返回 (读取速率, 写入速率)，单位 bytes/s
+    pub(crate) fn rates(&mut self, mount_point: &str, total_read: u64, total_written: u64, now: Instant) -> (f64, f64) {
+        let previous = self.samples.insert(mount_point.to_string(), (now, total_read, total_written));
+        let Some((last, last_read, last_written)) = previous else {
+            return (0.0, 0.0);
+        };
+        let elapsed = now.saturating_duration_since(last).as_secs_f64();
+        if elapsed <= 0.0 {
+            return (0.0, 0.0);
+        }
+        (
+            total_read.saturating_sub(last_read) as f64 / elapsed,
+            total_written.saturating_sub(last_written) as f64 / elapsed,
+        )
+    }
+
+    /// 丢弃已移除磁盘的记录
+    pub(crate) fn retain(&mut self, disks: &[DiskInfo]) {
+        self.samples.retain(|mount_point, _| disks.iter().any(|disk| &disk.mount_point == mount_point));
+    }
+}
+
+/// 网络接口采样器
+///
+/// 保留同一个 `Networks` 实例，每次刷新后sysinfo给出自上次刷新以来的增量，
+/// 除以实际间隔得到速率。接口断开重连导致计数器重置时增量按0处理。
+pub(crate) struct NetworkSampler {
+    networks: Networks,
+    last_refresh: Instant,
+}
+
+impl NetworkSampler {
+    /// 创建采样器并读取一次接口列表作为基准
+    pub(crate) fn new() -> Self {
+        Self {
+            networks: Networks::new_with_refreshed_list(),
+            last_refresh: Instant::now(),
+        }
+    }
+
+    /// 刷新接口计数，返回累计值和自上次采样以来的速率
+    pub(crate) fn sample(&mut self) -> Vec<NetworkInfo> {
+        self.networks.refresh(true);
+        let now = Instant::now();
+        let elapsed = now.saturating_duration_since(self.last_refresh).as_secs_f64();
+        self.last_refresh = now;
+
+        let rate = |bytes: u64| if elapsed > 0.0 { bytes as f64 / elapsed } else { 0.0 };
+        self.networks.iter().map(|(name, network)| NetworkInfo {
+            name: name.clone(),
+            bytes_received: network.total_received(),
+            bytes_sent: network.total_transmitted(),
+            packets_received: network.total_packets_received(),
+            packets_sent: network.total_packets_transmitted(),
+            errors_received: network.total_errors_on_received(),
+            errors_sent: network.total_errors_on_transmitted(),
+            recv_rate_bps: rate(network.received()),
+            send_rate_bps: rate(network.transmitted()),
+        }).collect()
+    }
+}
+
+/// 读取所有温度传感器
+///
+/// 没有可用传感器的平台（如未安装额外驱动的Windows）返回空列表。
+pub(crate) fn read_sensors() -> Vec<SensorInfo> {
+    Components::new_with_refreshed_list().iter().map(|component| SensorInfo {
+        label: component.label().to_string(),
+        temperature: component.temperature(),
+        critical: component.critical(),
+        max: component.max(),
+    }).collect()
+}
+
+/// 获取进程的启动时间和运行时长
+///
+/// sysinfo 在无法获取启动时间时返回0，此时两者都视为未知。
+pub(crate) fn process_times(process: &sysinfo::Process) -> (Option<u64>, Option<u64>) {
+    match process.start_time() {
+        0 => (None, None),
+        start_time => (Some(start_time), Some(process.run_time())),
+    }
+}
+
+/// 解析进程所属的用户名，用户列表每次采集只读取一次
+pub(crate) fn process_user(users: &Users, process: &sysinfo::Process) -> Option<String> {
+    let user_id = process.user_id()?;
+    users.get_user_by_id(user_id).map(|user| user.name().to_string())
+}
+
+/// 获取进程的命令行参数，无效的UTF-8字节被替换
+pub(crate) fn process_cmd(process: &sysinfo::Process) -> Vec<String> {
+    process.cmd().iter().map(|arg| arg.to_string_lossy().into_owned()).collect()
+}
+
+/// 运行期间不变的CPU信息
+#[derive(Debug, Clone, Default)]
+pub(crate) struct CpuIdentity {
+    /// CPU型号
+    pub(crate) brand: String,
+    /// CPU厂商标识
+    pub(crate) vendor: String,
+    /// 物理核心数
+    pub(crate) physical_core_count: Option<usize>,
+}
+
+/// 获取CPU型号、厂商和物理核心数，型号和厂商取第一个核心的信息并去除首尾空白
+pub(crate) fn cpu_identity(system: &System) -> CpuIdentity {
+    let (brand, vendor) = system.cpus().first()
+        .map(|cpu| (cpu.brand().trim().to_string(), cpu.vendor_id().trim().to_string()))
+        .unwrap_or_default();
+    CpuIdentity {
+        brand,
+        vendor,
+        physical_core_count: System::physical_core_count(),
+    }
+}
+
+/// 将进程名称转换为字符串，并标记是否发生了有损转换
+pub(crate) fn process_name(name: &OsStr) -> (String, bool) {
+    match name.to_str() {
+        Some(name) => (name.to_string(), false),
+        None => (name.to_string_lossy().into_owned(), true),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -148,5 +685,200 @@ mod tests {
         let info = memory_info.unwrap();
         assert!(info.total > 0);
         assert!(info.usage_percent >= 0.0 && info.usage_percent <= 100.0);
+        assert!(info.reclaimable <= info.available);
+        assert!(info.swap_usage_percent.is_finite());
+        if info.swap_total == 0 {
+            assert_eq!(info.swap_usage_percent, 0.0);
+        }
+    }
+
+    #[test]
+    fn test_get_memory_info_sync() {
+        let manager = SystemInfoManager::new().unwrap();
+
+        let info = manager.get_memory_info().unwrap();
+        assert!(info.total > 0);
+        assert!(manager.get_disk_info(&DiskFilter::default()).is_ok());
     }
-}
\ No newline at end of file
+
+    #[tokio::test]
+    async fn test_snapshot_marks_uncollected_categories() {
+        let manager = SystemInfoManager::new().unwrap();
+        let request = SnapshotRequest { cpu: false, ..Default::default() };
+        let snapshot = manager.get_snapshot_with(request).await.unwrap();
+
+        assert_eq!(snapshot.cpu_usage(), None);
+        assert!(snapshot.memory_usage().is_some());
+    }
+
+    #[tokio::test]
+    async fn test_snapshot_stream_stops_on_cancel() {
+        let manager = SystemInfoManager::new().unwrap();
+        let token = CancellationToken::new();
+        let mut stream = manager.snapshot_stream(Duration::from_millis(10), token.clone());
+
+        let snapshot = stream.recv().await.expect("应至少产生一个快照");
+        assert!(snapshot.is_ok());
+
+        token.cancel();
+        // 取消后通道中剩余的快照被读完，随后结束
+        let drained = tokio::time::timeout(Duration::from_secs(5), async {
+            while stream.recv().await.is_some() {}
+        }).await;
+        assert!(drained.is_ok(), "取消后快照流应结束");
+    }
+
+    #[tokio::test]
+    async fn test_snapshot_stream_interval_update() {
+        let manager = SystemInfoManager::new().unwrap();
+        let token = CancellationToken::new();
+        let (interval_tx, interval_rx) = watch::channel(Duration::from_secs(3600));
+        let mut stream = manager.snapshot_stream_with(interval_rx, token.clone(), None, || Some(SnapshotRequest::summary()));
+
+        // 首次采集立即进行，之后按一小时的间隔不会再有快照
+        assert!(stream.recv().await.is_some());
+        let waited = tokio::time::timeout(Duration::from_millis(300), stream.recv()).await;
+        assert!(waited.is_err(), "间隔未调整前不应产生新快照");
+
+        interval_tx.send(Duration::from_millis(20)).unwrap();
+        for _ in 0..3 {
+            let next = tokio::time::timeout(Duration::from_secs(5), stream.recv()).await;
+            assert!(matches!(next, Ok(Some(Ok(_)))), "调整间隔后应按新间隔采集");
+        }
+
+        token.cancel();
+    }
+
+    #[tokio::test]
+    async fn test_snapshot_stream_skips_when_no_request() {
+        let manager = SystemInfoManager::new().unwrap();
+        let token = CancellationToken::new();
+        let (_interval_tx, interval_rx) = watch::channel(Duration::from_millis(10));
+        let mut stream = manager.snapshot_stream_with(interval_rx, token.clone(), None, || None);
+
+        let waited = tokio::time::timeout(Duration::from_millis(200), stream.recv()).await;
+        assert!(waited.is_err(), "不需要采集时不应产生快照");
+
+        token.cancel();
+    }
+
+    #[tokio::test]
+    async fn test_get_snapshot_with_processes() {
+        let manager = SystemInfoManager::new().unwrap();
+
+        let snapshot = manager.get_snapshot().await.unwrap();
+        assert!(snapshot.processes.is_none());
+
+        let snapshot = manager.get_snapshot_with(SnapshotRequest { processes: true, ..Default::default() }).await.unwrap();
+        assert!(snapshot.processes.is_some_and(|p| !p.is_empty()));
+
+        let snapshot = manager.get_snapshot_with(SnapshotRequest::summary()).await.unwrap();
+        assert!(snapshot.disks.is_empty());
+        assert!(snapshot.processes.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_snapshot_uses_cached_sensors() {
+        let manager = SystemInfoManager::new().unwrap();
+        assert!(manager.get_snapshot().await.unwrap().sensors.is_empty());
+
+        // 快照只带上最近一次采样的结果，不会自行读取传感器
+        manager.refresh_sensors_async().await.unwrap();
+        let snapshot = manager.get_snapshot().await.unwrap();
+        assert_eq!(snapshot.sensors, manager.cached_sensors());
+    }
+
+    #[tokio::test]
+    async fn test_snapshot_uses_cached_gpus() {
+        let manager = SystemInfoManager::new().unwrap();
+        let request = SnapshotRequest { gpus: true, ..Default::default() };
+
+        // 尚未采样时不启动 nvidia-smi，快照中没有GPU数据
+        assert!(manager.get_snapshot_with(request.clone()).await.unwrap().gpus.is_none());
+
+        manager.refresh_gpus_async().await.unwrap();
+        let snapshot = manager.get_snapshot_with(request).await.unwrap();
+        assert_eq!(snapshot.gpus, manager.cached_gpus());
+    }
+
+    #[test]
+    fn test_disk_io_tracker_rates() {
+        let mut tracker = DiskIoTracker::default();
+        let start = Instant::now();
+
+        // 首次采样速率为0
+        assert_eq!(tracker.rates("/", 1000, 500, start), (0.0, 0.0));
+        assert_eq!(tracker.rates("/mnt/usb", 9000, 9000, start), (0.0, 0.0));
+
+        let later = start + Duration::from_secs(2);
+        assert_eq!(tracker.rates("/", 3000, 1500, later), (1000.0, 500.0));
+        // 计数器重置时不出现负值
+        assert_eq!(tracker.rates("/mnt/usb", 100, 100, later), (0.0, 0.0));
+
+        // 拔出的磁盘重新接入时按首次采样处理
+        tracker.retain(&[DiskInfo { mount_point: "/".to_string(), ..Default::default() }]);
+        assert_eq!(tracker.rates("/mnt/usb", 5000, 5000, later + Duration::from_secs(1)), (0.0, 0.0));
+    }
+
+    #[test]
+    fn test_network_sampler_rates_are_non_negative() {
+        let mut sampler = NetworkSampler::new();
+        std::thread::sleep(Duration::from_millis(20));
+        for network in sampler.sample() {
+            assert!(network.recv_rate_bps.is_finite() && network.recv_rate_bps >= 0.0);
+            assert!(network.send_rate_bps.is_finite() && network.send_rate_bps >= 0.0);
+        }
+    }
+
+    #[test]
+    fn test_snapshot_request_from_monitoring() {
+        let mut monitoring = MonitoringConfig::default();
+        assert_eq!(
+            SnapshotRequest::from_monitoring(&monitoring, false),
+            SnapshotRequest { gpus: true, ..Default::default() }
+        );
+        assert!(SnapshotRequest::from_monitoring(&monitoring, true).processes);
+
+        monitoring.enable_gpu_monitoring = false;
+        assert!(!SnapshotRequest::from_monitoring(&monitoring, false).gpus);
+
+        monitoring.enable_disk_monitoring = false;
+        monitoring.enable_cpu_monitoring = false;
+        let request = SnapshotRequest::from_monitoring(&monitoring, false);
+        assert!(!request.disks);
+        assert!(!request.cpu);
+        assert!(request.memory);
+
+        monitoring.process_collection_mode = ProcessCollectionMode::Always;
+        assert!(SnapshotRequest::from_monitoring(&monitoring, false).processes);
+        monitoring.process_collection_mode = ProcessCollectionMode::Never;
+        assert!(!SnapshotRequest::from_monitoring(&monitoring, true).processes);
+    }
+
+    #[test]
+    fn test_snapshot_request_minimized() {
+        let mut monitoring = MonitoringConfig {
+            process_collection_mode: ProcessCollectionMode::Always,
+            ..Default::default()
+        };
+        let request = SnapshotRequest::from_monitoring(&monitoring, true).minimized();
+        assert!(request.cpu && request.memory);
+        assert!(!request.disks && !request.processes);
+
+        // 禁用的类别不会因最小化而重新启用
+        monitoring.enable_cpu_monitoring = false;
+        assert!(!SnapshotRequest::from_monitoring(&monitoring, false).minimized().cpu);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_process_name_non_utf8() {
+        use std::os::unix::ffi::OsStrExt;
+
+        assert_eq!(process_name(OsStr::new("init")), ("init".to_string(), false));
+
+        let (name, lossy) = process_name(OsStr::from_bytes(b"bad\xFFname"));
+        assert_eq!(name, "bad\u{FFFD}name");
+        assert!(lossy);
+    }
+}
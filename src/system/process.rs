@@ -0,0 +1,318 @@
+//! 进程视图模块
+//!
+//! 定义进程列表的过滤与排序规则，界面显示与导出共用同一套规则，保证两者一致。
+
+use crate::system::info::ProcessInfo;
+use crate::utils::t;
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+use sysinfo::{Pid, ProcessesToUpdate, System};
+
+/// 进程排序字段
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ProcessSortKey {
+    /// 按CPU使用率
+    #[default]
+    Cpu,
+    /// 按内存使用量
+    Memory,
+    /// 按进程ID
+    Pid,
+    /// 按进程名称
+    Name,
+    /// 按运行时长（运行时长未知的进程视为最短）
+    Age,
+}
+
+impl ProcessSortKey {
+    /// 获取排序字段名称
+    pub fn label(&self) -> &'static str {
+        match self {
+            ProcessSortKey::Cpu => "CPU",
+            ProcessSortKey::Memory => t("内存"),
+            ProcessSortKey::Pid => "PID",
+            ProcessSortKey::Name => t("名称"),
+            ProcessSortKey::Age => t("运行时间"),
+        }
+    }
+
+    /// 采集时的排序方向：CPU、内存和运行时间取最大的在前，PID和名称按升序
+    pub fn collect_descending(&self) -> bool {
+        matches!(self, ProcessSortKey::Cpu | ProcessSortKey::Memory | ProcessSortKey::Age)
+    }
+
+    /// 比较两个进程
+    pub fn compare(&self, a: &ProcessInfo, b: &ProcessInfo) -> Ordering {
+        match self {
+            ProcessSortKey::Cpu => a.cpu_usage.partial_cmp(&b.cpu_usage).unwrap_or(Ordering::Equal),
+            ProcessSortKey::Memory => a.memory_usage.cmp(&b.memory_usage),
+            ProcessSortKey::Pid => a.pid.cmp(&b.pid),
+            ProcessSortKey::Name => a.name.to_lowercase().cmp(&b.name.to_lowercase()),
+            ProcessSortKey::Age => a.run_time.cmp(&b.run_time),
+        }
+    }
+}
+
+/// 进程列表视图（过滤 + 排序）
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProcessView {
+    /// 过滤字符串，按名称或PID子串匹配（不区分大小写），为空时显示全部
+    pub filter: String,
+    /// 排序字段
+    pub sort_key: ProcessSortKey,
+    /// 是否降序
+    pub descending: bool,
+}
+
+impl Default for ProcessView {
+    fn default() -> Self {
+        Self {
+            filter: String::new(),
+            sort_key: ProcessSortKey::Cpu,
+            descending: true,
+        }
+    }
+}
+
+impl ProcessView {
+    /// 判断进程是否匹配过滤条件
+    pub fn matches(&self, process: &ProcessInfo) -> bool {
+        let filter = self.filter.trim();
+        if filter.is_empty() {
+            return true;
+        }
+
+        let filter = filter.to_lowercase();
+        process.name.to_lowercase().contains(&filter) || process.pid.to_string().contains(&filter)
+    }
+
+    /// 先过滤后排序，返回视图中的进程
+    pub fn apply<'a>(&self, processes: &'a [ProcessInfo]) -> Vec<&'a ProcessInfo> {
+        self.sorted_indices(processes).into_iter().map(|index| &processes[index]).collect()
+    }
+
+    /// 先过滤后排序，返回视图中的进程在 `processes` 中的下标，便于缓存排序结果
+    pub fn sorted_indices(&self, processes: &[ProcessInfo]) -> Vec<usize> {
+        let mut indices: Vec<usize> = (0..processes.len()).filter(|&index| self.matches(&processes[index])).collect();
+        indices.sort_by(|&a, &b| {
+            let ordering = self.sort_key.compare(&processes[a], &processes[b]);
+            if self.descending {
+                ordering.reverse()
+            } else {
+                ordering
+            }
+        });
+        indices
+    }
+}
+
+/// 按采集排序字段排序并保留前 `limit` 个进程，`limit` 为0时保留全部
+pub fn select_top(processes: &mut Vec<ProcessInfo>, sort_by: ProcessSortKey, limit: usize) {
+    processes.sort_by(|a, b| {
+        let ordering = sort_by.compare(a, b);
+        if sort_by.collect_descending() {
+            ordering.reverse()
+        } else {
+            ordering
+        }
+    });
+    if limit > 0 {
+        processes.truncate(limit);
+    }
+}
+
+/// 进程分组键
+///
+/// 名称经过有损转换的进程可能与其他进程得到相同的显示名称，
+/// 因此按PID单独成组，避免把不同的进程合并在一起。
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ProcessGroupKey {
+    /// 按名称分组
+    Name(String),
+    /// 名称不可靠，按PID单独分组
+    Pid(u32),
+}
+
+impl ProcessGroupKey {
+    /// 获取进程的分组键
+    pub fn of(process: &ProcessInfo) -> Self {
+        if process.name_lossy {
+            ProcessGroupKey::Pid(process.pid)
+        } else {
+            ProcessGroupKey::Name(process.name.clone())
+        }
+    }
+}
+
+/// 按名称对进程分组
+pub fn group_by_name(processes: &[ProcessInfo]) -> BTreeMap<ProcessGroupKey, Vec<&ProcessInfo>> {
+    let mut groups: BTreeMap<ProcessGroupKey, Vec<&ProcessInfo>> = BTreeMap::new();
+    for process in processes {
+        groups.entry(ProcessGroupKey::of(process)).or_default().push(process);
+    }
+    groups
+}
+
+/// 要结束的进程
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProcessTarget {
+    /// 进程ID
+    pub pid: u32,
+    /// 选择时的启动时间，用于识别PID被新进程复用的情况
+    pub start_time: Option<u64>,
+}
+
+impl ProcessTarget {
+    /// 以进程当前的信息创建目标
+    pub fn of(process: &ProcessInfo) -> Self {
+        Self { pid: process.pid, start_time: process.start_time }
+    }
+}
+
+/// 批量结束进程的结果
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct KillReport {
+    /// 已发送结束信号的进程
+    pub killed: Vec<u32>,
+    /// 已退出或PID已被其他进程复用，因此跳过的进程
+    pub exited: Vec<u32>,
+    /// 结束失败的进程（通常是权限不足）
+    pub failed: Vec<u32>,
+}
+
+/// 结束指定的进程
+///
+/// 从选择到执行之间目标进程可能已经退出，其PID甚至可能被新进程复用，
+/// 因此执行前重新读取进程信息，启动时间不一致的进程不会被结束。
+pub fn kill_processes(targets: &[ProcessTarget]) -> KillReport {
+    let pids: Vec<Pid> = targets.iter().map(|target| Pid::from_u32(target.pid)).collect();
+    let mut system = System::new();
+    system.refresh_processes(ProcessesToUpdate::Some(&pids), true);
+
+    let mut report = KillReport::default();
+    for target in targets {
+        match system.process(Pid::from_u32(target.pid)) {
+            Some(process) if target.start_time.is_none_or(|start_time| process.start_time() == start_time) => {
+                if process.kill() {
+                    report.killed.push(target.pid);
+                } else {
+                    report.failed.push(target.pid);
+                }
+            }
+            _ => report.exited.push(target.pid),
+        }
+    }
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn process(pid: u32, name: &str, cpu_usage: f32, memory_usage: u64) -> ProcessInfo {
+        ProcessInfo {
+            pid,
+            name: name.to_string(),
+            cpu_usage,
+            memory_usage,
+            status: "Run".to_string(),
+            start_time: None,
+            run_time: None,
+            name_lossy: false,
+            user: None,
+            exe_path: None,
+            cmd: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_select_top() {
+        let all = vec![
+            process(3, "b", 5.0, 300),
+            process(1, "c", 20.0, 100),
+            process(2, "a", 10.0, 200),
+        ];
+
+        let mut processes = all.clone();
+        select_top(&mut processes, ProcessSortKey::Cpu, 2);
+        assert_eq!(processes.iter().map(|p| p.pid).collect::<Vec<_>>(), vec![1, 2]);
+
+        let mut processes = all.clone();
+        select_top(&mut processes, ProcessSortKey::Name, 0);
+        assert_eq!(processes.iter().map(|p| p.pid).collect::<Vec<_>>(), vec![2, 3, 1]);
+    }
+
+    #[test]
+    fn test_process_view_filter_and_sort() {
+        let processes = vec![
+            process(1, "init", 0.5, 1024),
+            process(42, "Firefox", 30.0, 4096),
+            process(420, "firefox-helper", 10.0, 2048),
+        ];
+
+        let view = ProcessView::default();
+        let pids: Vec<u32> = view.apply(&processes).iter().map(|p| p.pid).collect();
+        assert_eq!(pids, vec![42, 420, 1]);
+
+        let view = ProcessView { filter: "FIRE".to_string(), sort_key: ProcessSortKey::Memory, descending: false };
+        let pids: Vec<u32> = view.apply(&processes).iter().map(|p| p.pid).collect();
+        assert_eq!(pids, vec![420, 42]);
+
+        let view = ProcessView { filter: "42".to_string(), sort_key: ProcessSortKey::Pid, descending: false };
+        let pids: Vec<u32> = view.apply(&processes).iter().map(|p| p.pid).collect();
+        assert_eq!(pids, vec![42, 420]);
+        assert_eq!(view.sorted_indices(&processes), vec![1, 2]);
+    }
+
+    #[test]
+    fn test_process_view_sort_by_age() {
+        let mut processes = vec![
+            process(1, "init", 0.5, 1024),
+            process(2, "sshd", 1.0, 1024),
+            process(3, "bash", 2.0, 1024),
+        ];
+        processes[0].run_time = Some(86_400);
+        processes[2].run_time = Some(60);
+
+        let view = ProcessView { sort_key: ProcessSortKey::Age, ..Default::default() };
+        let pids: Vec<u32> = view.apply(&processes).iter().map(|p| p.pid).collect();
+        assert_eq!(pids, vec![1, 3, 2]);
+    }
+
+    #[test]
+    fn test_group_by_name_keeps_lossy_names_apart() {
+        let mut processes = vec![
+            process(1, "worker", 0.0, 0),
+            process(2, "worker", 0.0, 0),
+            process(3, "bad\u{FFFD}name", 0.0, 0),
+            process(4, "bad\u{FFFD}name", 0.0, 0),
+        ];
+        processes[2].name_lossy = true;
+        processes[3].name_lossy = true;
+
+        let groups = group_by_name(&processes);
+        assert_eq!(groups.len(), 3);
+        assert_eq!(groups[&ProcessGroupKey::Name("worker".to_string())].len(), 2);
+        assert_eq!(groups[&ProcessGroupKey::Pid(3)].len(), 1);
+        assert_eq!(groups[&ProcessGroupKey::Pid(4)].len(), 1);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_kill_processes_skips_exited_and_reused_pids() {
+        let mut child = std::process::Command::new("sleep").arg("30").spawn().unwrap();
+        let pid = child.id();
+
+        // 启动时间不一致视为PID被复用，不结束该进程
+        let reused = ProcessTarget { pid, start_time: Some(1) };
+        let missing = ProcessTarget { pid: u32::MAX - 1, start_time: None };
+        let report = kill_processes(&[reused, missing]);
+        assert_eq!(report.exited, vec![pid, u32::MAX - 1]);
+        assert!(report.killed.is_empty());
+
+        let report = kill_processes(&[ProcessTarget { pid, start_time: None }]);
+        assert_eq!(report.killed, vec![pid]);
+        assert!(!child.wait().unwrap().success());
+    }
+}
@@ -0,0 +1,197 @@
+//! Prometheus指标端点模块
+//!
+//! 以Prometheus文本格式（0.0.4）在 `/metrics` 提供最近一次快照的指标，
+//! 便于集中采集多台主机。只处理简单的GET请求，不依赖额外的HTTP库。
+
+use crate::error::Result;
+use crate::system::{NetworkInfo, SystemSnapshot};
+use std::fmt::Write as _;
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio_util::sync::CancellationToken;
+
+/// 与采集任务共享的最新快照
+pub type SharedSnapshot = Arc<RwLock<Option<SystemSnapshot>>>;
+
+/// 请求头的最大长度
+const MAX_REQUEST_BYTES: usize = 8192;
+
+/// 读取请求的超时时间
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// 转义标签值中的反斜杠、双引号和换行
+fn escape_label(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n")
+}
+
+/// 写入一个仪表指标的说明和类型
+fn write_gauge_header(output: &mut String, name: &str, help: &str) {
+    let _ = writeln!(output, "# HELP {} {}", name, help);
+    let _ = writeln!(output, "# TYPE {} gauge", name);
+}
+
+/// 将快照渲染为Prometheus文本格式
+pub fn render_metrics(snapshot: &SystemSnapshot) -> String {
+    let mut output = String::new();
+
+    if let Some(cpu) = snapshot.cpu_usage() {
+        write_gauge_header(&mut output, "system_cpu_usage", "Global CPU usage in percent.");
+        let _ = writeln!(output, "system_cpu_usage {}", cpu);
+    }
+
+    if let Some(memory) = snapshot.memory_usage() {
+        write_gauge_header(&mut output, "system_memory_usage_percent", "Memory usage in percent.");
+        let _ = writeln!(output, "system_memory_usage_percent {}", memory);
+        write_gauge_header(&mut output, "system_memory_used_bytes", "Used memory in bytes.");
+        let _ = writeln!(output, "system_memory_used_bytes {}", snapshot.memory.used);
+        write_gauge_header(&mut output, "system_memory_total_bytes", "Total memory in bytes.");
+        let _ = writeln!(output, "system_memory_total_bytes {}", snapshot.memory.total);
+    }
+
+    if !snapshot.disks.is_empty() {
+        write_gauge_header(&mut output, "system_disk_usage_percent", "Disk usage in percent.");
+        for disk in &snapshot.disks {
+            let _ = writeln!(
+                output,
+                "system_disk_usage_percent{{mount=\"{}\"}} {}",
+                escape_label(&disk.mount_point),
+                disk.usage_percent
+            );
+        }
+    }
+
+    if let Some(networks) = snapshot.networks.as_deref() {
+        let total = NetworkInfo::aggregate(networks);
+        write_gauge_header(&mut output, "system_network_received_bytes", "Total bytes received on all interfaces.");
+        let _ = writeln!(output, "system_network_received_bytes {}", total.bytes_received);
+        write_gauge_header(&mut output, "system_network_sent_bytes", "Total bytes sent on all interfaces.");
+        let _ = writeln!(output, "system_network_sent_bytes {}", total.bytes_sent);
+    }
+
+    output
+}
+
+/// 在指定端口（所有网络接口）提供指标，取消令牌触发后停止
+pub async fn serve(port: u16, snapshot: SharedSnapshot, cancel_token: CancellationToken) -> Result<()> {
+    let listener = TcpListener::bind(("0.0.0.0", port)).await?;
+    log::info!("Prometheus指标端点已启动: http://0.0.0.0:{}/metrics", port);
+    serve_listener(listener, snapshot, cancel_token).await;
+    Ok(())
+}
+
+/// 在已绑定的监听器上提供指标，取消令牌触发后停止
+pub async fn serve_listener(listener: TcpListener, snapshot: SharedSnapshot, cancel_token: CancellationToken) {
+    loop {
+        let accepted = tokio::select! {
+            _ = cancel_token.cancelled() => break,
+            accepted = listener.accept() => accepted,
+        };
+        match accepted {
+            Ok((stream, _)) => {
+                let snapshot = snapshot.clone();
+                tokio::spawn(async move {
+                    if let Err(e) = handle_connection(stream, &snapshot).await {
+                        log::debug!("处理指标请求失败: {}", e);
+                    }
+                });
+            }
+            Err(e) => log::warn!("接受指标连接失败: {}", e),
+        }
+    }
+    log::info!("Prometheus指标端点已停止");
+}
+
+/// 读取请求行并返回响应
+async fn handle_connection(mut stream: TcpStream, snapshot: &SharedSnapshot) -> Result<()> {
+    let mut request = Vec::new();
+    let mut buffer = [0u8; 1024];
+    let read_head = async {
+        while !request.windows(4).any(|window| window == b"\r\n\r\n") && request.len() < MAX_REQUEST_BYTES {
+            let read = stream.read(&mut buffer).await?;
+            if read == 0 {
+                break;
+            }
+            request.extend_from_slice(&buffer[..read]);
+        }
+        std::io::Result::Ok(())
+    };
+    if tokio::time::timeout(REQUEST_TIMEOUT, read_head).await.is_err() {
+        return Ok(());
+    }
+
+    let request = String::from_utf8_lossy(&request);
+    let mut parts = request.lines().next().unwrap_or_default().split_whitespace();
+    let (status, body) = match (parts.next(), parts.next()) {
+        (Some("GET"), Some("/metrics")) => {
+            let rendered = snapshot.read().ok().and_then(|latest| latest.as_ref().map(render_metrics));
+            match rendered {
+                Some(body) => ("200 OK", body),
+                None => ("503 Service Unavailable", "暂无快照数据\n".to_string()),
+            }
+        }
+        (Some("GET"), Some(_)) => ("404 Not Found", "Not Found\n".to_string()),
+        _ => ("405 Method Not Allowed", "Method Not Allowed\n".to_string()),
+    };
+
+    let response = format!(
+        "HTTP/1.1 {}\r\nContent-Type: text/plain; version=0.0.4; charset=utf-8\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        body.len(),
+        body
+    );
+    stream.write_all(response.as_bytes()).await?;
+    stream.shutdown().await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::system::{CpuInfo, DiskInfo, MemoryInfo, SystemInfo};
+
+    fn snapshot() -> SystemSnapshot {
+        let cpu = CpuInfo { global_usage: 12.5, ..Default::default() };
+        let memory = MemoryInfo { usage_percent: 40.0, ..Default::default() };
+        let disk = DiskInfo { mount_point: "/".to_string(), usage_percent: 70.0, ..Default::default() };
+        SystemSnapshot::new(cpu, memory, vec![disk], SystemInfo::default(), None)
+    }
+
+    #[test]
+    fn test_render_metrics() {
+        let output = render_metrics(&snapshot());
+        assert!(output.contains("# TYPE system_cpu_usage gauge\nsystem_cpu_usage 12.5\n"));
+        assert!(output.contains("system_memory_usage_percent 40\n"));
+        assert!(output.contains("system_disk_usage_percent{mount=\"/\"} 70\n"));
+        assert!(!output.contains("system_network_received_bytes"));
+        assert_eq!(escape_label("C:\\ \"x\""), "C:\\\\ \\\"x\\\"");
+    }
+
+    #[tokio::test]
+    async fn test_serve_metrics() {
+        let listener = TcpListener::bind(("127.0.0.1", 0)).await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let shared: SharedSnapshot = Arc::new(RwLock::new(None));
+        let token = CancellationToken::new();
+        let server = tokio::spawn(serve_listener(listener, shared.clone(), token.clone()));
+
+        let get = |path: &'static str| async move {
+            let mut stream = TcpStream::connect(address).await.unwrap();
+            stream.write_all(format!("GET {} HTTP/1.1\r\nHost: localhost\r\n\r\n", path).as_bytes()).await.unwrap();
+            let mut response = String::new();
+            stream.read_to_string(&mut response).await.unwrap();
+            response
+        };
+
+        assert!(get("/metrics").await.starts_with("HTTP/1.1 503"));
+        *shared.write().unwrap() = Some(snapshot());
+        let response = get("/metrics").await;
+        assert!(response.starts_with("HTTP/1.1 200 OK"));
+        assert!(response.contains("system_cpu_usage 12.5"));
+        assert!(get("/other").await.starts_with("HTTP/1.1 404"));
+
+        token.cancel();
+        assert!(tokio::time::timeout(Duration::from_secs(5), server).await.is_ok(), "取消后端点应停止");
+    }
+}
@@ -0,0 +1,105 @@
+//! 远程主机模块
+//!
+//! 通过SSH在远程主机上执行无界面采集命令，解析其输出的JSON快照，
+//! 使界面可以在本机和配置的远程主机之间切换。
+
+use crate::config::RemoteHost;
+use crate::error::{Result, SystemMonitorError};
+use crate::system::{SnapshotRequest, SystemInfoManager, SystemSnapshot};
+use std::future::Future;
+use std::time::Duration;
+use tokio::process::Command;
+
+/// 远程采集的超时时间
+const REMOTE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// 快照来源
+pub trait SnapshotSource {
+    /// 获取一次快照
+    fn snapshot(&self, request: SnapshotRequest) -> impl Future<Output = Result<SystemSnapshot>> + Send;
+}
+
+impl SnapshotSource for SystemInfoManager {
+    fn snapshot(&self, request: SnapshotRequest) -> impl Future<Output = Result<SystemSnapshot>> + Send {
+        self.get_snapshot_with(request)
+    }
+}
+
+/// 远程快照来源
+///
+/// 远程主机需安装本程序并可通过免密SSH登录，采集请求由远程命令自行决定，
+/// 因此 `SnapshotRequest` 在此被忽略。
+#[derive(Debug, Clone)]
+pub struct RemoteSource {
+    host: RemoteHost,
+}
+
+impl RemoteSource {
+    /// 创建远程快照来源
+    pub fn new(host: RemoteHost) -> Self {
+        Self { host }
+    }
+
+    /// 远程主机配置
+    pub fn host(&self) -> &RemoteHost {
+        &self.host
+    }
+
+    /// 通过SSH执行远程命令并解析输出
+    async fn fetch(&self) -> Result<SystemSnapshot> {
+        let output = Command::new("ssh")
+            .args(["-o", "BatchMode=yes", "-o", "ConnectTimeout=5"])
+            // 防止目标被当作ssh选项解析
+            .arg("--")
+            .arg(&self.host.target)
+            .arg(&self.host.command)
+            .kill_on_drop(true)
+            .output();
+
+        let output = tokio::time::timeout(REMOTE_TIMEOUT, output)
+            .await
+            .map_err(|_| SystemMonitorError::Runtime(format!("连接 {} 超时", self.host.name)))??;
+
+        if !output.status.success() {
+            return Err(SystemMonitorError::Runtime(format!(
+                "远程命令执行失败（{}）: {}",
+                output.status,
+                String::from_utf8_lossy(&output.stderr).trim()
+            )));
+        }
+
+        parse_snapshot(&output.stdout)
+    }
+}
+
+impl SnapshotSource for RemoteSource {
+    fn snapshot(&self, _request: SnapshotRequest) -> impl Future<Output = Result<SystemSnapshot>> + Send {
+        self.fetch()
+    }
+}
+
+/// 解析远程命令输出的JSON快照
+pub fn parse_snapshot(output: &[u8]) -> Result<SystemSnapshot> {
+    Ok(serde_json::from_slice(output)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::system::info::{CpuInfo, MemoryInfo, SystemInfo};
+
+    #[test]
+    fn test_parse_snapshot_round_trip() {
+        let snapshot = SystemSnapshot::new(
+            CpuInfo { global_usage: 42.0, ..Default::default() },
+            MemoryInfo::default(),
+            vec![],
+            SystemInfo::default(),
+            None,
+        );
+        let json = serde_json::to_vec(&snapshot).unwrap();
+        assert_eq!(parse_snapshot(&json).unwrap().cpu.global_usage, 42.0);
+
+        assert!(parse_snapshot(b"ssh: connect to host").is_err());
+    }
+}
@@ -2,17 +2,29 @@
 //! 
 //! 提供各种数据可视化图表组件。
 
+use crate::utils::{t, Formatter};
 use eframe::{egui, epaint::StrokeKind};
 use std::collections::VecDeque;
+use std::ops::Range;
+use std::time::{Duration, Instant};
+
+/// 超过采样间隔的该倍数即视为数据断档
+const GAP_FACTOR: u32 = 2;
 
 /// 简单的线性图表组件
 pub struct LineChart {
     data: VecDeque<f32>,
+    timestamps: VecDeque<Instant>,
     max_points: usize,
     min_value: f32,
     max_value: f32,
     color: egui::Color32,
     fill_color: Option<egui::Color32>,
+    gap_threshold: Option<Duration>,
+    threshold_lines: Vec<(f32, egui::Color32, String)>,
+    line_width: f32,
+    show_points: bool,
+    autoscale: bool,
 }
 
 impl LineChart {
@@ -20,11 +32,17 @@ impl LineChart {
     pub fn new(max_points: usize, color: egui::Color32) -> Self {
         Self {
             data: VecDeque::with_capacity(max_points),
+            timestamps: VecDeque::with_capacity(max_points),
             max_points,
             min_value: 0.0,
             max_value: 100.0,
             color,
             fill_color: None,
+            gap_threshold: None,
+            threshold_lines: Vec::new(),
+            line_width: 2.0,
+            show_points: true,
+            autoscale: false,
         }
     }
 
@@ -41,22 +59,99 @@ impl LineChart {
         self
     }
 
+    /// 启用断档检测，相邻数据点间隔超过采样间隔两倍时断开线条
+    pub fn with_gap_detection(mut self, sample_interval: Duration) -> Self {
+        self.gap_threshold = Some(sample_interval * GAP_FACTOR);
+        self
+    }
+
+    /// 设置水平阈值参考线，每项为（数值, 颜色, 标签）
+    pub fn with_threshold_lines(mut self, lines: Vec<(f32, egui::Color32, String)>) -> Self {
+        self.threshold_lines = lines;
+        self
+    }
+
+    /// 设置线条宽度
+    pub fn with_line_width(mut self, width: f32) -> Self {
+        self.line_width = width;
+        self
+    }
+
+    /// 设置是否绘制数据点
+    pub fn with_points(mut self, show_points: bool) -> Self {
+        self.show_points = show_points;
+        self
+    }
+
+    /// 设置是否按数据范围自动缩放Y轴，关闭时使用固定值范围
+    pub fn with_autoscale(mut self, autoscale: bool) -> Self {
+        self.autoscale = autoscale;
+        self
+    }
+
+    /// 当前使用的Y轴范围
+    ///
+    /// 自动缩放时按数据最值上下各留10%余量，数据均非负时下限不低于0。
+    pub fn value_range(&self) -> (f32, f32) {
+        if !self.autoscale || self.data.is_empty() {
+            return (self.min_value, self.max_value);
+        }
+
+        let (min, max) = self.data.iter().fold((f32::MAX, f32::MIN), |(min, max), &value| {
+            (min.min(value), max.max(value))
+        });
+        let padding = if max > min { (max - min) * 0.1 } else { (max.abs() * 0.1).max(1.0) };
+        let lower = if min >= 0.0 { (min - padding).max(0.0) } else { min - padding };
+        (lower, max + padding)
+    }
+
     /// 添加数据点
     pub fn add_point(&mut self, value: f32) {
+        self.add_point_at(Instant::now(), value);
+    }
+
+    /// 添加带时间戳的数据点
+    pub fn add_point_at(&mut self, timestamp: Instant, value: f32) {
         if self.data.len() >= self.max_points {
             self.data.pop_front();
+            self.timestamps.pop_front();
         }
         self.data.push_back(value);
+        self.timestamps.push_back(timestamp);
     }
 
     /// 设置数据
     pub fn set_data(&mut self, data: Vec<f32>) {
         self.data.clear();
+        self.timestamps.clear();
         for value in data.into_iter().take(self.max_points) {
             self.data.push_back(value);
         }
     }
 
+    /// 设置带时间戳的历史数据，保留最新的数据点
+    pub fn set_timed_data(&mut self, data: &[(Instant, f32)]) {
+        self.data.clear();
+        self.timestamps.clear();
+        let skip = data.len().saturating_sub(self.max_points);
+        for &(timestamp, value) in &data[skip..] {
+            self.data.push_back(value);
+            self.timestamps.push_back(timestamp);
+        }
+    }
+
+    /// 按断档拆分的连续数据段
+    fn segments(&self) -> Vec<Range<usize>> {
+        match self.gap_threshold {
+            // 没有完整时间戳时无法判断断档
+            Some(threshold) if self.timestamps.len() == self.data.len() => {
+                let timestamps: Vec<Instant> = self.timestamps.iter().copied().collect();
+                split_at_gaps(&timestamps, threshold)
+            }
+            _ => std::iter::once(0..self.data.len()).collect(),
+        }
+    }
+
     /// 渲染图表
     pub fn render(&self, ui: &mut egui::Ui, size: egui::Vec2) -> egui::Response {
         let (response, painter) = ui.allocate_painter(size, egui::Sense::hover());
@@ -68,7 +163,7 @@ impl LineChart {
             painter.text(
                 rect.center(),
                 egui::Align2::CENTER_CENTER,
-                "无数据",
+                t("无数据"),
                 egui::FontId::default(),
                 ui.visuals().weak_text_color(),
             );
@@ -80,103 +175,107 @@ impl LineChart {
         painter.rect_stroke(rect, 2.0, egui::Stroke::new(1.0, ui.visuals().weak_text_color()), StrokeKind::Middle);
 
         // 计算点的位置
+        let (min_value, max_value) = self.value_range();
         let points: Vec<egui::Pos2> = self.data
             .iter()
             .enumerate()
             .map(|(i, &value)| {
-                let x = rect.left() + (i as f32 / (self.max_points - 1).max(1) as f32) * rect.width();
-                let normalized_value = (value - self.min_value) / (self.max_value - self.min_value);
-                let y = rect.bottom() - normalized_value.clamp(0.0, 1.0) * rect.height();
-                egui::Pos2::new(x, y)
+                egui::Pos2::new(x_position(rect, i, self.max_points), y_position(rect, value, (min_value, max_value)))
             })
             .collect();
 
-        // 绘制填充区域
-        if let Some(fill_color) = self.fill_color {
-            if points.len() > 1 {
-                if let (Some(first), Some(last)) = (points.first(), points.last()) {
-                    let mut fill_points = points.clone();
-                    fill_points.push(egui::Pos2::new(last.x, rect.bottom()));
-                    fill_points.push(egui::Pos2::new(first.x, rect.bottom()));
-
-                    painter.add(egui::Shape::convex_polygon(
-                        fill_points,
-                        fill_color,
-                        egui::Stroke::NONE,
-                    ));
+        let segments = self.segments();
+
+        // 绘制断档区域，断档两侧不连线
+        let gap_color = ui.visuals().weak_text_color().gamma_multiply(0.15);
+        for pair in segments.windows(2) {
+            let gap_rect = egui::Rect::from_x_y_ranges(
+                points[pair[0].end - 1].x..=points[pair[1].start].x,
+                rect.y_range(),
+            );
+            painter.rect_filled(gap_rect, 0.0, gap_color);
+        }
+
+        for segment in &segments {
+            let segment_points = &points[segment.clone()];
+
+            // 绘制填充区域
+            if let Some(fill_color) = self.fill_color {
+                if segment_points.len() > 1 {
+                    if let (Some(first), Some(last)) = (segment_points.first(), segment_points.last()) {
+                        let mut fill_points = segment_points.to_vec();
+                        fill_points.push(egui::Pos2::new(last.x, rect.bottom()));
+                        fill_points.push(egui::Pos2::new(first.x, rect.bottom()));
+
+                        painter.add(egui::Shape::convex_polygon(
+                            fill_points,
+                            fill_color,
+                            egui::Stroke::NONE,
+                        ));
+                    }
                 }
             }
-        }
 
-        // 绘制线条
-        if points.len() > 1 {
-            painter.add(egui::Shape::line(
-                points.clone(),
-                egui::Stroke::new(2.0, self.color),
-            ));
+            // 绘制线条
+            if segment_points.len() > 1 {
+                painter.add(egui::Shape::line(
+                    segment_points.to_vec(),
+                    egui::Stroke::new(self.line_width, self.color),
+                ));
+            }
         }
 
         // 绘制数据点
-        for point in &points {
-            painter.circle_filled(*point, 2.0, self.color);
+        if self.show_points {
+            for point in &points {
+                painter.circle_filled(*point, 2.0, self.color);
+            }
         }
 
         // 绘制网格线（可选）
-        self.draw_grid(&painter, rect, ui);
+        draw_grid(&painter, rect, ui);
+
+        // 绘制阈值参考线
+        self.draw_threshold_lines(&painter, rect, (min_value, max_value));
 
         // 绘制数值标签
-        self.draw_labels(&painter, rect, ui);
+        self.draw_labels(&painter, rect, ui, (min_value, max_value));
 
         response
     }
 
-    /// 绘制网格线
-    fn draw_grid(&self, painter: &egui::Painter, rect: egui::Rect, ui: &egui::Ui) {
-        let grid_color = ui.visuals().weak_text_color().gamma_multiply(0.3);
-        let stroke = egui::Stroke::new(0.5, grid_color);
+    /// 绘制阈值参考线，超出值范围的阈值不绘制
+    fn draw_threshold_lines(&self, painter: &egui::Painter, rect: egui::Rect, (min_value, max_value): (f32, f32)) {
+        let font_id = egui::FontId::proportional(9.0);
 
-        // 水平网格线
-        for i in 1..5 {
-            let y = rect.top() + (i as f32 / 5.0) * rect.height();
-            painter.line_segment(
-                [egui::Pos2::new(rect.left(), y), egui::Pos2::new(rect.right(), y)],
-                stroke,
-            );
-        }
+        for (value, color, label) in &self.threshold_lines {
+            if *value < min_value || *value > max_value {
+                continue;
+            }
 
-        // 垂直网格线
-        for i in 1..10 {
-            let x = rect.left() + (i as f32 / 10.0) * rect.width();
-            painter.line_segment(
-                [egui::Pos2::new(x, rect.top()), egui::Pos2::new(x, rect.bottom())],
-                stroke,
+            let y = y_position(rect, *value, (min_value, max_value));
+            painter.extend(egui::Shape::dashed_line(
+                &[egui::Pos2::new(rect.left(), y), egui::Pos2::new(rect.right(), y)],
+                egui::Stroke::new(1.0, color.gamma_multiply(0.8)),
+                6.0,
+                4.0,
+            ));
+            painter.text(
+                egui::Pos2::new(rect.right() - 4.0, y - 2.0),
+                egui::Align2::RIGHT_BOTTOM,
+                label,
+                font_id.clone(),
+                *color,
             );
         }
     }
 
     /// 绘制数值标签
-    fn draw_labels(&self, painter: &egui::Painter, rect: egui::Rect, ui: &egui::Ui) {
-        let text_color = ui.visuals().text_color();
+    fn draw_labels(&self, painter: &egui::Painter, rect: egui::Rect, ui: &egui::Ui, range: (f32, f32)) {
         let font_id = egui::FontId::monospace(10.0);
 
         // Y轴标签
-        for i in 0..=4 {
-            let value = self.max_value - (i as f32 / 4.0) * (self.max_value - self.min_value);
-            let y = rect.top() + (i as f32 / 4.0) * rect.height();
-            let text = if value.fract() == 0.0 {
-                format!("{:.0}", value)
-            } else {
-                format!("{:.1}", value)
-            };
-            
-            painter.text(
-                egui::Pos2::new(rect.left() - 5.0, y),
-                egui::Align2::RIGHT_CENTER,
-                text,
-                font_id.clone(),
-                text_color,
-            );
-        }
+        draw_y_labels(painter, rect, ui, range);
 
         // 当前值显示
         if let Some(&last_value) = self.data.back() {
@@ -199,7 +298,158 @@ impl LineChart {
     /// 清空数据
     pub fn clear(&mut self) {
         self.data.clear();
+        self.timestamps.clear();
+    }
+}
+
+/// 第 `index` 个数据点（共 `max_points` 个位置）的X坐标
+fn x_position(rect: egui::Rect, index: usize, max_points: usize) -> f32 {
+    rect.left() + (index as f32 / (max_points - 1).max(1) as f32) * rect.width()
+}
+
+/// 数值在Y轴范围内的Y坐标，超出范围的数值贴边显示
+fn y_position(rect: egui::Rect, value: f32, (min_value, max_value): (f32, f32)) -> f32 {
+    let normalized_value = (value - min_value) / (max_value - min_value);
+    rect.bottom() - normalized_value.clamp(0.0, 1.0) * rect.height()
+}
+
+/// 绘制网格线
+fn draw_grid(painter: &egui::Painter, rect: egui::Rect, ui: &egui::Ui) {
+    let grid_color = ui.visuals().weak_text_color().gamma_multiply(0.3);
+    let stroke = egui::Stroke::new(0.5, grid_color);
+
+    // 水平网格线
+    for i in 1..5 {
+        let y = rect.top() + (i as f32 / 5.0) * rect.height();
+        painter.line_segment(
+            [egui::Pos2::new(rect.left(), y), egui::Pos2::new(rect.right(), y)],
+            stroke,
+        );
+    }
+
+    // 垂直网格线
+    for i in 1..10 {
+        let x = rect.left() + (i as f32 / 10.0) * rect.width();
+        painter.line_segment(
+            [egui::Pos2::new(x, rect.top()), egui::Pos2::new(x, rect.bottom())],
+            stroke,
+        );
+    }
+}
+
+/// 在图表左侧绘制Y轴刻度标签
+fn draw_y_labels(painter: &egui::Painter, rect: egui::Rect, ui: &egui::Ui, (min_value, max_value): (f32, f32)) {
+    let text_color = ui.visuals().text_color();
+    let font_id = egui::FontId::monospace(10.0);
+
+    for i in 0..=4 {
+        let value = max_value - (i as f32 / 4.0) * (max_value - min_value);
+        let y = rect.top() + (i as f32 / 4.0) * rect.height();
+        let text = if value.fract() == 0.0 {
+            format!("{:.0}", value)
+        } else {
+            format!("{:.1}", value)
+        };
+
+        painter.text(
+            egui::Pos2::new(rect.left() - 5.0, y),
+            egui::Align2::RIGHT_CENTER,
+            text,
+            font_id.clone(),
+            text_color,
+        );
+    }
+}
+
+/// 按时间戳将数据拆分为连续段，相邻点间隔超过阈值处断开
+fn split_at_gaps(timestamps: &[Instant], threshold: Duration) -> Vec<Range<usize>> {
+    let mut segments = Vec::new();
+    let mut start = 0;
+
+    for i in 1..timestamps.len() {
+        if timestamps[i].saturating_duration_since(timestamps[i - 1]) > threshold {
+            segments.push(start..i);
+            start = i;
+        }
+    }
+
+    if start < timestamps.len() {
+        segments.push(start..timestamps.len());
+    }
+    segments
+}
+
+/// 图表显示的时间窗口
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TimeWindow {
+    /// 最近1分钟
+    #[default]
+    OneMinute,
+    /// 最近5分钟
+    FiveMinutes,
+    /// 最近15分钟
+    FifteenMinutes,
+    /// 保留的全部历史
+    All,
+}
+
+impl TimeWindow {
+    /// 所有时间窗口，按从短到长排列
+    pub const ALL: [TimeWindow; 4] = [
+        TimeWindow::OneMinute,
+        TimeWindow::FiveMinutes,
+        TimeWindow::FifteenMinutes,
+        TimeWindow::All,
+    ];
+
+    /// 按钮标签
+    pub fn label(&self) -> &'static str {
+        match self {
+            TimeWindow::OneMinute => t("1分钟"),
+            TimeWindow::FiveMinutes => t("5分钟"),
+            TimeWindow::FifteenMinutes => t("15分钟"),
+            TimeWindow::All => t("全部"),
+        }
+    }
+
+    /// 窗口时长，`All` 不限制
+    pub fn duration(&self) -> Option<Duration> {
+        match self {
+            TimeWindow::OneMinute => Some(Duration::from_secs(60)),
+            TimeWindow::FiveMinutes => Some(Duration::from_secs(5 * 60)),
+            TimeWindow::FifteenMinutes => Some(Duration::from_secs(15 * 60)),
+            TimeWindow::All => None,
+        }
+    }
+
+    /// 截取落在窗口内的历史数据（历史按时间升序）
+    pub fn slice<'a, T>(&self, history: &'a [(Instant, T)], now: Instant) -> &'a [(Instant, T)] {
+        match self.duration().and_then(|duration| now.checked_sub(duration)) {
+            Some(cutoff) => {
+                let start = history.partition_point(|(timestamp, _)| *timestamp < cutoff);
+                &history[start..]
+            }
+            None => history,
+        }
+    }
+}
+
+/// 将历史数据降采样到不超过 `max_points` 个点
+///
+/// 连续的若干点合并为一个，取其平均值和最后一个时间戳。
+pub fn downsample(history: &[(Instant, f32)], max_points: usize) -> Vec<(Instant, f32)> {
+    if max_points == 0 || history.len() <= max_points {
+        return history.to_vec();
     }
+
+    let bucket_size = history.len().div_ceil(max_points);
+    history
+        .chunks(bucket_size)
+        .map(|bucket| {
+            let sum: f32 = bucket.iter().map(|(_, value)| value).sum();
+            (bucket[bucket.len() - 1].0, sum / bucket.len() as f32)
+        })
+        .collect()
 }
 
 /// 环形进度图表
@@ -209,6 +459,7 @@ pub struct DonutChart {
     color: egui::Color32,
     background_color: egui::Color32,
     thickness: f32,
+    decimals: Option<usize>,
 }
 
 impl DonutChart {
@@ -220,6 +471,7 @@ impl DonutChart {
             color,
             background_color: egui::Color32::from_gray(50),
             thickness: 8.0,
+            decimals: None,
         }
     }
 
@@ -235,11 +487,33 @@ impl DonutChart {
         self
     }
 
+    /// 设置中心百分比的小数位数，未设置时使用全局格式化配置
+    pub fn with_decimals(mut self, decimals: usize) -> Self {
+        self.decimals = Some(decimals);
+        self
+    }
+
     /// 更新数值
     pub fn set_value(&mut self, value: f32) {
         self.value = value;
     }
 
+    /// 中心百分比文本，按小数位数四舍五入
+    fn center_text(&self) -> String {
+        let mut settings = Formatter::settings();
+        if let Some(decimals) = self.decimals {
+            settings.decimals = decimals;
+        }
+
+        let percentage = if self.max_value > 0.0 {
+            (self.value as f64 / self.max_value as f64 * 100.0).clamp(0.0, 100.0)
+        } else {
+            0.0
+        };
+        let scale = 10f64.powi(settings.decimals as i32);
+        Formatter::percent_with((percentage * scale).round() / scale, &settings)
+    }
+
     /// 渲染环形图表
     pub fn render(&self, ui: &mut egui::Ui, size: egui::Vec2) -> egui::Response {
         let (response, painter) = ui.allocate_painter(size, egui::Sense::hover());
@@ -264,11 +538,10 @@ impl DonutChart {
         }
 
         // 绘制中心文本
-        let percentage = (progress * 100.0) as i32;
         painter.text(
             center,
             egui::Align2::CENTER_CENTER,
-            format!("{}%", percentage),
+            self.center_text(),
             egui::FontId::proportional(16.0),
             ui.visuals().text_color(),
         );
@@ -297,6 +570,140 @@ impl DonutChart {
     }
 }
 
+/// 堆叠面积图的默认系列配色
+const SERIES_PALETTE: [egui::Color32; 8] = [
+    egui::Color32::from_rgb(33, 150, 243),
+    egui::Color32::from_rgb(76, 175, 80),
+    egui::Color32::from_rgb(255, 152, 0),
+    egui::Color32::from_rgb(156, 39, 176),
+    egui::Color32::from_rgb(0, 188, 212),
+    egui::Color32::from_rgb(244, 67, 54),
+    egui::Color32::from_rgb(205, 220, 57),
+    egui::Color32::from_rgb(121, 85, 72),
+];
+
+/// 堆叠面积图
+///
+/// 多个带标签的系列共享X轴，自下而上依次堆叠显示，图表下方绘制图例。
+/// 长度不同的系列按最新数据点右对齐，缺少的较早数据按0计算。
+pub struct StackedAreaChart {
+    series: Vec<(String, egui::Color32, Vec<f32>)>,
+    max_points: usize,
+    max_value: Option<f32>,
+}
+
+impl StackedAreaChart {
+    /// 创建新的堆叠面积图
+    pub fn new(max_points: usize) -> Self {
+        Self {
+            series: Vec::new(),
+            max_points,
+            max_value: None,
+        }
+    }
+
+    /// 设置固定的Y轴上限，未设置时按堆叠总和的最大值确定
+    pub fn with_max_value(mut self, max_value: f32) -> Self {
+        self.max_value = Some(max_value);
+        self
+    }
+
+    /// 添加一个系列，保留最新的数据点，未指定颜色时按顺序从默认配色中选取
+    pub fn add_series(&mut self, label: impl Into<String>, color: Option<egui::Color32>, values: &[f32]) {
+        let color = color.unwrap_or(SERIES_PALETTE[self.series.len() % SERIES_PALETTE.len()]);
+        let skip = values.len().saturating_sub(self.max_points);
+        self.series.push((label.into(), color, values[skip..].to_vec()));
+    }
+
+    /// 各系列堆叠后的上边界，第 `k` 项为前 `k + 1` 个系列的累计值，负值按0计算
+    pub fn stacked_points(&self) -> Vec<Vec<f32>> {
+        let len = self.series.iter().map(|(_, _, values)| values.len()).max().unwrap_or(0);
+        let mut totals = vec![0.0; len];
+        self.series
+            .iter()
+            .map(|(_, _, values)| {
+                let offset = len - values.len();
+                for (total, value) in totals[offset..].iter_mut().zip(values) {
+                    *total += value.max(0.0);
+                }
+                totals.clone()
+            })
+            .collect()
+    }
+
+    /// 当前使用的Y轴范围
+    pub fn value_range(&self, stacked: &[Vec<f32>]) -> (f32, f32) {
+        let max_value = self.max_value.unwrap_or_else(|| {
+            let top = stacked.last().map(|totals| totals.iter().copied().fold(0.0, f32::max)).unwrap_or(0.0);
+            if top > 0.0 { top * 1.1 } else { 1.0 }
+        });
+        (0.0, max_value)
+    }
+
+    /// 渲染图表和图例
+    pub fn render(&self, ui: &mut egui::Ui, size: egui::Vec2) -> egui::Response {
+        let (response, painter) = ui.allocate_painter(size, egui::Sense::hover());
+        let rect = response.rect;
+        let stacked = self.stacked_points();
+        let len = stacked.first().map(Vec::len).unwrap_or(0);
+
+        if len == 0 {
+            painter.rect_stroke(rect, 0.0, egui::Stroke::new(1.0, ui.visuals().weak_text_color()), StrokeKind::Middle);
+            painter.text(
+                rect.center(),
+                egui::Align2::CENTER_CENTER,
+                t("无数据"),
+                egui::FontId::default(),
+                ui.visuals().weak_text_color(),
+            );
+            return response;
+        }
+
+        painter.rect_filled(rect, 2.0, ui.visuals().extreme_bg_color);
+        painter.rect_stroke(rect, 2.0, egui::Stroke::new(1.0, ui.visuals().weak_text_color()), StrokeKind::Middle);
+
+        // 数据点靠右对齐，最新的数据位于右边缘
+        let range = self.value_range(&stacked);
+        let offset = self.max_points.saturating_sub(len);
+        let x = |i: usize| x_position(rect, offset + i, self.max_points);
+        let baseline = vec![0.0; len];
+        let mut lower = &baseline;
+        for ((_, color, _), upper) in self.series.iter().zip(&stacked) {
+            // 逐段绘制梯形，保证每块填充区域都是凸多边形
+            for i in 1..len {
+                painter.add(egui::Shape::convex_polygon(
+                    vec![
+                        egui::Pos2::new(x(i - 1), y_position(rect, lower[i - 1], range)),
+                        egui::Pos2::new(x(i - 1), y_position(rect, upper[i - 1], range)),
+                        egui::Pos2::new(x(i), y_position(rect, upper[i], range)),
+                        egui::Pos2::new(x(i), y_position(rect, lower[i], range)),
+                    ],
+                    color.gamma_multiply(0.6),
+                    egui::Stroke::NONE,
+                ));
+            }
+            let line: Vec<egui::Pos2> = (0..len).map(|i| egui::Pos2::new(x(i), y_position(rect, upper[i], range))).collect();
+            painter.add(egui::Shape::line(line, egui::Stroke::new(1.5, *color)));
+            lower = upper;
+        }
+
+        draw_grid(&painter, rect, ui);
+        draw_y_labels(&painter, rect, ui, range);
+
+        // 图例
+        ui.horizontal_wrapped(|ui| {
+            for (label, color, values) in &self.series {
+                let (swatch, _) = ui.allocate_exact_size(egui::vec2(10.0, 10.0), egui::Sense::hover());
+                ui.painter().rect_filled(swatch, 2.0, *color);
+                let latest = values.last().copied().unwrap_or_default();
+                ui.small(format!("{} {:.1}", label, latest));
+            }
+        });
+
+        response
+    }
+}
+
 /// 简单的柱状图
 pub struct BarChart {
     data: Vec<(String, f32)>,
@@ -324,7 +731,7 @@ impl BarChart {
             painter.text(
                 rect.center(),
                 egui::Align2::CENTER_CENTER,
-                "无数据",
+                t("无数据"),
                 egui::FontId::default(),
                 ui.visuals().weak_text_color(),
             );
@@ -378,6 +785,34 @@ mod tests {
         let chart = LineChart::new(100, egui::Color32::BLUE);
         assert_eq!(chart.max_points, 100);
         assert_eq!(chart.data.len(), 0);
+        assert_eq!(chart.line_width, 2.0);
+        assert!(chart.show_points);
+    }
+
+    #[test]
+    fn test_line_chart_style() {
+        let chart = LineChart::new(100, egui::Color32::BLUE)
+            .with_line_width(1.0)
+            .with_points(false);
+        assert_eq!(chart.line_width, 1.0);
+        assert!(!chart.show_points);
+    }
+
+    #[test]
+    fn test_line_chart_autoscale_range() {
+        let mut chart = LineChart::new(10, egui::Color32::BLUE);
+        chart.set_data(vec![1.0, 2.0, 3.0]);
+        assert_eq!(chart.value_range(), (0.0, 100.0));
+
+        let mut chart = chart.with_autoscale(true);
+        chart.set_data(vec![1.0, 2.0, 3.0]);
+        let (min, max) = chart.value_range();
+        assert!((min - 0.8).abs() < 1e-5);
+        assert!((max - 3.2).abs() < 1e-5);
+
+        // 非负数据的下限不低于0，平坦数据也保留可见范围
+        chart.set_data(vec![0.0, 0.0]);
+        assert_eq!(chart.value_range(), (0.0, 1.0));
     }
 
     #[test]
@@ -393,10 +828,114 @@ mod tests {
         assert_eq!(chart.data[2], 40.0);
     }
 
+    #[test]
+    fn test_line_chart_gap_detection() {
+        let base = Instant::now();
+        let history: Vec<(Instant, f32)> = [0, 1, 2, 10, 11]
+            .iter()
+            .map(|&secs| (base + Duration::from_secs(secs), secs as f32))
+            .collect();
+
+        let mut chart = LineChart::new(10, egui::Color32::BLUE)
+            .with_gap_detection(Duration::from_secs(1));
+        chart.set_timed_data(&history);
+        assert_eq!(chart.segments(), vec![0..3, 3..5]);
+
+        // 未启用断档检测时整体连线
+        let mut chart = LineChart::new(10, egui::Color32::BLUE);
+        chart.set_timed_data(&history);
+        assert_eq!(chart.segments(), vec![0..5]);
+    }
+
+    #[test]
+    fn test_split_at_gaps_within_threshold() {
+        let base = Instant::now();
+        let timestamps: Vec<Instant> = (0..4)
+            .map(|i| base + Duration::from_millis(i * 1500))
+            .collect();
+        assert_eq!(split_at_gaps(&timestamps, Duration::from_secs(2)), vec![0..4]);
+        assert!(split_at_gaps(&[], Duration::from_secs(2)).is_empty());
+    }
+
+    #[test]
+    fn test_line_chart_threshold_lines() {
+        let chart = LineChart::new(10, egui::Color32::BLUE).with_threshold_lines(vec![
+            (80.0, egui::Color32::YELLOW, "警告 80%".to_string()),
+            (95.0, egui::Color32::RED, "严重 95%".to_string()),
+        ]);
+        assert_eq!(chart.threshold_lines.len(), 2);
+        assert_eq!(chart.threshold_lines[1].0, 95.0);
+    }
+
+    #[test]
+    fn test_time_window_slice() {
+        let now = Instant::now() + Duration::from_secs(3600);
+        let history: Vec<(Instant, f32)> = [600, 240, 30, 0]
+            .iter()
+            .map(|&age| (now - Duration::from_secs(age), age as f32))
+            .collect();
+
+        assert_eq!(TimeWindow::OneMinute.slice(&history, now).len(), 2);
+        assert_eq!(TimeWindow::FiveMinutes.slice(&history, now).len(), 3);
+        assert_eq!(TimeWindow::All.slice(&history, now).len(), 4);
+    }
+
+    #[test]
+    fn test_downsample() {
+        let base = Instant::now();
+        let history: Vec<(Instant, f32)> = (0..10)
+            .map(|i| (base + Duration::from_secs(i), i as f32))
+            .collect();
+
+        let sampled = downsample(&history, 5);
+        assert_eq!(sampled.len(), 5);
+        assert_eq!(sampled[0], (history[1].0, 0.5));
+        assert_eq!(sampled[4], (history[9].0, 8.5));
+
+        // 点数不超过上限时原样返回
+        assert_eq!(downsample(&history, 20), history);
+    }
+
+    #[test]
+    fn test_stacked_area_points() {
+        let mut chart = StackedAreaChart::new(3);
+        chart.add_series("a", None, &[1.0, 2.0, 3.0]);
+        // 较短的系列右对齐，负值按0计算
+        chart.add_series("b", None, &[-5.0, 10.0]);
+        // 超出点数的早期数据被丢弃
+        chart.add_series("c", Some(egui::Color32::RED), &[100.0, 1.0, 1.0, 1.0]);
+
+        let stacked = chart.stacked_points();
+        assert_eq!(stacked, vec![
+            vec![1.0, 2.0, 3.0],
+            vec![1.0, 2.0, 13.0],
+            vec![2.0, 3.0, 14.0],
+        ]);
+        assert_eq!(chart.series[0].1, SERIES_PALETTE[0]);
+        assert_eq!(chart.series[2].1, egui::Color32::RED);
+
+        let (min, max) = chart.value_range(&stacked);
+        assert_eq!(min, 0.0);
+        assert!((max - 15.4).abs() < 1e-4);
+        assert_eq!(StackedAreaChart::new(3).with_max_value(100.0).value_range(&[]), (0.0, 100.0));
+    }
+
     #[test]
     fn test_donut_chart_creation() {
         let chart = DonutChart::new(75.0, 100.0, egui::Color32::GREEN);
         assert_eq!(chart.value, 75.0);
         assert_eq!(chart.max_value, 100.0);
     }
+
+    #[test]
+    fn test_donut_chart_center_text_rounds() {
+        let chart = |value: f32, decimals: usize| {
+            DonutChart::new(value, 100.0, egui::Color32::GREEN).with_decimals(decimals).center_text()
+        };
+        assert_eq!(chart(49.9, 0), "50%");
+        assert_eq!(chart(50.5, 0), "51%");
+        assert_eq!(chart(49.9, 1), "49.9%");
+        assert_eq!(chart(50.5, 1), "50.5%");
+        assert_eq!(chart(150.0, 0), "100%");
+    }
 }
\ No newline at end of file
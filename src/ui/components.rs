@@ -2,23 +2,42 @@
 //! 
 //! 提供可重用的UI组件。
 
-use crate::ui::{UiUtils, TabRenderer};
-use crate::system::SystemSnapshot;
+use crate::app::AppMessage;
+use crate::system::connections::{self, ConnectionFilter, ConnectionInfo};
+use crate::system::process::{ProcessSortKey, ProcessTarget, ProcessView};
+use crate::system::metrics::PROCESS_HISTORY_SAMPLES;
+use crate::system::{ProcessInfo, SystemSnapshot};
+use crate::system::{NetworkInfo, TimeFormatter};
+use crate::ui::charts::{StackedAreaChart, TimeWindow};
+use crate::ui::{UiUtils, TabRenderer, RenderContext};
+use crate::utils::{t, StringUtils};
 use eframe::egui;
+use std::collections::{HashSet, VecDeque};
+use std::time::Instant;
 
 /// 内存标签页渲染器
-pub struct MemoryTabRenderer;
+pub struct MemoryTabRenderer {
+    /// 历史图表的时间窗口
+    time_window: TimeWindow,
+}
 
 impl MemoryTabRenderer {
     pub fn new() -> Self {
-        Self
+        Self { time_window: TimeWindow::default() }
     }
 }
 
 impl TabRenderer for MemoryTabRenderer {
-    fn render(&mut self, ui: &mut egui::Ui, system_data: Option<&SystemSnapshot>) {
-        if let Some(data) = system_data {
-            ui.heading("内存信息");
+    fn render(&mut self, ui: &mut egui::Ui, context: &RenderContext<'_>) {
+        let raw_bytes = context.config.ui.raw_bytes_display;
+        if context.system_data.is_some_and(|data| !data.memory_collected) {
+            ui.centered_and_justified(|ui| {
+                ui.label(t("内存监控未启用"));
+            });
+            return;
+        }
+        if let Some(data) = context.system_data {
+            ui.heading(t("内存信息"));
             ui.separator();
             
             // 内存使用概览
@@ -26,106 +45,243 @@ impl TabRenderer for MemoryTabRenderer {
                 ui, 
                 data.memory.usage_percent as f32, 
                 100.0, 
-                "内存使用率"
+                t("内存使用率")
             );
+
+            if data.memory.swap_total > 0 {
+                UiUtils::progress_bar(ui, data.memory.swap_usage_percent as f32, 100.0, t("交换空间"))
+                    .on_hover_text(format!(
+                        "{} / {}",
+                        UiUtils::format_bytes(data.memory.swap_used),
+                        UiUtils::format_bytes(data.memory.swap_total)
+                    ));
+            } else {
+                ui.weak(t("未启用交换空间"));
+            }
             
+            ui.separator();
+
+            // 已用/可回收/空闲分解，仅在有可回收数据时显示
+            if context.config.ui.show_memory_breakdown && data.memory.reclaimable > 0 {
+                let free = data.memory.total
+                    .saturating_sub(data.memory.used)
+                    .saturating_sub(data.memory.reclaimable);
+                UiUtils::stacked_bar(
+                    ui,
+                    &[
+                        (data.memory.used, context.colors.error, t("已使用")),
+                        (data.memory.reclaimable, context.colors.warning, t("可回收缓存")),
+                        (free, context.colors.success, t("空闲")),
+                    ],
+                    data.memory.total,
+                ).on_hover_text(t("可回收缓存可在需要时释放给应用程序"));
+                ui.separator();
+            }
+
+            // 内存使用率历史
+            UiUtils::history_chart(
+                ui,
+                &context.metrics.get_memory_history_timed(),
+                &mut self.time_window,
+                UiUtils::color_from_rgb(context.config.ui.chart_colors_for(ui.visuals().dark_mode).memory_color),
+                context.config.ui.memory_chart_autoscale,
+                context.config,
+            );
+
             ui.separator();
             
             // 内存详细信息
             ui.columns(2, |columns| {
-                columns[0].heading("内存统计");
-                UiUtils::metric_display(
+                columns[0].heading(t("内存统计"));
+                UiUtils::bytes_metric_display(
                     &mut columns[0], 
-                    "总内存", 
-                    &UiUtils::format_bytes(data.memory.total),
-                    None
+                    t("总内存"), 
+                    data.memory.total,
+                    None,
+                    raw_bytes
                 );
-                UiUtils::metric_display(
+                UiUtils::bytes_metric_display(
                     &mut columns[0], 
-                    "已使用", 
-                    &UiUtils::format_bytes(data.memory.used),
-                    Some(UiUtils::get_usage_color(data.memory.usage_percent))
+                    t("已使用"), 
+                    data.memory.used,
+                    Some(UiUtils::get_usage_color(data.memory.usage_percent)),
+                    raw_bytes
                 );
-                UiUtils::metric_display(
+                UiUtils::bytes_metric_display(
                     &mut columns[0], 
-                    "可用", 
-                    &UiUtils::format_bytes(data.memory.available),
-                    None
+                    t("可用"), 
+                    data.memory.available,
+                    None,
+                    raw_bytes
                 );
-                UiUtils::metric_display(
+                UiUtils::bytes_metric_display(
                     &mut columns[0], 
-                    "空闲", 
-                    &UiUtils::format_bytes(data.memory.free),
-                    None
+                    t("空闲"), 
+                    data.memory.free,
+                    None,
+                    raw_bytes
                 );
                 
-                columns[1].heading("使用率分析");
-                columns[1].label(format!("使用率: {:.1}%", data.memory.usage_percent));
+                columns[1].heading(t("使用率分析"));
+                columns[1].label(format!("{}: {:.1}%", t("使用率"), data.memory.usage_percent));
                 
                 let status = if data.memory.usage_percent < 50.0 {
-                    ("正常", egui::Color32::GREEN)
+                    (t("正常"), context.colors.success)
                 } else if data.memory.usage_percent < 80.0 {
-                    ("注意", egui::Color32::YELLOW)
+                    (t("注意"), context.colors.warning)
                 } else {
-                    ("警告", egui::Color32::RED)
+                    (t("警告"), context.colors.error)
                 };
                 
                 UiUtils::status_indicator(&mut columns[1], status.0, status.1);
             });
         } else {
             ui.centered_and_justified(|ui| {
-                ui.label("正在加载内存数据...");
+                ui.label(t("正在加载内存数据..."));
             });
         }
     }
     
     fn title(&self) -> &str {
-        "内存"
+        t("内存")
+    }
+
+    fn to_clipboard_text(&self, context: &RenderContext<'_>) -> Option<String> {
+        let memory = &context.system_data.filter(|data| data.memory_collected)?.memory;
+        Some(format!(
+            "{}: {}\n{}: {}\n{}: {}\n{}: {}\n{}: {}\n",
+            t("内存使用率"),
+            UiUtils::format_percentage(memory.usage_percent),
+            t("总内存"),
+            UiUtils::format_bytes(memory.total),
+            t("已使用"),
+            UiUtils::format_bytes(memory.used),
+            t("可用"),
+            UiUtils::format_bytes(memory.available),
+            t("空闲"),
+            UiUtils::format_bytes(memory.free),
+        ))
     }
 }
 
+/// 磁盘已用空间历史保留的数据点数
+const DISK_USAGE_POINTS: usize = 120;
+
 /// 磁盘标签页渲染器
-pub struct DiskTabRenderer;
+pub struct DiskTabRenderer {
+    /// 最近一次记录已用空间的快照时间，避免同一快照重复记录
+    last_timestamp: Option<chrono::DateTime<chrono::Utc>>,
+    /// 每次采样各挂载点的已用空间（GiB）
+    usage_history: VecDeque<Vec<(String, f32)>>,
+}
 
 impl DiskTabRenderer {
     pub fn new() -> Self {
-        Self
+        Self {
+            last_timestamp: None,
+            usage_history: VecDeque::with_capacity(DISK_USAGE_POINTS),
+        }
+    }
+
+    /// 渲染各卷已用空间的堆叠面积图，总高度即所有卷的已用空间合计
+    fn render_usage_chart(&mut self, ui: &mut egui::Ui, disks: &[crate::system::DiskInfo], timestamp: chrono::DateTime<chrono::Utc>) {
+        const GIB: f64 = 1024.0 * 1024.0 * 1024.0;
+
+        if self.last_timestamp != Some(timestamp) {
+            self.last_timestamp = Some(timestamp);
+            if self.usage_history.len() == DISK_USAGE_POINTS {
+                self.usage_history.pop_front();
+            }
+            self.usage_history.push_back(
+                disks.iter().map(|disk| (disk.mount_point.clone(), (disk.used_space as f64 / GIB) as f32)).collect(),
+            );
+        }
+
+        // 以当前的卷为系列，历史中不存在的卷按0计算
+        let mut chart = StackedAreaChart::new(DISK_USAGE_POINTS);
+        for disk in disks {
+            let values: Vec<f32> = self.usage_history.iter()
+                .map(|sample| {
+                    sample.iter()
+                        .find(|(mount_point, _)| *mount_point == disk.mount_point)
+                        .map(|(_, used)| *used)
+                        .unwrap_or_default()
+                })
+                .collect();
+            chart.add_series(&disk.mount_point, None, &values);
+        }
+
+        ui.label(t("已用空间 (GiB)"));
+        chart.render(ui, egui::vec2(ui.available_width(), 120.0));
     }
 }
 
 impl TabRenderer for DiskTabRenderer {
-    fn render(&mut self, ui: &mut egui::Ui, system_data: Option<&SystemSnapshot>) {
-        if let Some(data) = system_data {
-            ui.heading("磁盘信息");
+    fn render(&mut self, ui: &mut egui::Ui, context: &RenderContext<'_>) {
+        let raw_bytes = context.config.ui.raw_bytes_display;
+        if let Some(data) = context.system_data {
+            ui.horizontal(|ui| {
+                ui.heading(t("磁盘信息"));
+                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                    let mut physical_only = context.config.monitoring.disk_filter.physical_only;
+                    if ui.checkbox(&mut physical_only, t("仅显示物理磁盘"))
+                        .on_hover_text(t("隐藏回环设备、网络文件系统和可移动磁盘，下次采集时生效"))
+                        .changed()
+                    {
+                        let mut config = context.config.clone();
+                        config.monitoring.disk_filter.physical_only = physical_only;
+                        let _ = context.sender.send(AppMessage::ApplyConfig(config));
+                    }
+                });
+            });
             ui.separator();
             
             if data.disks.is_empty() {
                 ui.centered_and_justified(|ui| {
-                    ui.label("未检测到磁盘");
+                    ui.label(t("未检测到磁盘"));
                 });
                 return;
             }
+
+            self.render_usage_chart(ui, &data.disks, data.timestamp);
+            ui.add_space(8.0);
             
             // 磁盘列表
             for disk in &data.disks {
                 UiUtils::info_card(ui, &disk.name, |ui| {
                     ui.horizontal(|ui| {
                         ui.vertical(|ui| {
-                            UiUtils::metric_display(ui, "挂载点", &disk.mount_point, None);
-                            UiUtils::metric_display(ui, "文件系统", &disk.file_system, None);
-                            UiUtils::metric_display(ui, "总容量", &UiUtils::format_bytes(disk.total_space), None);
-                            UiUtils::metric_display(ui, "已使用", &UiUtils::format_bytes(disk.used_space), None);
-                            UiUtils::metric_display(ui, "可用空间", &UiUtils::format_bytes(disk.available_space), None);
+                            UiUtils::metric_display(ui, t("挂载点"), &disk.mount_point, None);
+                            UiUtils::metric_display(ui, t("文件系统"), &disk.file_system, None);
+                            UiUtils::bytes_metric_display(ui, t("总容量"), disk.total_space, None, raw_bytes);
+                            UiUtils::bytes_metric_display(ui, t("已使用"), disk.used_space, None, raw_bytes);
+                            UiUtils::bytes_metric_display(ui, t("可用空间"), disk.available_space, None, raw_bytes);
                         });
                         
                         ui.separator();
                         
                         ui.vertical(|ui| {
-                            UiUtils::progress_bar(
-                                ui, 
-                                disk.usage_percent as f32, 
-                                100.0, 
-                                "使用率"
+                            if context.config.ui.disk_show_free_space {
+                                UiUtils::free_space_bar(ui, 100.0 - disk.usage_percent, t("剩余"));
+                            } else {
+                                UiUtils::progress_bar(
+                                    ui, 
+                                    disk.usage_percent as f32, 
+                                    100.0, 
+                                    t("使用率")
+                                );
+                            }
+                            UiUtils::metric_display(
+                                ui,
+                                t("读取"),
+                                &format!("{}/s", UiUtils::format_bytes(disk.read_bytes_per_sec as u64)),
+                                None,
+                            );
+                            UiUtils::metric_display(
+                                ui,
+                                t("写入"),
+                                &format!("{}/s", UiUtils::format_bytes(disk.write_bytes_per_sec as u64)),
+                                None,
                             );
                         });
                     });
@@ -135,126 +291,684 @@ impl TabRenderer for DiskTabRenderer {
             }
         } else {
             ui.centered_and_justified(|ui| {
-                ui.label("正在加载磁盘数据...");
+                ui.label(t("正在加载磁盘数据..."));
             });
         }
     }
     
     fn title(&self) -> &str {
-        "磁盘"
+        t("磁盘")
+    }
+
+    fn to_clipboard_text(&self, context: &RenderContext<'_>) -> Option<String> {
+        let data = context.system_data?;
+        Some(data.disks.iter()
+            .map(|disk| format!(
+                "{} ({}, {}): {} / {} ({})\n",
+                disk.mount_point,
+                disk.name,
+                disk.file_system,
+                UiUtils::format_bytes(disk.used_space),
+                UiUtils::format_bytes(disk.total_space),
+                UiUtils::format_percentage(disk.usage_percent)
+            ))
+            .collect())
+    }
+}
+
+/// GPU标签页渲染器
+#[derive(Default)]
+pub struct GpuTabRenderer;
+
+impl GpuTabRenderer {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl TabRenderer for GpuTabRenderer {
+    fn render(&mut self, ui: &mut egui::Ui, context: &RenderContext<'_>) {
+        let raw_bytes = context.config.ui.raw_bytes_display;
+        ui.heading(t("GPU信息"));
+        ui.separator();
+
+        let Some(gpus) = context.system_data.and_then(|data| data.gpus.as_deref()) else {
+            ui.centered_and_justified(|ui| {
+                if context.config.monitoring.enable_gpu_monitoring {
+                    ui.label(t("正在加载GPU数据..."));
+                } else {
+                    ui.label(t("GPU监控未启用"));
+                }
+            });
+            return;
+        };
+        if gpus.is_empty() {
+            ui.weak(t("未检测到支持的GPU（目前仅支持安装了驱动的NVIDIA显卡）"));
+            return;
+        }
+
+        egui::ScrollArea::vertical().show(ui, |ui| {
+            for gpu in gpus {
+                ui.group(|ui| {
+                    ui.strong(&gpu.name);
+                    UiUtils::progress_bar(ui, gpu.usage_percent, 100.0, t("GPU使用率"));
+                    UiUtils::progress_bar(ui, gpu.memory_usage_percent() as f32, 100.0, t("显存使用率"));
+
+                    ui.columns(2, |columns| {
+                        UiUtils::bytes_metric_display(&mut columns[0], t("总显存"), gpu.memory_total, None, raw_bytes);
+                        UiUtils::bytes_metric_display(
+                            &mut columns[0],
+                            t("已用显存"),
+                            gpu.memory_used,
+                            Some(UiUtils::get_usage_color(gpu.memory_usage_percent())),
+                            raw_bytes,
+                        );
+
+                        let temperature = gpu.temperature_celsius
+                            .map(|celsius| format!("{:.0}°C", celsius))
+                            .unwrap_or_else(|| t("未知").to_string());
+                        UiUtils::metric_display(&mut columns[1], t("温度"), &temperature, None);
+                        UiUtils::metric_display(&mut columns[1], t("驱动版本"), &gpu.driver_version, None);
+                    });
+                });
+            }
+        });
+    }
+
+    fn title(&self) -> &str {
+        "GPU"
+    }
+
+    fn to_clipboard_text(&self, context: &RenderContext<'_>) -> Option<String> {
+        let gpus = context.system_data?.gpus.as_deref()?;
+        Some(gpus.iter()
+            .map(|gpu| format!(
+                "{}: {} {}, {} {} / {} ({})\n",
+                gpu.name,
+                t("使用率"),
+                UiUtils::format_percentage(gpu.usage_percent as f64),
+                t("显存"),
+                UiUtils::format_bytes(gpu.memory_used),
+                UiUtils::format_bytes(gpu.memory_total),
+                UiUtils::format_percentage(gpu.memory_usage_percent())
+            ))
+            .collect())
     }
 }
 
+/// 进程表格中命令行单元格显示的最大字符数
+const COMMAND_LINE_CELL_CHARS: usize = 60;
+
 /// 进程标签页渲染器
-pub struct ProcessTabRenderer;
+pub struct ProcessTabRenderer {
+    /// 选中的进程（按PID记录，重新排序后保持不变）
+    selected: HashSet<u32>,
+    /// 等待确认结束的进程（批量结束时为选中的进程，右键结束时为单个进程）
+    confirming_kill: Option<HashSet<u32>>,
+    /// 点击名称查看历史曲线的进程
+    focused: Option<u32>,
+    /// 表格的过滤与排序
+    view: ProcessView,
+    /// 缓存的可见进程顺序（快照中进程列表的下标）
+    order: Vec<usize>,
+    /// 缓存顺序对应的快照时间和视图，两者之一变化时重新排序
+    order_key: Option<(chrono::DateTime<chrono::Utc>, ProcessView)>,
+}
 
 impl ProcessTabRenderer {
     pub fn new() -> Self {
-        Self
+        Self {
+            selected: HashSet::new(),
+            confirming_kill: None,
+            focused: None,
+            view: ProcessView::default(),
+            order: Vec::new(),
+            order_key: None,
+        }
+    }
+
+    /// 快照或过滤排序条件变化时重新计算可见进程的顺序
+    fn refresh_order(&mut self, snapshot: &SystemSnapshot) {
+        if self.is_order_current(snapshot) {
+            return;
+        }
+        let processes = snapshot.processes.as_deref().unwrap_or_default();
+        self.order = self.view.sorted_indices(processes);
+        self.order_key = Some((snapshot.timestamp, self.view.clone()));
+    }
+
+    /// 缓存的顺序是否对应该快照和当前视图
+    fn is_order_current(&self, snapshot: &SystemSnapshot) -> bool {
+        self.order_key.as_ref()
+            .is_some_and(|(timestamp, view)| *timestamp == snapshot.timestamp && *view == self.view)
+    }
+
+    /// 按表格顺序返回快照中可见的进程，缓存过期时临时排序
+    fn visible<'a>(&self, snapshot: &'a SystemSnapshot) -> Vec<&'a ProcessInfo> {
+        let processes = snapshot.processes.as_deref().unwrap_or_default();
+        if self.is_order_current(snapshot) {
+            self.order.iter().filter_map(|&index| processes.get(index)).collect()
+        } else {
+            self.view.apply(processes)
+        }
+    }
+
+    /// 渲染当前查看进程的CPU使用率历史曲线
+    fn render_focused_history(&mut self, ui: &mut egui::Ui, processes: &[ProcessInfo], context: &RenderContext<'_>) {
+        let Some(pid) = self.focused else {
+            return;
+        };
+        let Some(process) = processes.iter().find(|process| process.pid == pid) else {
+            self.focused = None;
+            return;
+        };
+
+        let history = context.metrics.process_history().cpu_history(pid);
+        ui.horizontal(|ui| {
+            ui.strong(format!("{} ({})", process.name, process.pid));
+            ui.label(format!(
+                "CPU {}  {} {}",
+                UiUtils::format_percentage(process.cpu_usage as f64),
+                t("内存"),
+                UiUtils::format_bytes(process.memory_usage)
+            ));
+            if ui.small_button("✖").on_hover_text(t("关闭历史曲线")).clicked() {
+                self.focused = None;
+            }
+        });
+        if let Some(exe_path) = &process.exe_path {
+            UiUtils::metric_display(ui, t("路径"), exe_path, None);
+        }
+        if !process.cmd.is_empty() {
+            ui.horizontal_wrapped(|ui| {
+                ui.label(t("命令行:"));
+                ui.monospace(process.command_line());
+            });
+        }
+        let colors = context.config.ui.chart_colors_for(ui.visuals().dark_mode);
+        let mut chart = UiUtils::line_chart(PROCESS_HISTORY_SAMPLES, UiUtils::color_from_rgb(colors.cpu_color), &context.config.ui)
+            .with_autoscale(true);
+        chart.set_timed_data(&history);
+        chart.render(ui, egui::vec2(ui.available_width(), 60.0));
+        ui.separator();
+    }
+
+    /// 渲染结束进程的确认窗口，列出将被结束的进程
+    fn render_kill_confirmation(&mut self, ui: &mut egui::Ui, processes: &[ProcessInfo], context: &RenderContext<'_>) {
+        let Some(pending) = self.confirming_kill.as_ref() else {
+            return;
+        };
+        // 确认期间已退出的进程不再列出
+        let targets: Vec<&ProcessInfo> = processes.iter()
+            .filter(|process| pending.contains(&process.pid))
+            .collect();
+        if targets.is_empty() {
+            self.confirming_kill = None;
+            return;
+        }
+
+        egui::Window::new(t("确认结束进程"))
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, egui::Vec2::ZERO)
+            .show(ui.ctx(), |ui| {
+                ui.label(format!("{}: {}", t("将结束的进程数"), targets.len()));
+                egui::ScrollArea::vertical().max_height(200.0).show(ui, |ui| {
+                    for process in &targets {
+                        ui.label(format!("{}  {}", process.pid, process.name));
+                    }
+                });
+                ui.separator();
+                ui.horizontal(|ui| {
+                    if ui.button(t("结束")).clicked() {
+                        let targets = targets.iter().map(|process| ProcessTarget::of(process)).collect();
+                        let _ = context.sender.send(AppMessage::KillProcesses(targets));
+                        if let Some(pending) = self.confirming_kill.take() {
+                            self.selected.retain(|pid| !pending.contains(pid));
+                        }
+                    }
+                    if ui.button(t("取消")).clicked() {
+                        self.confirming_kill = None;
+                    }
+                });
+            });
+    }
+
+    /// 渲染可点击排序的表头，再次点击当前排序列时切换升降序
+    fn sort_header(ui: &mut egui::Ui, view: &mut ProcessView, key: ProcessSortKey) {
+        let selected = view.sort_key == key;
+        let text = match (selected, view.descending) {
+            (true, true) => format!("{} ▼", key.label()),
+            (true, false) => format!("{} ▲", key.label()),
+            (false, _) => key.label().to_string(),
+        };
+
+        if ui.selectable_label(selected, text).clicked() {
+            if selected {
+                view.descending = !view.descending;
+            } else {
+                view.sort_key = key;
+                view.descending = true;
+            }
+        }
     }
 }
 
 impl TabRenderer for ProcessTabRenderer {
-    fn render(&mut self, ui: &mut egui::Ui, _system_data: Option<&SystemSnapshot>) {
-        ui.heading("进程信息");
+    fn render(&mut self, ui: &mut egui::Ui, context: &RenderContext<'_>) {
+        ui.heading(t("进程信息"));
         ui.separator();
-        
-        // 暂时显示占位内容
-        ui.label("进程监控功能开发中...");
-        ui.label("将显示:");
-        ui.label("• 进程列表");
-        ui.label("• CPU和内存使用情况");
-        ui.label("• 进程状态");
-        ui.label("• 进程管理操作");
+
+        let Some(snapshot) = context.system_data.filter(|data| data.processes.is_some()) else {
+            ui.centered_and_justified(|ui| {
+                ui.label(t("正在加载进程数据..."));
+            });
+            return;
+        };
+        let processes = snapshot.processes.as_deref().unwrap_or_default();
+
+        // 已退出的进程不再保留选中状态
+        let multi_select = context.config.ui.process_multi_select;
+        if multi_select {
+            let alive: HashSet<u32> = processes.iter().map(|process| process.pid).collect();
+            self.selected.retain(|pid| alive.contains(pid));
+        } else {
+            self.selected.clear();
+        }
+
+        self.refresh_order(snapshot);
+        let visible = self.visible(snapshot);
+        ui.horizontal(|ui| {
+            ui.label(t("过滤:"));
+            ui.text_edit_singleline(&mut self.view.filter);
+            // 按输入框中的过滤条件计数，输入时即时更新
+            let matched = processes.iter().filter(|process| self.view.matches(process)).count();
+            ui.label(format!("{}: {} / {}", t("显示的进程"), matched, processes.len()));
+
+            if multi_select {
+                ui.separator();
+                if ui.button(t("全选")).clicked() {
+                    self.selected.extend(visible.iter().map(|process| process.pid));
+                }
+                if ui.add_enabled(!self.selected.is_empty(), egui::Button::new(t("清除选择"))).clicked() {
+                    self.selected.clear();
+                }
+                let kill_label = format!("{} ({})", t("结束选中"), self.selected.len());
+                if ui.add_enabled(!self.selected.is_empty(), egui::Button::new(kill_label)).clicked() {
+                    self.confirming_kill = Some(self.selected.clone());
+                }
+            }
+        });
+        ui.separator();
+
+        self.render_focused_history(ui, processes, context);
+
+        egui::ScrollArea::vertical().show(ui, |ui| {
+            egui::Grid::new("process_table")
+                .striped(true)
+                .num_columns(if multi_select { 9 } else { 8 })
+                .show(ui, |ui| {
+                    if multi_select {
+                        ui.label("");
+                    }
+                    for key in [
+                        ProcessSortKey::Pid,
+                        ProcessSortKey::Name,
+                        ProcessSortKey::Cpu,
+                        ProcessSortKey::Memory,
+                    ] {
+                        Self::sort_header(ui, &mut self.view, key);
+                    }
+                    ui.strong(t("用户"));
+                    ui.strong(t("状态"));
+                    Self::sort_header(ui, &mut self.view, ProcessSortKey::Age);
+                    ui.strong(t("命令行"));
+                    ui.end_row();
+
+                    for process in &visible {
+                        if multi_select {
+                            let mut selected = self.selected.contains(&process.pid);
+                            if ui.checkbox(&mut selected, "").changed() {
+                                if selected {
+                                    self.selected.insert(process.pid);
+                                } else {
+                                    self.selected.remove(&process.pid);
+                                }
+                            }
+                        }
+                        ui.label(process.pid.to_string());
+                        let focused = self.focused == Some(process.pid);
+                        let name = if process.name_lossy {
+                            ui.selectable_label(focused, format!("⚠ {}", process.name))
+                                .on_hover_text(t("进程名称包含无效字符，已替换显示"))
+                        } else {
+                            ui.selectable_label(focused, &process.name)
+                        };
+                        if name.clicked() {
+                            self.focused = if focused { None } else { Some(process.pid) };
+                        }
+                        name.context_menu(|ui| {
+                            if ui.button(t("结束进程")).clicked() {
+                                self.confirming_kill = Some(HashSet::from([process.pid]));
+                                ui.close_menu();
+                            }
+                        });
+                        ui.label(UiUtils::format_percentage(process.cpu_usage as f64));
+                        ui.label(UiUtils::format_bytes(process.memory_usage));
+                        ui.label(process.user.as_deref().unwrap_or("—"));
+                        ui.label(&process.status);
+                        let run_time = ui.label(TimeFormatter::optional_seconds_to_human_readable(process.run_time));
+                        if let Some(start_time) = process.start_time {
+                            run_time.on_hover_text(format!("{} {}", t("启动于"), TimeFormatter::timestamp_to_local_string(start_time)));
+                        }
+                        // 单元格中截断，悬停显示完整命令行
+                        let command_line = process.command_line();
+                        if command_line.is_empty() {
+                            ui.label("—");
+                        } else {
+                            ui.label(StringUtils::truncate(&command_line, COMMAND_LINE_CELL_CHARS))
+                                .on_hover_text(&command_line);
+                        }
+                        ui.end_row();
+                    }
+                });
+        });
+
+        self.render_kill_confirmation(ui, processes, context);
     }
     
     fn title(&self) -> &str {
-        "进程"
+        t("进程")
+    }
+
+    /// 按当前过滤与排序输出可见的进程
+    fn to_clipboard_text(&self, context: &RenderContext<'_>) -> Option<String> {
+        let snapshot = context.system_data.filter(|data| data.processes.is_some())?;
+        let mut text = format!("PID\t{}\tCPU\t{}\t{}\n", t("名称"), t("内存"), t("状态"));
+        for process in self.visible(snapshot) {
+            text.push_str(&format!(
+                "{}\t{}\t{}\t{}\t{}\n",
+                process.pid,
+                process.name,
+                UiUtils::format_percentage(process.cpu_usage as f64),
+                UiUtils::format_bytes(process.memory_usage),
+                process.status
+            ));
+        }
+        Some(text)
+    }
+
+    fn visible_processes(&mut self, snapshot: &SystemSnapshot) -> Option<Vec<ProcessInfo>> {
+        self.refresh_order(snapshot);
+        Some(self.visible(snapshot).into_iter().cloned().collect())
     }
 }
 
+/// 网络速率图表保留的样本数
+const NETWORK_RATE_POINTS: usize = 120;
+
 /// 网络标签页渲染器
-pub struct NetworkTabRenderer;
+pub struct NetworkTabRenderer {
+    /// 正在重命名的接口及其别名草稿
+    renaming: Option<(String, String)>,
+    /// 最近一次记录速率的快照时间，避免同一快照重复记录
+    last_timestamp: Option<chrono::DateTime<chrono::Utc>>,
+    /// 总计的接收/发送速率历史（KB/s）
+    rate_history: VecDeque<(Instant, f32, f32)>,
+    /// 连接列表的状态过滤
+    connection_filter: ConnectionFilter,
+}
 
 impl NetworkTabRenderer {
     pub fn new() -> Self {
-        Self
+        Self {
+            renaming: None,
+            last_timestamp: None,
+            rate_history: VecDeque::with_capacity(NETWORK_RATE_POINTS),
+            connection_filter: ConnectionFilter::default(),
+        }
+    }
+
+    /// 渲染TCP/UDP连接列表，按需获取，可按状态过滤
+    fn render_connections(&mut self, ui: &mut egui::Ui, context: &RenderContext<'_>) {
+        ui.collapsing(t("连接"), |ui| {
+            ui.horizontal(|ui| {
+                for filter in ConnectionFilter::ALL {
+                    ui.selectable_value(&mut self.connection_filter, filter, filter.label());
+                }
+                if ui.button(t("刷新")).clicked() {
+                    let _ = context.sender.send(AppMessage::RefreshConnections);
+                }
+            });
+
+            let Some(connections) = context.connections else {
+                ui.weak(t("点击刷新获取当前的TCP/UDP连接"));
+                return;
+            };
+            let visible: Vec<&ConnectionInfo> = connections.iter()
+                .filter(|connection| self.connection_filter.matches(connection))
+                .collect();
+            ui.label(format!("{}: {}", t("连接数"), visible.len()));
+
+            egui::ScrollArea::vertical().id_salt("connections").max_height(300.0).show(ui, |ui| {
+                egui::Grid::new("connection_table").striped(true).num_columns(5).show(ui, |ui| {
+                    for header in [t("协议"), t("本地地址"), t("远端地址"), t("状态"), "PID"] {
+                        ui.strong(header);
+                    }
+                    ui.end_row();
+
+                    for connection in visible {
+                        ui.label(connection.protocol.label());
+                        ui.label(connection.local_addr.to_string());
+                        ui.label(connection.remote_addr.map_or_else(|| "—".to_string(), |addr| addr.to_string()));
+                        ui.label(&connection.state);
+                        ui.label(connection.owning_pid.map_or_else(|| "—".to_string(), |pid| pid.to_string()));
+                        ui.end_row();
+                    }
+                });
+            });
+        });
+    }
+
+    /// 渲染总计速率的实时曲线
+    fn render_rate_chart(&self, ui: &mut egui::Ui, context: &RenderContext<'_>) {
+        let colors = context.config.ui.chart_colors_for(ui.visuals().dark_mode);
+        for (label, color, received) in [
+            (t("接收速率 (KB/s)"), colors.cpu_color, true),
+            (t("发送速率 (KB/s)"), colors.memory_color, false),
+        ] {
+            ui.small(label);
+            let points: Vec<(Instant, f32)> = self.rate_history.iter()
+                .map(|&(at, recv, sent)| (at, if received { recv } else { sent }))
+                .collect();
+            let mut chart = UiUtils::line_chart(NETWORK_RATE_POINTS, UiUtils::color_from_rgb(color), &context.config.ui)
+                .with_autoscale(true);
+            chart.set_timed_data(&points);
+            chart.render(ui, egui::vec2(ui.available_width(), 80.0));
+        }
+    }
+
+    /// 渲染所有接口的总计卡片，默认不计入虚拟接口
+    fn render_total(
+        &mut self,
+        ui: &mut egui::Ui,
+        networks: &[NetworkInfo],
+        timestamp: chrono::DateTime<chrono::Utc>,
+        context: &RenderContext<'_>,
+    ) {
+        let include_virtual = context.config.ui.network_total_include_virtual;
+        let total = NetworkInfo::aggregate(networks.iter().filter(|network| include_virtual || !network.is_virtual()));
+
+        // 每个新快照记录一次总计速率
+        if self.last_timestamp != Some(timestamp) {
+            self.last_timestamp = Some(timestamp);
+            if self.rate_history.len() == NETWORK_RATE_POINTS {
+                self.rate_history.pop_front();
+            }
+            self.rate_history.push_back((
+                Instant::now(),
+                (total.recv_rate_bps / 1024.0) as f32,
+                (total.send_rate_bps / 1024.0) as f32,
+            ));
+        }
+
+        let rate = |bytes_per_second: f64| format!("{}/s", UiUtils::format_bytes(bytes_per_second as u64));
+        let title = if include_virtual { t("总计") } else { t("总计（不含虚拟接口）") };
+        UiUtils::info_card(ui, title, |ui| {
+            ui.columns(2, |columns| {
+                columns[0].heading(t("接收"));
+                UiUtils::metric_display(&mut columns[0], t("字节数"), &UiUtils::format_bytes(total.bytes_received), None);
+                UiUtils::metric_display(&mut columns[0], t("速率"), &rate(total.recv_rate_bps), None);
+
+                columns[1].heading(t("发送"));
+                UiUtils::metric_display(&mut columns[1], t("字节数"), &UiUtils::format_bytes(total.bytes_sent), None);
+                UiUtils::metric_display(&mut columns[1], t("速率"), &rate(total.send_rate_bps), None);
+            });
+            self.render_rate_chart(ui, context);
+        });
+    }
+
+    /// 渲染接口重命名行
+    fn render_rename_row(&mut self, ui: &mut egui::Ui, name: &str, context: &RenderContext<'_>) {
+        ui.horizontal(|ui| {
+            if let Some((interface, draft)) = self.renaming.as_mut().filter(|(interface, _)| interface == name) {
+                let response = ui.text_edit_singleline(draft);
+                let submitted = response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+
+                if submitted || ui.button(t("保存")).clicked() {
+                    // 别名为空表示恢复原始名称
+                    let mut config = context.config.clone();
+                    let alias = draft.trim().to_string();
+                    if alias.is_empty() {
+                        config.ui.network_aliases.remove(interface);
+                    } else {
+                        config.ui.network_aliases.insert(interface.clone(), alias);
+                    }
+                    let _ = context.sender.send(AppMessage::ApplyConfig(config));
+                    self.renaming = None;
+                } else if ui.button(t("取消")).clicked() {
+                    self.renaming = None;
+                }
+            } else if ui.small_button(t("重命名")).clicked() {
+                let display_name = context.config.ui.network_display_name(name);
+                self.renaming = Some((name.to_string(), display_name.to_string()));
+            }
+        });
     }
 }
 
 impl TabRenderer for NetworkTabRenderer {
-    fn render(&mut self, ui: &mut egui::Ui, system_data: Option<&SystemSnapshot>) {
-        ui.heading("网络信息");
+    fn render(&mut self, ui: &mut egui::Ui, context: &RenderContext<'_>) {
+        ui.heading(t("网络信息"));
         ui.separator();
         
-        if let Some(data) = system_data {
+        if let Some(data) = context.system_data {
             if let Some(ref networks) = data.networks {
                 if networks.is_empty() {
                     ui.centered_and_justified(|ui| {
-                        ui.label("未检测到网络接口");
+                        ui.label(t("未检测到网络接口"));
                     });
                     return;
                 }
+
+                if context.config.ui.network_show_total {
+                    self.render_total(ui, networks, data.timestamp, context);
+                    ui.add_space(8.0);
+                }
                 
                 // 网络接口列表
                 for network in networks {
-                    UiUtils::info_card(ui, &network.name, |ui| {
+                    let display_name = context.config.ui.network_display_name(&network.name);
+                    UiUtils::info_card(ui, display_name, |ui| {
+                        self.render_rename_row(ui, &network.name, context);
                         ui.columns(2, |columns| {
-                            columns[0].heading("接收");
+                            columns[0].heading(t("接收"));
                             UiUtils::metric_display(
                                 &mut columns[0], 
-                                "字节数", 
+                                t("字节数"), 
                                 &UiUtils::format_bytes(network.bytes_received),
                                 None
                             );
                             UiUtils::metric_display(
                                 &mut columns[0], 
-                                "包数", 
+                                t("速率"), 
+                                &format!("{}/s", UiUtils::format_bytes(network.recv_rate_bps as u64)),
+                                None
+                            );
+                            UiUtils::metric_display(
+                                &mut columns[0], 
+                                t("包数"), 
                                 &network.packets_received.to_string(),
                                 None
                             );
                             UiUtils::metric_display(
                                 &mut columns[0], 
-                                "错误数", 
+                                t("错误数"), 
                                 &network.errors_received.to_string(),
-                                if network.errors_received > 0 { Some(egui::Color32::RED) } else { None }
+                                if network.errors_received > 0 { Some(context.colors.error) } else { None }
                             );
                             
-                            columns[1].heading("发送");
+                            columns[1].heading(t("发送"));
                             UiUtils::metric_display(
                                 &mut columns[1], 
-                                "字节数", 
+                                t("字节数"), 
                                 &UiUtils::format_bytes(network.bytes_sent),
                                 None
                             );
                             UiUtils::metric_display(
                                 &mut columns[1], 
-                                "包数", 
+                                t("速率"), 
+                                &format!("{}/s", UiUtils::format_bytes(network.send_rate_bps as u64)),
+                                None
+                            );
+                            UiUtils::metric_display(
+                                &mut columns[1], 
+                                t("包数"), 
                                 &network.packets_sent.to_string(),
                                 None
                             );
                             UiUtils::metric_display(
                                 &mut columns[1], 
-                                "错误数", 
+                                t("错误数"), 
                                 &network.errors_sent.to_string(),
-                                if network.errors_sent > 0 { Some(egui::Color32::RED) } else { None }
+                                if network.errors_sent > 0 { Some(context.colors.error) } else { None }
                             );
                         });
-                    });
+                    }).response.on_hover_text(format!("{}: {}", t("接口"), network.name));
                     
                     ui.add_space(8.0);
                 }
             } else {
-                ui.label("网络信息不可用");
+                ui.label(t("网络信息不可用"));
             }
         } else {
             ui.centered_and_justified(|ui| {
-                ui.label("正在加载网络数据...");
+                ui.label(t("正在加载网络数据..."));
             });
+            return;
+        }
+
+        // 不支持列出连接的平台不显示连接列表，避免一直显示错误
+        if connections::is_supported() {
+            self.render_connections(ui, context);
         }
     }
     
     fn title(&self) -> &str {
-        "网络"
+        t("网络")
+    }
+
+    fn to_clipboard_text(&self, context: &RenderContext<'_>) -> Option<String> {
+        let networks = context.system_data?.networks.as_ref()?;
+        Some(networks.iter()
+            .map(|network| format!(
+                "{}: {} {}, {} {}\n",
+                context.config.ui.network_display_name(&network.name),
+                t("接收"),
+                UiUtils::format_bytes(network.bytes_received),
+                t("发送"),
+                UiUtils::format_bytes(network.bytes_sent)
+            ))
+            .collect())
     }
 }
\ No newline at end of file
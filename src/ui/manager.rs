@@ -2,16 +2,63 @@
 //! 
 //! 负责协调和管理所有UI组件的渲染和状态。
 
-use crate::config::AppConfig;
+use crate::config::{AppConfig, WindowConfig, BootTimeDisplay, ByteUnits, FrequencyUnit, Language, OverviewLayout, ProcessCollectionMode, RawBytesDisplay};
 use crate::error::Result;
-use crate::system::SystemSnapshot;
-use crate::app::{AppMessage, AppState};
-use crate::ui::{TabType, UiState, UiTheme, ColorScheme, MemoryTabRenderer, DiskTabRenderer, ProcessTabRenderer, NetworkTabRenderer};
+use crate::system::{ProcessInfo, SensorInfo, SystemSnapshot};
+use crate::system::alert::AlertLevel;
+use crate::system::metrics::{LoadTrend, MetricsCalculator};
+use crate::system::connections::ConnectionInfo;
+use crate::system::process::ProcessSortKey;
+use crate::system::export;
+use crate::utils::{t, Formatter, I18n};
+use crate::app::{AppMessage, AppState, LogLevel, SourceStatus};
+use crate::ui::charts::TimeWindow;
+use crate::ui::{TabType, UiState, UiTheme, ColorScheme, ResponsiveLayout, MemoryTabRenderer, DiskTabRenderer, ProcessTabRenderer, NetworkTabRenderer, GpuTabRenderer};
 use eframe::egui;
 use std::sync::Arc;
 use std::collections::HashMap;
+use std::time::{Duration, Instant};
 use tokio::sync::mpsc;
 
+/// 负载预测的时间窗口
+const PREDICTION_WINDOW: Duration = Duration::from_secs(60);
+
+/// 用于预测的历史数据时长
+const PREDICTION_HISTORY: Duration = Duration::from_secs(600);
+
+/// 侧边栏宽度停止变化后保存到配置的延迟
+const SIDEBAR_SAVE_DELAY: Duration = Duration::from_secs(1);
+
+/// 切换标签页的快捷键按键，与Ctrl组合，按标签页顺序对应
+const TAB_SHORTCUT_KEYS: [egui::Key; 7] = [
+    egui::Key::Num1,
+    egui::Key::Num2,
+    egui::Key::Num3,
+    egui::Key::Num4,
+    egui::Key::Num5,
+    egui::Key::Num6,
+    egui::Key::Num7,
+];
+
+/// 立即刷新的快捷键
+const REFRESH_SHORTCUT: egui::KeyboardShortcut = egui::KeyboardShortcut::new(egui::Modifiers::COMMAND, egui::Key::R);
+
+/// 打开设置的快捷键
+const SETTINGS_SHORTCUT: egui::KeyboardShortcut = egui::KeyboardShortcut::new(egui::Modifiers::COMMAND, egui::Key::Comma);
+
+/// 切换紧凑模式的快捷键
+const COMPACT_SHORTCUT: egui::KeyboardShortcut = egui::KeyboardShortcut::new(egui::Modifiers::COMMAND, egui::Key::M);
+
+/// 紧凑模式下每个指标格的最小宽度
+const COMPACT_CELL_MIN_WIDTH: f32 = 120.0;
+
+/// 概览页环形图的最小和最大边长
+const GAUGE_MIN_SIZE: f32 = 60.0;
+const GAUGE_MAX_SIZE: f32 = 120.0;
+
+/// CPU核心热力图的方格边长
+const HEATMAP_CELL_SIZE: f32 = 24.0;
+
 /// UI管理器
 pub struct UiManager {
     /// UI状态
@@ -20,14 +67,51 @@ pub struct UiManager {
     config: Arc<AppConfig>,
     /// 系统数据
     system_data: Option<SystemSnapshot>,
+    /// 历史指标计算器（用于负载预测）
+    metrics: MetricsCalculator,
     /// 标签页渲染器
     tab_renderers: HashMap<TabType, Box<dyn TabRenderer>>,
+    /// 设置窗口中尚未应用的修改（窗口打开期间有效）
+    settings_draft: Option<SettingsDraft>,
+}
+
+/// 设置窗口的编辑草稿
+///
+/// 修改先写入草稿，在拖动结束且没有输入框获得焦点时校验并应用，
+/// 避免编辑过程中的中间值被拒绝后静默丢失。
+struct SettingsDraft {
+    /// 创建草稿时已应用的配置，配置更新后未修改的草稿随之刷新
+    base: Arc<AppConfig>,
+    /// 编辑中的配置
+    config: AppConfig,
+    /// 是否有尚未应用的修改
+    dirty: bool,
+    /// 最近一次校验失败的原因
+    error: Option<String>,
+}
+
+/// 标签页渲染上下文
+pub struct RenderContext<'a> {
+    /// 最新的系统快照
+    pub system_data: Option<&'a SystemSnapshot>,
+    /// 应用配置
+    pub config: &'a AppConfig,
+    /// 消息发送端
+    pub sender: &'a mpsc::UnboundedSender<AppMessage>,
+    /// 负载趋势预测（样本不足时为 `None`）
+    pub load_trend: Option<&'a LoadTrend>,
+    /// 当前配色方案
+    pub colors: &'a ColorScheme,
+    /// 历史指标
+    pub metrics: &'a MetricsCalculator,
+    /// 最近一次获取的网络连接（尚未获取时为 `None`）
+    pub connections: Option<&'a [ConnectionInfo]>,
 }
 
 /// 标签页渲染器特征
 pub trait TabRenderer: Send + Sync {
     /// 渲染标签页内容
-    fn render(&mut self, ui: &mut egui::Ui, system_data: Option<&SystemSnapshot>);
+    fn render(&mut self, ui: &mut egui::Ui, context: &RenderContext<'_>);
     
     /// 获取标签页标题
     fn title(&self) -> &str;
@@ -36,30 +120,37 @@ pub trait TabRenderer: Send + Sync {
     fn is_enabled(&self) -> bool {
         true
     }
+
+    /// 生成当前标签页数据的文本摘要，用于复制到剪贴板（无数据时为 `None`）
+    fn to_clipboard_text(&self, _context: &RenderContext<'_>) -> Option<String> {
+        None
+    }
+
+    /// 按表格当前的过滤与排序返回快照中的进程，用于导出（非进程标签页为 `None`）
+    fn visible_processes(&mut self, _snapshot: &SystemSnapshot) -> Option<Vec<ProcessInfo>> {
+        None
+    }
 }
 
 impl UiManager {
     /// 创建新的UI管理器
     pub fn new(ctx: &egui::Context, config: Arc<AppConfig>) -> Result<Self> {
-        // 根据配置设置主题
-        let theme = match config.ui.theme {
-            crate::config::Theme::Light => UiTheme::Light,
-            crate::config::Theme::Dark => UiTheme::Dark,
-            crate::config::Theme::Auto => {
-                // 简单的自动主题检测，实际可以根据系统主题
-                UiTheme::Dark
-            }
-        };
-        
+        // 根据配置设置主题，Auto 跟随系统的亮色/暗色偏好
+        let theme = UiTheme::resolve(&config.ui.theme, ctx.system_theme());
         theme.apply_to_context(ctx);
         
         let mut state = UiState::default();
         state.theme = theme;
-        state.color_scheme = match theme {
-            UiTheme::Light => ColorScheme::light(),
-            UiTheme::Dark => ColorScheme::dark(),
-        };
+        state.color_scheme = theme.color_scheme();
+        state.follow_system_theme = matches!(config.ui.theme, crate::config::Theme::Auto);
+        // 窗口已按配置的布局和层级创建
+        state.compact_mode = config.window.compact_mode;
+        state.always_on_top = config.window.always_on_top;
         state.font_size = config.ui.font_size;
+        let width_range = crate::config::UiConfig::SIDEBAR_WIDTH_RANGE;
+        state.sidebar_width = config.ui.sidebar_width.clamp(*width_range.start(), *width_range.end());
+        Formatter::configure(config.ui.format);
+        I18n::set_language(config.ui.language);
         
         // 初始化标签页渲染器
         let mut tab_renderers: HashMap<TabType, Box<dyn TabRenderer>> = HashMap::new();
@@ -69,27 +160,145 @@ impl UiManager {
         tab_renderers.insert(TabType::Disk, Box::new(DiskTabRenderer::new()));
         tab_renderers.insert(TabType::Process, Box::new(ProcessTabRenderer::new()));
         tab_renderers.insert(TabType::Network, Box::new(NetworkTabRenderer::new()));
+        tab_renderers.insert(TabType::Gpu, Box::new(GpuTabRenderer::new()));
         
+        let mut metrics = MetricsCalculator::new(config.monitoring.cpu_history_points, PREDICTION_HISTORY);
+        if config.monitoring.persist_history {
+            Self::restore_history(&mut metrics);
+        }
+
         Ok(Self {
             state,
             config,
             system_data: None,
+            metrics,
             tab_renderers,
+            settings_draft: None,
         })
     }
     
     /// 更新配置
     pub fn update_config(&mut self, config: Arc<AppConfig>) -> Result<()> {
         self.config = config;
+        Formatter::configure(self.config.ui.format);
+        I18n::set_language(self.config.ui.language);
         self.state.font_size = self.config.ui.font_size;
         Ok(())
     }
     
+    /// 处理全局快捷键，文本框获得焦点时不处理，避免与输入冲突
+    fn handle_shortcuts(&self, ctx: &egui::Context, sender: &mpsc::UnboundedSender<AppMessage>) {
+        if ctx.wants_keyboard_input() {
+            return;
+        }
+
+        if ctx.input_mut(|i| i.consume_shortcut(&COMPACT_SHORTCUT)) {
+            self.toggle_compact_mode(sender);
+        }
+        // 紧凑模式不显示标签页
+        if self.state.compact_mode {
+            return;
+        }
+
+        for (tab, key) in TabType::all().into_iter().zip(TAB_SHORTCUT_KEYS) {
+            let shortcut = egui::KeyboardShortcut::new(egui::Modifiers::COMMAND, key);
+            if ctx.input_mut(|i| i.consume_shortcut(&shortcut)) {
+                let _ = sender.send(AppMessage::SwitchTab(tab));
+            }
+        }
+        if ctx.input_mut(|i| i.consume_shortcut(&REFRESH_SHORTCUT)) {
+            let _ = sender.send(AppMessage::RefreshNow);
+        }
+        if ctx.input_mut(|i| i.consume_shortcut(&SETTINGS_SHORTCUT)) {
+            let _ = sender.send(AppMessage::ShowSettings);
+        }
+    }
+
+    /// 切换紧凑模式，写入配置后在下一帧调整窗口布局
+    fn toggle_compact_mode(&self, sender: &mpsc::UnboundedSender<AppMessage>) {
+        let mut config = self.config.as_ref().clone();
+        config.window.compact_mode = !config.window.compact_mode;
+        let _ = sender.send(AppMessage::ApplyConfig(config));
+    }
+
+    /// 按配置进入或退出紧凑模式，调整最小窗口尺寸并恢复之前的标签页和窗口尺寸
+    fn apply_compact_mode(&mut self, ctx: &egui::Context, compact: bool) {
+        self.state.compact_mode = compact;
+        if compact {
+            self.state.tab_before_compact = Some(self.state.active_tab);
+            self.state.window_size_before_compact = ctx.input(|i| i.viewport().inner_rect).map(|rect| rect.size());
+            ctx.send_viewport_cmd(egui::ViewportCommand::MinInnerSize(WindowConfig::COMPACT_MIN_SIZE.into()));
+            ctx.send_viewport_cmd(egui::ViewportCommand::InnerSize(WindowConfig::COMPACT_SIZE.into()));
+        } else {
+            if let Some(tab) = self.state.tab_before_compact.take() {
+                self.state.active_tab = tab;
+            }
+            let size = self.state.window_size_before_compact.take()
+                .unwrap_or(egui::vec2(self.config.window.width, self.config.window.height))
+                .max(WindowConfig::MIN_SIZE.into());
+            ctx.send_viewport_cmd(egui::ViewportCommand::MinInnerSize(WindowConfig::MIN_SIZE.into()));
+            ctx.send_viewport_cmd(egui::ViewportCommand::InnerSize(size));
+        }
+    }
+
+    /// 切换主题并应用对应的配色方案
+    fn set_theme(&mut self, ctx: &egui::Context, theme: UiTheme) {
+        self.state.theme = theme;
+        self.state.color_scheme = theme.color_scheme();
+        theme.apply_to_context(ctx);
+    }
+
     /// 更新系统数据
     pub fn update_system_data(&mut self, data: SystemSnapshot) {
+        // 未采集的类别不计入历史，避免停用监控后记录0%样本
+        if let Some(cpu) = data.cpu_usage() {
+            self.metrics.add_cpu_data(cpu);
+        }
+        if let Some(memory) = data.memory_usage() {
+            self.metrics.add_memory_data(memory);
+        }
+        if let Some(ref processes) = data.processes {
+            self.metrics.add_process_data(processes);
+        }
+
+        // 变化低于阈值的采样只计入统计，不刷新显示
+        if let Some(ref current) = self.system_data {
+            if !crate::ui::UiUtils::is_significant_change(&self.config.ui.change_threshold, current, &data) {
+                return;
+            }
+        }
         self.system_data = Some(data);
     }
     
+    /// 历史统计计算器
+    pub fn metrics(&self) -> &MetricsCalculator {
+        &self.metrics
+    }
+
+    /// 从磁盘恢复上次保存的历史，文件缺失或损坏时从空历史开始
+    fn restore_history(metrics: &mut MetricsCalculator) {
+        let restored = AppConfig::history_path().and_then(|path| metrics.load_history(&path));
+        match restored {
+            Ok(count) => log::info!("已恢复 {} 个历史数据点", count),
+            Err(e) => log::warn!("恢复历史数据失败，从空历史开始: {}", e),
+        }
+    }
+
+    /// 按配置将历史保存到磁盘
+    pub fn save_history(&self) {
+        if !self.config.monitoring.persist_history {
+            return;
+        }
+        if let Err(e) = AppConfig::history_path().and_then(|path| self.metrics.save_history(&path)) {
+            log::error!("保存历史数据失败: {}", e);
+        }
+    }
+
+    /// 重置历史统计
+    pub fn reset_statistics(&mut self) {
+        self.metrics.clear();
+    }
+
     /// 设置活动标签页
     pub fn set_active_tab(&mut self, tab: TabType) {
         self.state.active_tab = tab;
@@ -97,19 +306,49 @@ impl UiManager {
     
     /// 渲染主界面
     pub fn render(&mut self, ctx: &egui::Context, app_state: &mut AppState, sender: &mpsc::UnboundedSender<AppMessage>) {
-        // 渲染顶部菜单栏
-        self.render_menu_bar(ctx, app_state, sender);
-        
-        // 渲染侧边栏
-        if self.state.show_sidebar {
-            self.render_sidebar(ctx, sender);
+        // 跟随系统主题时应用系统偏好的变化
+        if self.state.follow_system_theme {
+            let theme = UiTheme::resolve(&crate::config::Theme::Auto, ctx.system_theme());
+            if theme != self.state.theme {
+                self.set_theme(ctx, theme);
+            }
+        }
+
+        if self.state.always_on_top != self.config.window.always_on_top {
+            self.state.always_on_top = self.config.window.always_on_top;
+            let level = if self.state.always_on_top { egui::WindowLevel::AlwaysOnTop } else { egui::WindowLevel::Normal };
+            ctx.send_viewport_cmd(egui::ViewportCommand::WindowLevel(level));
+        }
+        if self.state.compact_mode != self.config.window.compact_mode {
+            self.apply_compact_mode(ctx, self.config.window.compact_mode);
+        }
+
+        self.handle_shortcuts(ctx, sender);
+
+        if self.state.compact_mode {
+            // 紧凑模式只显示关键指标
+            self.render_compact_view(ctx, app_state, sender);
+        } else {
+            // 渲染顶部菜单栏
+            self.render_menu_bar(ctx, app_state, sender);
+
+            // 渲染摘要条
+            if self.config.ui.show_summary_strip {
+                self.render_summary_strip(ctx, app_state);
+            }
+
+            // 渲染侧边栏
+            if self.state.show_sidebar {
+                self.render_sidebar(ctx, app_state, sender);
+                self.save_sidebar_width(ctx, sender);
+            }
+
+            // 渲染主内容区域
+            self.render_main_content(ctx, app_state, sender);
+
+            // 渲染状态栏
+            self.render_status_bar(ctx, app_state);
         }
-        
-        // 渲染主内容区域
-        self.render_main_content(ctx, sender);
-        
-        // 渲染状态栏
-        self.render_status_bar(ctx, app_state);
 
         // 根据状态渲染设置窗口
         if app_state.show_settings {
@@ -120,48 +359,186 @@ impl UiManager {
         if app_state.show_about {
             self.render_about_window(ctx, app_state);
         }
+
+        // 根据状态渲染告警历史窗口
+        if app_state.show_alert_history {
+            self.render_alert_history_window(ctx, app_state, sender);
+        }
+
+        // 根据状态渲染事件日志窗口
+        if app_state.show_event_log {
+            self.render_event_log_window(ctx, app_state, sender);
+        }
+
+        // 有告警通知时显示通知窗口
+        if app_state.notification.is_some() {
+            self.render_notification_window(ctx, app_state, sender);
+        }
+
+        // 启动健康检查发现问题时显示警告窗口
+        if !app_state.startup_warnings.is_empty() {
+            self.render_startup_warning_window(ctx, app_state);
+        }
     }
     
     /// 渲染菜单栏
-    fn render_menu_bar(&mut self, ctx: &egui::Context, _app_state: &mut AppState, sender: &mpsc::UnboundedSender<AppMessage>) {
+    fn render_menu_bar(&mut self, ctx: &egui::Context, app_state: &mut AppState, sender: &mpsc::UnboundedSender<AppMessage>) {
         egui::TopBottomPanel::top("menu_bar").show(ctx, |ui| {
             egui::menu::bar(ui, |ui| {
-                ui.menu_button("文件", |ui| {
-                    if ui.button("设置").clicked() {
+                ui.menu_button(t("文件"), |ui| {
+                    if ui.button(t("设置")).clicked() {
                         let _ = sender.send(AppMessage::ShowSettings);
                         ui.close_menu();
                     }
+                    if ui.button(t("导出进程列表")).clicked() {
+                        let hostname = self.system_data.as_ref()
+                            .map(|data| data.system.hostname.as_str())
+                            .unwrap_or("unknown");
+                        match export::configured_export_path(&self.config.export, hostname, "processes", "csv") {
+                            Ok(path) => {
+                                // 与进程表格使用同一份快照和顺序
+                                let snapshot = app_state.frozen_snapshot.as_ref()
+                                    .map(|frozen| &frozen.snapshot)
+                                    .or(self.system_data.as_ref());
+                                let processes = snapshot
+                                    .and_then(|snapshot| {
+                                        self.tab_renderers.get_mut(&TabType::Process)?.visible_processes(snapshot)
+                                    })
+                                    .unwrap_or_default();
+                                let _ = sender.send(AppMessage::ExportProcesses { path, processes });
+                            }
+                            Err(e) => {
+                                let _ = sender.send(AppMessage::Error(format!("{}: {}", t("导出进程列表失败"), e)));
+                            }
+                        }
+                        ui.close_menu();
+                    }
+                    if ui.button(t("导出CSV")).on_hover_text(t("导出保留的快照历史")).clicked() {
+                        let hostname = self.system_data.as_ref()
+                            .map(|data| data.system.hostname.as_str())
+                            .unwrap_or("unknown");
+                        match export::configured_export_path(&self.config.export, hostname, "history", "csv") {
+                            Ok(path) => {
+                                let _ = sender.send(AppMessage::ExportHistory(path));
+                            }
+                            Err(e) => {
+                                let _ = sender.send(AppMessage::Error(format!("{}: {}", t("导出CSV失败"), e)));
+                            }
+                        }
+                        ui.close_menu();
+                    }
+                    for (label, with_stats) in [("导出快照", false), ("导出快照及统计", true)] {
+                        if ui.button(t(label)).clicked() {
+                            let hostname = self.system_data.as_ref()
+                                .map(|data| data.system.hostname.as_str())
+                                .unwrap_or("unknown");
+                            let kind = if with_stats { "report" } else { "snapshot" };
+                            match export::configured_export_path(&self.config.export, hostname, kind, "json") {
+                                Ok(path) => {
+                                    let _ = sender.send(AppMessage::ExportSnapshot { path, with_stats });
+                                }
+                                Err(e) => {
+                                    let _ = sender.send(AppMessage::Error(format!("{}: {}", t("导出快照失败"), e)));
+                                }
+                            }
+                            ui.close_menu();
+                        }
+                    }
                     ui.separator();
-                    if ui.button("退出").clicked() {
+                    if ui.button(t("退出")).clicked() {
                         let _ = sender.send(AppMessage::Exit);
                         ui.close_menu();
                     }
                 });
                 
-                ui.menu_button("视图", |ui| {
-                    if ui.checkbox(&mut self.state.show_sidebar, "显示侧边栏").clicked() {
+                ui.menu_button(t("视图"), |ui| {
+                    if ui.checkbox(&mut self.state.show_sidebar, t("显示侧边栏")).clicked() {
+                        ui.close_menu();
+                    }
+
+                    if ui.add(egui::Button::new(t("紧凑模式")).shortcut_text(ctx.format_shortcut(&COMPACT_SHORTCUT))).clicked() {
+                        self.toggle_compact_mode(sender);
+                        ui.close_menu();
+                    }
+
+                    if ui.button(format!("{} ({})", t("告警历史"), app_state.alert_history.len())).clicked() {
+                        let _ = sender.send(AppMessage::ShowAlertHistory);
+                        ui.close_menu();
+                    }
+
+                    if ui.button(format!("{} ({})", t("事件日志"), app_state.event_log.len())).clicked() {
+                        let _ = sender.send(AppMessage::ShowEventLog);
+                        ui.close_menu();
+                    }
+
+                    if ui.button(t("重置统计")).on_hover_text(t("清空历史数据，平均值、最值和预测重新开始计算")).clicked() {
+                        let _ = sender.send(AppMessage::ResetStatistics);
                         ui.close_menu();
                     }
                     
                     ui.separator();
-                    ui.menu_button("主题", |ui| {
-                        if ui.selectable_label(self.state.theme == UiTheme::Light, "亮色主题").clicked() {
-                            self.state.theme = UiTheme::Light;
-                            self.state.color_scheme = ColorScheme::light();
-                            self.state.theme.apply_to_context(ctx);
+                    ui.menu_button(t("主题"), |ui| {
+                        let follow_system = self.state.follow_system_theme;
+                        if ui.selectable_label(follow_system, t("跟随系统")).clicked() {
+                            self.state.follow_system_theme = true;
+                            let theme = UiTheme::resolve(&crate::config::Theme::Auto, ctx.system_theme());
+                            self.set_theme(ctx, theme);
+                            ui.close_menu();
+                        }
+                        if ui.selectable_label(!follow_system && self.state.theme == UiTheme::Light, t("亮色主题")).clicked() {
+                            self.state.follow_system_theme = false;
+                            self.set_theme(ctx, UiTheme::Light);
                             ui.close_menu();
                         }
-                        if ui.selectable_label(self.state.theme == UiTheme::Dark, "暗色主题").clicked() {
-                            self.state.theme = UiTheme::Dark;
-                            self.state.color_scheme = ColorScheme::dark();
-                            self.state.theme.apply_to_context(ctx);
+                        if ui.selectable_label(!follow_system && self.state.theme == UiTheme::Dark, t("暗色主题")).clicked() {
+                            self.state.follow_system_theme = false;
+                            self.set_theme(ctx, UiTheme::Dark);
                             ui.close_menu();
                         }
                     });
                 });
                 
-                ui.menu_button("帮助", |ui| {
-                    if ui.button("关于").clicked() {
+                if !self.config.remote_hosts.is_empty() {
+                    ui.menu_button(t("主机"), |ui| {
+                        if ui.radio(app_state.selected_host.is_none(), t("本机")).clicked() {
+                            let _ = sender.send(AppMessage::SelectHost(None));
+                            ui.close_menu();
+                        }
+                        for host in &self.config.remote_hosts {
+                            let selected = app_state.selected_host.as_ref() == Some(&host.name);
+                            if ui.radio(selected, &host.name).on_hover_text(&host.target).clicked() {
+                                let _ = sender.send(AppMessage::SelectHost(Some(host.name.clone())));
+                                ui.close_menu();
+                            }
+                        }
+                    });
+                }
+
+                ui.menu_button(t("帮助"), |ui| {
+                    ui.menu_button(t("快捷键"), |ui| {
+                        egui::Grid::new("shortcut_help").num_columns(2).show(ui, |ui| {
+                            for (tab, key) in TabType::all().into_iter().zip(TAB_SHORTCUT_KEYS) {
+                                ui.monospace(ctx.format_shortcut(&egui::KeyboardShortcut::new(egui::Modifiers::COMMAND, key)));
+                                ui.label(tab.name());
+                                ui.end_row();
+                            }
+                            ui.monospace(ctx.format_shortcut(&REFRESH_SHORTCUT));
+                            ui.label(t("立即刷新"));
+                            ui.end_row();
+                            ui.monospace(ctx.format_shortcut(&SETTINGS_SHORTCUT));
+                            ui.label(t("打开设置"));
+                            ui.end_row();
+                            ui.monospace(ctx.format_shortcut(&COMPACT_SHORTCUT));
+                            ui.label(t("紧凑模式"));
+                            ui.end_row();
+                            if let Some(shortcut) = crate::ui::UiUtils::parse_shortcut(&self.config.ui.copy_tab_shortcut) {
+                                ui.monospace(ctx.format_shortcut(&shortcut));
+                                ui.label(t("复制当前标签页数据"));
+                                ui.end_row();
+                            }
+                        });
+                    });
+                    if ui.button(t("关于")).clicked() {
                         let _ = sender.send(AppMessage::ShowAbout);
                         ui.close_menu();
                     }
@@ -170,28 +547,33 @@ impl UiManager {
                 // 右对齐的系统状态
                 ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
                     if let Some(ref data) = self.system_data {
-                        let health_color = data.get_health_status().color();
+                        let status = data.get_health_status();
                         ui.colored_label(
-                            egui::Color32::from_rgb(
-                                (health_color[0] * 255.0) as u8,
-                                (health_color[1] * 255.0) as u8,
-                                (health_color[2] * 255.0) as u8,
-                            ),
-                            format!("● {}", data.get_health_status().description())
+                            self.state.color_scheme.health_color(status),
+                            format!("● {}", status.description())
                         );
                     }
+                    if let Some(ref host) = app_state.selected_host {
+                        if app_state.degraded {
+                            ui.colored_label(self.state.color_scheme.error, format!("{} ({})", host, t("不可达")));
+                        } else {
+                            ui.label(host);
+                        }
+                    }
                 });
             });
         });
     }
     
     /// 渲染侧边栏
-    fn render_sidebar(&mut self, ctx: &egui::Context, sender: &mpsc::UnboundedSender<AppMessage>) {
-        egui::SidePanel::left("sidebar")
+    fn render_sidebar(&mut self, ctx: &egui::Context, app_state: &AppState, sender: &mpsc::UnboundedSender<AppMessage>) {
+        let now = Instant::now();
+        let stale_after = Duration::from_secs(self.config.ui.source_stale_secs);
+        let panel = egui::SidePanel::left("sidebar")
             .default_width(self.state.sidebar_width)
-            .width_range(150.0..=300.0)
+            .width_range(crate::config::UiConfig::SIDEBAR_WIDTH_RANGE)
             .show(ctx, |ui| {
-                ui.heading("系统监控");
+                ui.heading(t("系统监控"));
                 ui.separator();
                 
                 // 渲染标签页导航
@@ -202,9 +584,28 @@ impl UiManager {
                         .unwrap_or(true);
                     
                     ui.add_enabled_ui(is_enabled, |ui| {
-                        if ui.selectable_label(is_active, tab_type.name()).clicked() {
-                            let _ = sender.send(AppMessage::SwitchTab(tab_type));
-                        }
+                        ui.horizontal(|ui| {
+                            if self.config.ui.show_source_health {
+                                let health = app_state.source_health.get(&tab_type).cloned().unwrap_or_default();
+                                let colors = &self.state.color_scheme;
+                                let color = match health.status(now, stale_after) {
+                                    SourceStatus::Fresh => colors.success,
+                                    SourceStatus::Stale => colors.warning,
+                                    SourceStatus::Error => colors.error,
+                                };
+                                let age = health.last_success
+                                    .map(|at| crate::ui::UiUtils::format_duration(now.saturating_duration_since(at).as_secs()))
+                                    .unwrap_or_else(|| t("尚未采集").to_string());
+                                let error = health.last_error
+                                    .map(|(_, message)| message)
+                                    .unwrap_or_else(|| t("无").to_string());
+                                ui.colored_label(color, "●")
+                                    .on_hover_text(format!("{}: {}\n{}: {}", t("距上次成功采集"), age, t("最近错误"), error));
+                            }
+                            if ui.selectable_label(is_active, tab_type.name()).clicked() {
+                                let _ = sender.send(AppMessage::SwitchTab(tab_type));
+                            }
+                        });
                     });
                 }
                 
@@ -212,69 +613,295 @@ impl UiManager {
                 
                 // 系统信息摘要
                 if let Some(ref data) = self.system_data {
-                    ui.heading("系统摘要");
-                    
-                    ui.label(format!("CPU: {:.1}%", data.cpu.global_usage));
-                    ui.label(format!("内存: {:.1}%", data.memory.usage_percent));
+                    ui.heading(t("系统摘要"));
+
+                    // 按配置显示最近若干样本的平均值，减少数值跳动
+                    let samples = self.config.ui.summary_average_samples;
+                    if let Some(cpu) = data.cpu_usage() {
+                        let cpu = self.metrics.recent_cpu_average(samples).unwrap_or(cpu);
+                        ui.label(format!("CPU: {:.1}%", cpu));
+                    }
+                    if let Some(memory) = data.memory_usage() {
+                        let memory = self.metrics.recent_memory_average(samples).unwrap_or(memory);
+                        ui.label(format!("{}: {:.1}%", t("内存"), memory));
+                    }
+                    if samples > 1 {
+                        ui.small(format!("{}: {}", t("平均采样数"), samples));
+                    }
                     
                     if let Some(disk) = data.disks.first() {
-                        ui.label(format!("磁盘: {:.1}%", disk.usage_percent));
+                        ui.label(format!("{}: {:.1}%", t("磁盘"), disk.usage_percent));
+                    } else {
+                        ui.weak(t("无可用磁盘信息"));
                     }
                     
                     ui.separator();
-                    ui.small(format!("更新时间: {}", 
-                        data.timestamp.format("%H:%M:%S")));
+                    ui.small(format!("{}: {}", t("更新时间"), data.timestamp.format("%H:%M:%S")));
                 }
             });
+
+        let width = panel.response.rect.width();
+        if (width - self.state.sidebar_width).abs() >= 1.0 {
+            self.state.sidebar_width = width;
+            self.state.sidebar_resized_at = Some(Instant::now());
+        }
+    }
+
+    /// 侧边栏宽度停止变化一段时间且未在拖动时保存到配置，避免拖动过程中频繁写配置
+    fn save_sidebar_width(&mut self, ctx: &egui::Context, sender: &mpsc::UnboundedSender<AppMessage>) {
+        let Some(resized_at) = self.state.sidebar_resized_at else {
+            return;
+        };
+        let elapsed = resized_at.elapsed();
+        if elapsed < SIDEBAR_SAVE_DELAY || ctx.dragged_id().is_some() {
+            ctx.request_repaint_after(SIDEBAR_SAVE_DELAY.saturating_sub(elapsed));
+            return;
+        }
+
+        self.state.sidebar_resized_at = None;
+        if (self.config.ui.sidebar_width - self.state.sidebar_width).abs() >= 1.0 {
+            let mut config = self.config.as_ref().clone();
+            config.ui.sidebar_width = self.state.sidebar_width;
+            let _ = sender.send(AppMessage::ApplyConfig(config));
+        }
     }
     
     /// 渲染主内容区域
-    fn render_main_content(&mut self, ctx: &egui::Context, _sender: &mpsc::UnboundedSender<AppMessage>) {
+    fn render_main_content(&mut self, ctx: &egui::Context, app_state: &AppState, sender: &mpsc::UnboundedSender<AppMessage>) {
         egui::CentralPanel::default().show(ctx, |ui| {
             // 渲染标签页标题
             ui.horizontal(|ui| {
                 ui.heading(self.state.active_tab.name());
                 
-                // 右对齐的刷新按钮
+                // 右对齐的刷新和冻结按钮
                 ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
-                    if ui.button("🔄 刷新").clicked() {
-                        // 发送刷新消息 - 这将在异步重构中更有用
+                    if ui.button(t("🔄 刷新")).clicked() {
+                        let _ = sender.send(AppMessage::RefreshNow);
+                    }
+
+                    let frozen = app_state.frozen_snapshot.is_some();
+                    if ui.selectable_label(frozen, t("❄ 冻结显示")).clicked() {
+                        let message = if frozen { AppMessage::UnfreezeDisplay } else { AppMessage::FreezeDisplay };
+                        let _ = sender.send(message);
                     }
                 });
             });
+
+            // 冻结提示横幅
+            if let Some(ref frozen) = app_state.frozen_snapshot {
+                egui::Frame::NONE
+                    .fill(self.state.color_scheme.warning.gamma_multiply(0.2))
+                    .inner_margin(6.0)
+                    .show(ui, |ui| {
+                        ui.horizontal(|ui| {
+                            ui.colored_label(
+                                self.state.color_scheme.warning,
+                                format!(
+                                    "{} {} ({})",
+                                    t("显示已冻结，冻结时长"),
+                                    crate::ui::UiUtils::format_duration(frozen.frozen_at.elapsed().as_secs()),
+                                    frozen.snapshot.timestamp.with_timezone(&chrono::Local).format("%H:%M:%S")
+                                ),
+                            );
+                            if ui.button(t("恢复实时")).clicked() {
+                                let _ = sender.send(AppMessage::UnfreezeDisplay);
+                            }
+                        });
+                    });
+            }
             
             ui.separator();
             
+            // 至少两个样本才能计算趋势
+            let load_trend = (self.metrics.calculate_cpu_stats().sample_count >= 2)
+                .then(|| self.metrics.predict_load_trend(PREDICTION_WINDOW));
+
             // 渲染活动标签页内容
             if let Some(renderer) = self.tab_renderers.get_mut(&self.state.active_tab) {
-                renderer.render(ui, self.system_data.as_ref());
+                // 冻结时优先显示冻结的快照
+                let system_data = app_state.frozen_snapshot.as_ref()
+                    .map(|frozen| &frozen.snapshot)
+                    .or(self.system_data.as_ref());
+                let context = RenderContext {
+                    system_data,
+                    config: &self.config,
+                    sender,
+                    load_trend: load_trend.as_ref(),
+                    colors: &self.state.color_scheme,
+                    metrics: &self.metrics,
+                    connections: app_state.connections.as_deref(),
+                };
+
+                // 复制当前标签页数据的快捷键
+                let shortcut = crate::ui::UiUtils::parse_shortcut(&self.config.ui.copy_tab_shortcut);
+                if shortcut.is_some_and(|shortcut| ui.input_mut(|i| i.consume_shortcut(&shortcut))) {
+                    if let Some(text) = renderer.to_clipboard_text(&context) {
+                        ui.ctx().copy_text(text);
+                    }
+                }
+
+                renderer.render(ui, &context);
             } else {
                 ui.centered_and_justified(|ui| {
-                    ui.label("标签页内容加载中...");
+                    ui.label(t("标签页内容加载中..."));
                 });
             }
         });
     }
     
+    /// 渲染紧凑模式视图
+    ///
+    /// 只显示CPU、内存、磁盘使用率和网络速率的密集网格，右键菜单可退出紧凑模式。
+    fn render_compact_view(&mut self, ctx: &egui::Context, app_state: &AppState, sender: &mpsc::UnboundedSender<AppMessage>) {
+        let response = egui::CentralPanel::default().show(ctx, |ui| {
+            let data = app_state.frozen_snapshot.as_ref()
+                .map(|frozen| &frozen.snapshot)
+                .or(self.system_data.as_ref());
+            let Some(data) = data else {
+                ui.centered_and_justified(|ui| {
+                    ui.weak(t("正在加载系统数据..."));
+                });
+                return;
+            };
+
+            let percent = |value: f64| {
+                (crate::ui::UiUtils::format_percentage(value), crate::ui::UiUtils::get_usage_color(value))
+            };
+            // 多块磁盘时显示使用率最高的一块
+            let busiest_disk = data.disks.iter().map(|disk| disk.usage_percent).reduce(f64::max);
+            let network = data.networks.as_deref().map(|networks| {
+                let include_virtual = self.config.ui.network_total_include_virtual;
+                crate::system::NetworkInfo::aggregate(networks.iter().filter(|network| include_virtual || !network.is_virtual()))
+            });
+            let rate = |bps: f64| format!("{}/s", crate::ui::UiUtils::format_bytes(bps as u64));
+            let neutral = self.state.color_scheme.primary;
+            let missing = || ("—".to_string(), self.state.color_scheme.text_secondary);
+
+            let cells = [
+                ("CPU", data.cpu_usage().map(|usage| percent(usage as f64)).unwrap_or_else(missing)),
+                (t("内存"), data.memory_usage().map(percent).unwrap_or_else(missing)),
+                (t("磁盘"), busiest_disk.map(percent).unwrap_or_else(missing)),
+                ("↓", network.as_ref().map(|total| (rate(total.recv_rate_bps), neutral)).unwrap_or_else(missing)),
+                ("↑", network.as_ref().map(|total| (rate(total.send_rate_bps), neutral)).unwrap_or_else(missing)),
+            ];
+
+            let columns = ResponsiveLayout::calculate_columns(ui.available_width(), COMPACT_CELL_MIN_WIDTH).min(cells.len());
+            egui::Grid::new("compact_metrics")
+                .num_columns(columns)
+                .spacing([12.0, 4.0])
+                .show(ui, |ui| {
+                    for (index, (label, (value, color))) in cells.into_iter().enumerate() {
+                        ui.horizontal(|ui| {
+                            ui.small(label);
+                            ui.label(egui::RichText::new(value).heading().strong().color(color));
+                        });
+                        if (index + 1) % columns == 0 {
+                            ui.end_row();
+                        }
+                    }
+                });
+        }).response;
+
+        response.context_menu(|ui| {
+            if ui.add(egui::Button::new(t("退出紧凑模式")).shortcut_text(ctx.format_shortcut(&COMPACT_SHORTCUT))).clicked() {
+                self.toggle_compact_mode(sender);
+                ui.close_menu();
+            }
+        });
+    }
+
+    /// 渲染摘要条
+    ///
+    /// 固定在菜单栏下方，无论当前标签页如何都显示CPU、内存、磁盘和健康状态。
+    fn render_summary_strip(&mut self, ctx: &egui::Context, app_state: &AppState) {
+        egui::TopBottomPanel::top("summary_strip")
+            .resizable(false)
+            .max_height(24.0)
+            .show(ctx, |ui| {
+                ui.horizontal_centered(|ui| {
+                    let Some(ref data) = self.system_data else {
+                        ui.weak(t("正在加载系统数据..."));
+                        return;
+                    };
+
+                    let compact = |ui: &mut egui::Ui, label: &str, value: Option<f64>| {
+                        ui.small(label);
+                        match value {
+                            Some(value) => ui.label(
+                                egui::RichText::new(crate::ui::UiUtils::format_percentage(value))
+                                    .small()
+                                    .strong()
+                                    .color(crate::ui::UiUtils::get_usage_color(value)),
+                            ),
+                            None => ui.weak("—"),
+                        };
+                    };
+
+                    compact(ui, "CPU", data.cpu_usage().map(f64::from));
+                    ui.separator();
+                    compact(ui, t("内存"), data.memory_usage());
+                    ui.separator();
+                    // 多块磁盘时显示使用率最高的一块
+                    let busiest_disk = data.disks.iter().map(|disk| disk.usage_percent).reduce(f64::max);
+                    compact(ui, t("磁盘"), busiest_disk);
+                    ui.separator();
+
+                    ui.small(t("健康"));
+                    ui.label(
+                        egui::RichText::new(app_state.health_status.description())
+                            .small()
+                            .color(self.state.color_scheme.health_color(app_state.health_status)),
+                    );
+                });
+            });
+    }
+
     /// 渲染状态栏
     fn render_status_bar(&mut self, ctx: &egui::Context, app_state: &crate::app::AppState) {
         egui::TopBottomPanel::bottom("status_bar").show(ctx, |ui| {
             ui.horizontal(|ui| {
                 // 左侧状态信息
-                ui.label(format!("运行时间: {:.0}秒", app_state.start_time.elapsed().as_secs()));
+                ui.label(format!("{}: {}", t("运行时间"), crate::ui::UiUtils::format_duration(app_state.start_time.elapsed().as_secs())));
                 
                 ui.separator();
                 
+                if app_state.degraded {
+                    ui.colored_label(self.state.color_scheme.warning, t("降级模式"));
+                    ui.separator();
+                }
+
                 if let Some(ref error) = app_state.last_error {
-                    ui.colored_label(self.state.color_scheme.error, format!("错误: {}", error));
+                    ui.colored_label(self.state.color_scheme.error, format!("{}: {}", t("错误"), error));
                 } else {
-                    ui.colored_label(self.state.color_scheme.success, "运行正常");
+                    ui.colored_label(self.state.color_scheme.success, t("运行正常"));
+                }
+
+                // 温度最高的传感器
+                if let Some(sensor) = self.system_data.as_ref().and_then(|data| SensorInfo::hottest(&data.sensors)) {
+                    ui.separator();
+                    let text = format!("🌡 {:.0}°C", sensor.temperature.unwrap_or_default());
+                    let label = if sensor.is_critical() {
+                        ui.colored_label(self.state.color_scheme.error, text)
+                    } else {
+                        ui.label(text)
+                    };
+                    label.on_hover_text(&sensor.label);
                 }
                 
                 // 右侧系统信息
                 ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
                     if let Some(ref data) = self.system_data {
-                        ui.label(format!("{}核心", data.cpu.core_count));
+                        let entries = crate::ui::UiUtils::boot_time_entries(
+                            &data.system,
+                            self.config.ui.boot_time_display,
+                            &self.config.ui.datetime_format,
+                        );
+                        // 从右向左布局，逆序添加以保持阅读顺序
+                        for (label, value) in entries.iter().rev() {
+                            ui.label(format!("{}: {}", label, value));
+                            ui.separator();
+                        }
+                        ui.label(format!("{} {}", data.cpu.core_count, t("个核心")));
                         ui.separator();
                         ui.label(crate::ui::UiUtils::format_bytes(data.memory.total));
                         ui.separator();
@@ -287,117 +914,682 @@ impl UiManager {
     /// 渲染设置窗口
     fn render_settings_window(&mut self, ctx: &egui::Context, app_state: &mut AppState, sender: &mpsc::UnboundedSender<AppMessage>) {
         let mut open = app_state.show_settings;
-        egui::Window::new("设置")
+        egui::Window::new(t("设置"))
             .open(&mut open)
             .default_width(400.0)
             .default_height(300.0)
             .resizable(true)
             .show(ctx, |ui| {
-                ui.heading("应用程序设置");
+                ui.heading(t("应用程序设置"));
                 ui.separator();
                 
-                let mut config = self.config.as_ref().clone();
+                let mut draft = match self.settings_draft.take() {
+                    Some(draft) if draft.dirty || Arc::ptr_eq(&draft.base, &self.config) => draft,
+                    _ => SettingsDraft {
+                        base: self.config.clone(),
+                        config: self.config.as_ref().clone(),
+                        dirty: false,
+                        error: None,
+                    },
+                };
+                let config = &mut draft.config;
                 let mut changed = false;
 
                 // 监控设置
-                ui.collapsing("监控设置", |ui| {
+                ui.collapsing(t("监控设置"), |ui| {
                     ui.horizontal(|ui| {
-                        ui.label("刷新间隔 (毫秒):");
+                        ui.label(t("刷新间隔 (毫秒):"));
                         if ui.add(egui::Slider::new(&mut config.monitoring.refresh_interval_ms, 100..=5000)).changed() {
                             changed = true;
                         }
                     });
+
+                    ui.horizontal(|ui| {
+                        ui.label(t("传感器采样间隔 (毫秒):"));
+                        if ui.add(egui::Slider::new(&mut config.monitoring.sensor_interval_ms, 1000..=60000)).changed() {
+                            changed = true;
+                        }
+                    }).response.on_hover_text(t("温度传感器读取较慢，按独立的较长间隔采样"));
+
+                    if ui.checkbox(&mut config.monitoring.persist_history, t("保存历史数据"))
+                        .on_hover_text(t("退出时保存CPU和内存历史，下次启动时恢复图表"))
+                        .changed()
+                    {
+                        changed = true;
+                    }
                     
-                    if ui.checkbox(&mut config.monitoring.enable_cpu_monitoring, "启用CPU监控").changed() {
+                    if ui.checkbox(&mut config.monitoring.enable_cpu_monitoring, t("启用CPU监控")).changed() {
                         changed = true;
                     }
                     
-                    if ui.checkbox(&mut config.monitoring.enable_memory_monitoring, "启用内存监控").changed() {
+                    if ui.checkbox(&mut config.monitoring.enable_memory_monitoring, t("启用内存监控")).changed() {
                         changed = true;
                     }
                     
-                    if ui.checkbox(&mut config.monitoring.enable_disk_monitoring, "启用磁盘监控").changed() {
+                    if ui.checkbox(&mut config.monitoring.enable_gpu_monitoring, t("启用GPU监控")).changed() {
+                        changed = true;
+                    }
+                    if ui.checkbox(&mut config.monitoring.enable_network_monitoring, t("启用网络监控")).changed() {
+                        changed = true;
+                    }
+                    if ui.checkbox(&mut config.monitoring.enable_disk_monitoring, t("启用磁盘监控")).changed() {
+                        changed = true;
+                    }
+
+                    ui.horizontal(|ui| {
+                        ui.label(t("进程采集:"));
+                        for (mode, label) in [
+                            (ProcessCollectionMode::Always, t("始终")),
+                            (ProcessCollectionMode::OnDemand, t("按需")),
+                            (ProcessCollectionMode::Never, t("从不")),
+                        ] {
+                            if ui.radio_value(&mut config.monitoring.process_collection_mode, mode, label).changed() {
+                                changed = true;
+                            }
+                        }
+                    }).response.on_hover_text(t("进程采集开销较大，按需模式仅在进程页可见时采集"));
+
+                    ui.horizontal(|ui| {
+                        ui.label(t("进程数上限:"));
+                        if ui.add(egui::Slider::new(&mut config.monitoring.process_list_limit, 0..=1000)).changed() {
+                            changed = true;
+                        }
+                    }).response.on_hover_text(t("0表示采集全部进程"));
+
+                    ui.horizontal(|ui| {
+                        ui.label(t("进程选取依据:"));
+                        egui::ComboBox::from_id_salt("process_sort_by")
+                            .selected_text(config.monitoring.process_sort_by.label())
+                            .show_ui(ui, |ui| {
+                                for key in [
+                                    ProcessSortKey::Cpu,
+                                    ProcessSortKey::Memory,
+                                    ProcessSortKey::Pid,
+                                    ProcessSortKey::Name,
+                                    ProcessSortKey::Age,
+                                ] {
+                                    if ui.selectable_value(&mut config.monitoring.process_sort_by, key, key.label()).changed() {
+                                        changed = true;
+                                    }
+                                }
+                            });
+                    }).response.on_hover_text(t("CPU、内存和运行时间取最大的进程，PID和名称按升序取前面的进程"));
+
+                    if ui.checkbox(&mut config.alerts.startup_check, t("启动时检查磁盘和内存")).changed() {
+                        changed = true;
+                    }
+
+                    ui.horizontal(|ui| {
+                        ui.label(t("通知聚合窗口:"));
+                        if ui.add(egui::Slider::new(&mut config.alerts.notification_window_secs, 0..=300).suffix(t(" 秒"))).changed() {
+                            changed = true;
+                        }
+                    }).response.on_hover_text(t("窗口内的多条告警合并为一条通知"));
+
+                    ui.horizontal(|ui| {
+                        ui.label(t("通知冷却时间:"));
+                        if ui.add(egui::Slider::new(&mut config.alerts.notification_cooldown_secs, 0..=3600).suffix(t(" 秒"))).changed() {
+                            changed = true;
+                        }
+                    });
+
+                    if ui.checkbox(&mut config.monitoring.fast_start, t("快速启动（首次仅采集CPU和内存）")).changed() {
+                        changed = true;
+                    }
+
+                    if ui.checkbox(&mut config.monitoring.suspend_when_minimized, t("最小化时暂停磁盘和进程采集"))
+                        .on_hover_text(t("CPU和内存仍会采集，告警照常触发"))
+                        .changed()
+                    {
                         changed = true;
                     }
                 });
                 
                 // UI设置
-                ui.collapsing("界面设置", |ui| {
+                ui.collapsing(t("界面设置"), |ui| {
+                    ui.horizontal(|ui| {
+                        ui.label(t("语言:"));
+                        for language in Language::ALL {
+                            if ui.radio_value(&mut config.ui.language, language, language.label()).changed() {
+                                changed = true;
+                            }
+                        }
+                    });
+
                     ui.horizontal(|ui| {
-                        ui.label("字体大小:");
+                        ui.label(t("字体大小:"));
                         if ui.add(egui::Slider::new(&mut config.ui.font_size, 8.0..=24.0)).changed() {
                             changed = true;
                         }
                     });
                     
-                    if ui.checkbox(&mut config.ui.show_grid, "显示网格").changed() {
+                    if ui.checkbox(&mut config.ui.show_grid, t("显示网格")).changed() {
                         changed = true;
                     }
-                });
 
-                if changed {
-                    // 发送消息而不是直接调用 config_manager
-                    let _ = sender.send(AppMessage::ApplyConfig(config));
-                }
-                
-                ui.separator();
-                ui.horizontal(|ui| {
-                    if ui.button("关闭").clicked() {
-                        app_state.show_settings = false;
+                    if ui.checkbox(&mut config.ui.chart_show_points, t("图表显示数据点")).changed() {
+                        changed = true;
                     }
-                    
-                    if ui.button("重置为默认").clicked() {
-                        let _ = sender.send(AppMessage::ApplyConfig(AppConfig::default()));
+
+                    ui.horizontal(|ui| {
+                        ui.label(t("图表线条宽度:"));
+                        if ui.add(egui::Slider::new(&mut config.ui.chart_line_width, 0.5..=5.0)).changed() {
+                            changed = true;
+                        }
+                    });
+
+                    if ui.checkbox(&mut config.ui.show_cpu_busy_cores, t("显示CPU繁忙核心数")).changed() {
+                        changed = true;
                     }
-                });
-            });
-        
-        if !open {
-            app_state.show_settings = false;
-        }
-    }
-    
+
+                    if ui.checkbox(&mut config.ui.show_cpu_brand, t("显示CPU型号")).changed() {
+                        changed = true;
+                    }
+
+                    ui.horizontal(|ui| {
+                        ui.label(t("核心频率单位:"));
+                        for (unit, label) in [
+                            (FrequencyUnit::Auto, t("自动")),
+                            (FrequencyUnit::MHz, "MHz"),
+                            (FrequencyUnit::GHz, "GHz"),
+                        ] {
+                            if ui.radio_value(&mut config.ui.core_frequency_unit, unit, label).changed() {
+                                changed = true;
+                            }
+                        }
+                    });
+
+                    if ui.checkbox(&mut config.ui.idle_core_dimming.enabled, t("淡化空闲核心")).changed() {
+                        changed = true;
+                    }
+                    ui.add_enabled_ui(config.ui.idle_core_dimming.enabled, |ui| {
+                        ui.horizontal(|ui| {
+                            ui.label(t("空闲阈值 (%):"));
+                            if ui.add(egui::Slider::new(&mut config.ui.idle_core_dimming.threshold, 0.0..=50.0)).changed() {
+                                changed = true;
+                            }
+                        });
+                        ui.horizontal(|ui| {
+                            ui.label(t("淡化不透明度:"));
+                            if ui.add(egui::Slider::new(&mut config.ui.idle_core_dimming.opacity, 0.1..=1.0)).changed() {
+                                changed = true;
+                            }
+                        });
+                    });
+
+                    if ui.checkbox(&mut config.ui.show_summary_strip, t("显示摘要条")).changed() {
+                        changed = true;
+                    }
+
+                    if ui.checkbox(&mut config.window.always_on_top, t("窗口置顶")).changed() {
+                        changed = true;
+                    }
+
+                    if ui.checkbox(&mut config.ui.process_multi_select, t("进程表支持多选和批量结束")).changed() {
+                        changed = true;
+                    }
+
+                    if ui.checkbox(&mut config.ui.show_load_score, t("在概览页显示负载评分")).changed() {
+                        changed = true;
+                    }
+                    ui.add_enabled_ui(config.ui.show_load_score, |ui| {
+                        ui.horizontal(|ui| {
+                            ui.label(t("负载评分小数位数:"));
+                            if ui.add(egui::Slider::new(&mut config.ui.load_score_decimals, 0..=2)).changed() {
+                                changed = true;
+                            }
+                        });
+                    });
+
+                    if ui.checkbox(&mut config.ui.show_source_health, t("在侧边栏显示数据来源健康状态")).changed() {
+                        changed = true;
+                    }
+                    ui.add_enabled_ui(config.ui.show_source_health, |ui| {
+                        ui.horizontal(|ui| {
+                            ui.label(t("数据过期时间:"));
+                            if ui.add(egui::Slider::new(&mut config.ui.source_stale_secs, 1..=600).suffix(t(" 秒"))).changed() {
+                                changed = true;
+                            }
+                        });
+                    });
+
+                    ui.label(t("图表颜色:"));
+                    egui::Grid::new("chart_colors").num_columns(4).show(ui, |ui| {
+                        ui.label("");
+                        ui.label("CPU");
+                        ui.label(t("内存"));
+                        ui.label(t("磁盘"));
+                        ui.end_row();
+
+                        for (label, colors) in [(t("暗色主题"), &mut config.ui.chart_colors), (t("亮色主题"), &mut config.ui.chart_colors_light)] {
+                            ui.label(label);
+                            for color in [&mut colors.cpu_color, &mut colors.memory_color, &mut colors.disk_color] {
+                                if ui.color_edit_button_rgb(color).changed() {
+                                    changed = true;
+                                }
+                            }
+                            ui.end_row();
+                        }
+                    });
+
+                    ui.horizontal(|ui| {
+                        ui.label(t("图表自动缩放:"));
+                        if ui.checkbox(&mut config.ui.cpu_chart_autoscale, "CPU").changed() {
+                            changed = true;
+                        }
+                        if ui.checkbox(&mut config.ui.memory_chart_autoscale, t("内存")).changed() {
+                            changed = true;
+                        }
+                    }).response.on_hover_text(t("按数据范围调整Y轴，使用率较低时也能看清变化"));
+
+                    if ui.checkbox(&mut config.ui.show_memory_breakdown, t("显示内存分解")).changed() {
+                        changed = true;
+                    }
+
+                    if ui.checkbox(&mut config.ui.network_show_total, t("显示网络总计")).changed() {
+                        changed = true;
+                    }
+
+                    if ui.checkbox(&mut config.ui.network_total_include_virtual, t("网络总计包含虚拟接口"))
+                        .on_hover_text(t("回环、容器和VPN接口的流量通常也经过物理接口，计入会重复统计"))
+                        .changed()
+                    {
+                        changed = true;
+                    }
+
+                    if ui.checkbox(&mut config.ui.clamp_cpu_usage, t("CPU使用率限制在100%以内"))
+                        .on_hover_text(t("关闭后显示原始读数，采样误差可能使其略超100%"))
+                        .changed()
+                    {
+                        changed = true;
+                    }
+
+                    if ui.checkbox(&mut config.ui.disk_show_free_space, t("磁盘按剩余空间显示")).changed() {
+                        changed = true;
+                    }
+
+                    ui.label(t("界面更新阈值 (百分点):"));
+                    for (value, label) in [
+                        (&mut config.ui.change_threshold.cpu, "CPU"),
+                        (&mut config.ui.change_threshold.memory, t("内存")),
+                        (&mut config.ui.change_threshold.disk, t("磁盘")),
+                    ] {
+                        ui.horizontal(|ui| {
+                            ui.label(label);
+                            if ui.add(egui::Slider::new(value, 0.0..=10.0)).changed() {
+                                changed = true;
+                            }
+                        });
+                    }
+
+                    ui.horizontal(|ui| {
+                        ui.label(t("侧边栏平均样本数:"));
+                        if ui.add(egui::Slider::new(&mut config.ui.summary_average_samples, 1..=30)).changed() {
+                            changed = true;
+                        }
+                    });
+
+                    ui.horizontal(|ui| {
+                        ui.label(t("预测最低置信度:"));
+                        if ui.add(egui::Slider::new(&mut config.ui.prediction_min_confidence, 0.0..=1.0)).changed() {
+                            changed = true;
+                        }
+                    });
+
+                    ui.horizontal(|ui| {
+                        ui.label(t("小数位数:"));
+                        if ui.add(egui::Slider::new(&mut config.ui.format.decimals, 0..=3)).changed() {
+                            changed = true;
+                        }
+                    });
+
+                    ui.horizontal(|ui| {
+                        ui.label(t("字节单位:"));
+                        for (units, label) in [
+                            (ByteUnits::Binary, t("1024进制 (KiB)")),
+                            (ByteUnits::Decimal, t("1000进制 (KB)")),
+                        ] {
+                            if ui.radio_value(&mut config.ui.format.byte_units, units, label).changed() {
+                                changed = true;
+                            }
+                        }
+                    });
+
+                    ui.horizontal(|ui| {
+                        ui.label(t("概览布局:"));
+                        for (layout, label) in [
+                            (OverviewLayout::Auto, t("自动")),
+                            (OverviewLayout::Compact, t("紧凑")),
+                            (OverviewLayout::Detailed, t("详细")),
+                        ] {
+                            if ui.radio_value(&mut config.ui.overview_layout, layout, label).changed() {
+                                changed = true;
+                            }
+                        }
+                    });
+
+                    if ui.checkbox(&mut config.ui.overview_use_gauges, t("概览以环形图显示CPU和内存")).changed() {
+                        changed = true;
+                    }
+
+                    ui.horizontal(|ui| {
+                        ui.label(t("系统时间显示:"));
+                        for (display, label) in [
+                            (BootTimeDisplay::Uptime, t("运行时间")),
+                            (BootTimeDisplay::BootTime, t("启动时间")),
+                            (BootTimeDisplay::Both, t("两者")),
+                        ] {
+                            if ui.radio_value(&mut config.ui.boot_time_display, display, label).changed() {
+                                changed = true;
+                            }
+                        }
+                    });
+
+                    ui.horizontal(|ui| {
+                        ui.label(t("日期时间格式:"));
+                        if ui.text_edit_singleline(&mut config.ui.datetime_format).changed() {
+                            changed = true;
+                        }
+                    });
+
+                    ui.horizontal(|ui| {
+                        ui.label(t("复制标签页快捷键:"));
+                        if ui.text_edit_singleline(&mut config.ui.copy_tab_shortcut).changed() {
+                            changed = true;
+                        }
+                    }).response.on_hover_text(t("如 Ctrl+Shift+C，留空禁用"));
+
+                    ui.horizontal(|ui| {
+                        ui.label(t("精确字节数:"));
+                        egui::ComboBox::from_id_salt("raw_bytes_display")
+                            .selected_text(match config.ui.raw_bytes_display {
+                                RawBytesDisplay::Off => t("不显示"),
+                                RawBytesDisplay::Inline => t("内联显示"),
+                                RawBytesDisplay::Tooltip => t("悬停显示"),
+                            })
+                            .show_ui(ui, |ui| {
+                                for (mode, label) in [
+                                    (RawBytesDisplay::Off, t("不显示")),
+                                    (RawBytesDisplay::Inline, t("内联显示")),
+                                    (RawBytesDisplay::Tooltip, t("悬停显示")),
+                                ] {
+                                    if ui.selectable_value(&mut config.ui.raw_bytes_display, mode, label).changed() {
+                                        changed = true;
+                                    }
+                                }
+                            });
+                    });
+                });
+
+                // 导出设置
+                ui.collapsing(t("导出设置"), |ui| {
+                    ui.horizontal(|ui| {
+                        ui.label(t("导出目录:"));
+                        let mut directory = config.export.directory
+                            .as_ref()
+                            .map(|path| path.display().to_string())
+                            .unwrap_or_default();
+                        if ui.text_edit_singleline(&mut directory)
+                            .on_hover_text(t("留空时导出到文档目录"))
+                            .changed()
+                        {
+                            let directory = directory.trim();
+                            config.export.directory = (!directory.is_empty()).then(|| directory.into());
+                            changed = true;
+                        }
+                    });
+
+                    ui.horizontal(|ui| {
+                        ui.label(t("文件名模板:"));
+                        if ui.text_edit_singleline(&mut config.export.filename_template)
+                            .on_hover_text(t("可用占位符: {hostname}、{timestamp}、{type}"))
+                            .changed()
+                        {
+                            changed = true;
+                        }
+                    });
+                });
+
+                draft.dirty |= changed;
+
+                // 拖动滑块或编辑输入框期间只更新草稿，松开或完成输入后再校验并应用
+                let editing = ctx.dragged_id().is_some() || ctx.memory(|memory| memory.focused().is_some());
+                if draft.dirty && !editing {
+                    match draft.config.validate() {
+                        Ok(()) => {
+                            // 发送消息而不是直接调用 config_manager
+                            let _ = sender.send(AppMessage::ApplyConfig(draft.config.clone()));
+                            draft.dirty = false;
+                            draft.error = None;
+                        }
+                        Err(e) => draft.error = Some(e.to_string()),
+                    }
+                }
+
+                if let Some(ref error) = draft.error {
+                    ui.separator();
+                    ui.colored_label(self.state.color_scheme.error, format!("⚠ {}: {}", t("设置未应用"), error));
+                }
+                
+                ui.separator();
+                let mut discard = false;
+                ui.horizontal(|ui| {
+                    if ui.button(t("关闭")).clicked() {
+                        app_state.show_settings = false;
+                    }
+
+                    if draft.error.is_some() && ui.button(t("撤销未应用的修改")).clicked() {
+                        discard = true;
+                    }
+                    
+                    if ui.button(t("重置为默认")).clicked() {
+                        let _ = sender.send(AppMessage::ApplyConfig(AppConfig::default()));
+                        discard = true;
+                    }
+                });
+
+                if !discard {
+                    self.settings_draft = Some(draft);
+                }
+            });
+        
+        if !open {
+            app_state.show_settings = false;
+        }
+        // 关闭窗口时丢弃草稿，下次打开时从已应用的配置重新开始
+        if !app_state.show_settings {
+            self.settings_draft = None;
+        }
+    }
+    
+    /// 渲染告警历史窗口
+    fn render_alert_history_window(&mut self, ctx: &egui::Context, app_state: &mut AppState, sender: &mpsc::UnboundedSender<AppMessage>) {
+        let mut open = app_state.show_alert_history;
+        egui::Window::new(t("告警历史"))
+            .open(&mut open)
+            .default_width(450.0)
+            .default_height(300.0)
+            .resizable(true)
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label(format!("{}: {}", t("告警总数"), app_state.alert_history.len()));
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        if ui.button(t("清空")).clicked() {
+                            let _ = sender.send(AppMessage::ClearAlerts);
+                        }
+                    });
+                });
+                ui.separator();
+
+                if app_state.alert_history.is_empty() {
+                    ui.label(t("暂无告警"));
+                    return;
+                }
+
+                egui::ScrollArea::vertical().show(ui, |ui| {
+                    for alert in app_state.alert_history.iter().rev() {
+                        ui.horizontal(|ui| {
+                            let color = match alert.level {
+                                AlertLevel::Warning => self.state.color_scheme.warning,
+                                AlertLevel::Critical => self.state.color_scheme.error,
+                            };
+                            ui.colored_label(color, format!("● {}", t(alert.level.description())));
+                            ui.label(alert.timestamp.with_timezone(&chrono::Local).format("%H:%M:%S").to_string());
+                            ui.label(alert.message());
+
+                            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                                if ui.small_button(t("查看")).clicked() {
+                                    let _ = sender.send(AppMessage::SwitchTab(TabType::for_alert(alert.kind)));
+                                }
+                            });
+                        });
+                    }
+                });
+            });
+
+        if !open {
+            let _ = sender.send(AppMessage::HideAlertHistory);
+        }
+    }
+
+    /// 渲染事件日志窗口，按级别着色列出错误和告警
+    fn render_event_log_window(&mut self, ctx: &egui::Context, app_state: &mut AppState, sender: &mpsc::UnboundedSender<AppMessage>) {
+        let mut open = app_state.show_event_log;
+        egui::Window::new(t("事件日志"))
+            .open(&mut open)
+            .default_width(500.0)
+            .default_height(300.0)
+            .resizable(true)
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label(format!("{}: {}", t("事件总数"), app_state.event_log.len()));
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        if ui.button(t("清空")).clicked() {
+                            let _ = sender.send(AppMessage::ClearEventLog);
+                        }
+                    });
+                });
+                ui.separator();
+
+                if app_state.event_log.is_empty() {
+                    ui.label(t("暂无事件"));
+                    return;
+                }
+
+                egui::ScrollArea::vertical().auto_shrink([false, false]).show(ui, |ui| {
+                    for entry in app_state.event_log.iter().rev() {
+                        ui.horizontal(|ui| {
+                            let color = match entry.level {
+                                LogLevel::Warning => self.state.color_scheme.warning,
+                                LogLevel::Critical | LogLevel::Error => self.state.color_scheme.error,
+                            };
+                            ui.colored_label(color, format!("● {}", entry.level.description()));
+                            ui.label(entry.timestamp.with_timezone(&chrono::Local).format("%m-%d %H:%M:%S").to_string());
+                            ui.label(&entry.message);
+                        });
+                    }
+                });
+            });
+
+        if !open {
+            let _ = sender.send(AppMessage::HideEventLog);
+        }
+    }
+
+    /// 渲染告警通知窗口
+    fn render_notification_window(&mut self, ctx: &egui::Context, app_state: &AppState, sender: &mpsc::UnboundedSender<AppMessage>) {
+        let Some(notification) = &app_state.notification else {
+            return;
+        };
+
+        let mut open = true;
+        egui::Window::new(t("告警通知"))
+            .open(&mut open)
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::RIGHT_BOTTOM, egui::vec2(-16.0, -40.0))
+            .show(ctx, |ui| {
+                ui.colored_label(self.state.color_scheme.warning, format!("⚠ {}", notification));
+                ui.horizontal(|ui| {
+                    if ui.button(t("查看告警历史")).clicked() {
+                        let _ = sender.send(AppMessage::ShowAlertHistory);
+                        let _ = sender.send(AppMessage::DismissNotification);
+                    }
+                    if ui.button(t("关闭")).clicked() {
+                        let _ = sender.send(AppMessage::DismissNotification);
+                    }
+                });
+            });
+        if !open {
+            let _ = sender.send(AppMessage::DismissNotification);
+        }
+    }
+
+    /// 渲染启动健康检查警告窗口
+    fn render_startup_warning_window(&mut self, ctx: &egui::Context, app_state: &mut AppState) {
+        let mut open = true;
+        egui::Window::new(t("启动健康检查"))
+            .open(&mut open)
+            .default_width(400.0)
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_TOP, egui::vec2(0.0, 80.0))
+            .show(ctx, |ui| {
+                ui.colored_label(self.state.color_scheme.error, t("⚠ 检测到以下严重问题："));
+                ui.separator();
+
+                for alert in &app_state.startup_warnings {
+                    ui.colored_label(self.state.color_scheme.error, alert.message());
+                }
+
+                ui.separator();
+                if ui.button(t("知道了")).clicked() {
+                    app_state.startup_warnings.clear();
+                }
+            });
+        if !open {
+            app_state.startup_warnings.clear();
+        }
+    }
+
     /// 渲染关于窗口
     fn render_about_window(&mut self, ctx: &egui::Context, app_state: &mut AppState) {
         let mut open = app_state.show_about;
-        egui::Window::new("关于")
+        egui::Window::new(t("关于"))
             .open(&mut open)
             .default_width(350.0)
             .default_height(250.0)
             .resizable(false)
             .show(ctx, |ui| {
                 ui.vertical_centered(|ui| {
-                    ui.heading("系统监控工具");
-                    ui.label("版本 0.1.0");
+                    ui.heading(t("系统监控工具"));
+                    ui.label(format!("{} 0.1.0", t("版本")));
                     ui.separator();
                     
-                    ui.label("基于Rust和egui构建的实时系统监控工具");
-                    ui.label("提供CPU、内存、磁盘等系统信息的实时监控");
+                    ui.label(t("基于Rust和egui构建的实时系统监控工具"));
+                    ui.label(t("提供CPU、内存、磁盘等系统信息的实时监控"));
                     
                     ui.separator();
                     
                     ui.horizontal(|ui| {
-                        ui.label("运行时间:");
-                        ui.label(format!("{:.1}秒", app_state.start_time.elapsed().as_secs_f32()));
+                        ui.label(t("运行时间:"));
+                        ui.label(crate::ui::UiUtils::format_duration(app_state.start_time.elapsed().as_secs()));
                     });
                     
                     if let Some(ref snapshot) = self.system_data {
                         ui.horizontal(|ui| {
-                            ui.label("系统状态:");
+                            ui.label(t("系统状态:"));
+                            let status = snapshot.get_health_status();
                             ui.colored_label(
-                                egui::Color32::from_rgb(
-                                    (snapshot.get_health_status().color()[0] * 255.0) as u8,
-                                    (snapshot.get_health_status().color()[1] * 255.0) as u8,
-                                    (snapshot.get_health_status().color()[2] * 255.0) as u8,
-                                ),
-                                snapshot.get_health_status().description()
+                                self.state.color_scheme.health_color(status),
+                                status.description()
                             );
                         });
                     }
                     
                     ui.separator();
                     
-                    if ui.button("关闭").clicked() {
+                    if ui.button(t("关闭")).clicked() {
                         app_state.show_about = false;
                     }
                 });
@@ -417,80 +1609,265 @@ impl OverviewTabRenderer {
     pub fn new() -> Self {
         Self
     }
-}
 
-impl TabRenderer for OverviewTabRenderer {
-    fn render(&mut self, ui: &mut egui::Ui, system_data: Option<&SystemSnapshot>) {
-        if let Some(data) = system_data {
-            ui.columns(2, |columns| {
-                // 左列 - CPU和内存
-                columns[0].heading("性能概览");
-                columns[0].separator();
-                
-                crate::ui::UiUtils::progress_bar(
-                    &mut columns[0], 
-                    data.cpu.global_usage, 
-                    100.0, 
-                    "CPU使用率"
-                );
-                
-                crate::ui::UiUtils::progress_bar(
-                    &mut columns[0], 
-                    data.memory.usage_percent as f32, 
-                    100.0, 
-                    "内存使用率"
-                );
-                
-                // 右列 - 系统信息
-                columns[1].heading("系统信息");
-                columns[1].separator();
-                
-                crate::ui::UiUtils::metric_display(
-                    &mut columns[1], 
-                    "操作系统", 
-                    &format!("{} {}", data.system.os_name, data.system.os_version),
-                    None
-                );
-                
-                crate::ui::UiUtils::metric_display(
-                    &mut columns[1], 
-                    "主机名", 
-                    &data.system.hostname,
-                    None
-                );
-                
-                crate::ui::UiUtils::metric_display(
-                    &mut columns[1], 
-                    "运行时间", 
-                    &crate::ui::UiUtils::format_duration(data.system.uptime),
-                    None
+    /// 紧凑布局：环形图 + 健康状态，适合小窗口
+    fn render_compact(&self, ui: &mut egui::Ui, data: &SystemSnapshot, context: &RenderContext<'_>) {
+        Self::render_gauges(ui, data);
+
+        ui.separator();
+        self.render_health(ui, data, context);
+    }
+
+    /// 并排的CPU和内存使用率环形图，窄窗口下随可用宽度缩小
+    fn render_gauges(ui: &mut egui::Ui, data: &SystemSnapshot) {
+        let spacing = ui.spacing().item_spacing.x;
+        let size = ((ui.available_width() - spacing) / 2.0).clamp(GAUGE_MIN_SIZE, GAUGE_MAX_SIZE);
+        ui.horizontal(|ui| {
+            let gauges = [
+                ("CPU", data.cpu_usage()),
+                (t("内存"), data.memory_usage().map(|usage| usage as f32)),
+            ];
+            for (label, value) in gauges.into_iter().filter_map(|(label, value)| Some((label, value?))) {
+                ui.vertical(|ui| {
+                    crate::ui::charts::DonutChart::new(value, 100.0, crate::ui::UiUtils::get_usage_color(value as f64))
+                        .render(ui, egui::vec2(size, size));
+                    ui.label(label);
+                });
+            }
+        });
+    }
+
+    /// 健康状态，按配置附带数值负载评分
+    fn render_health(&self, ui: &mut egui::Ui, data: &SystemSnapshot, context: &RenderContext<'_>) {
+        let status = data.get_health_status();
+        let color = context.colors.health_color(status);
+        ui.horizontal(|ui| {
+            ui.colored_label(color, format!("● {}", status.description()));
+            if context.config.ui.show_load_score {
+                let score = data.calculate_system_load_score();
+                ui.add(
+                    egui::ProgressBar::new(score.clamp(0.0, 100.0) / 100.0)
+                        .desired_width(80.0)
+                        .fill(color),
                 );
+                ui.label(format!(
+                    "{} {}",
+                    t("负载评分"),
+                    crate::ui::UiUtils::format_load_score(score, context.config.ui.load_score_decimals)
+                ));
+            }
+        });
+    }
+
+    /// 详细布局：性能统计 + 负载预测 + 系统信息
+    fn render_detailed(&self, ui: &mut egui::Ui, data: &SystemSnapshot, context: &RenderContext<'_>) {
+        ui.columns(2, |columns| {
+            // 左列 - CPU和内存
+            columns[0].heading(t("性能概览"));
+            columns[0].separator();
+            
+            if context.config.ui.overview_use_gauges {
+                Self::render_gauges(&mut columns[0], data);
+            } else {
+                if let Some(cpu) = data.cpu_usage() {
+                    crate::ui::UiUtils::progress_bar(&mut columns[0], cpu, 100.0, t("CPU使用率"));
+                }
+                if let Some(memory) = data.memory_usage() {
+                    crate::ui::UiUtils::progress_bar(&mut columns[0], memory as f32, 100.0, t("内存使用率"));
+                }
+            }
+
+            // 多块磁盘时显示使用率最高的一块
+            match data.disks.iter().map(|disk| disk.usage_percent).reduce(f64::max) {
+                Some(usage) => {
+                    crate::ui::UiUtils::progress_bar(&mut columns[0], usage as f32, 100.0, t("磁盘使用率"));
+                }
+                None => {
+                    columns[0].weak(t("无可用磁盘信息"));
+                }
+            }
+
+            columns[0].add_space(8.0);
+            self.render_health(&mut columns[0], data, context);
+
+            // 负载预测，置信度不足时不显示预测值
+            columns[0].add_space(8.0);
+            columns[0].horizontal(|ui| {
+                ui.strong(format!("{} ({})", t("负载预测"), crate::ui::UiUtils::format_duration(PREDICTION_WINDOW.as_secs())));
+                if let Some(trend) = context.load_trend {
+                    crate::ui::UiUtils::confidence_badge(ui, trend.confidence);
+                }
             });
+            match context.load_trend {
+                Some(trend) if trend.is_confident(context.config.ui.prediction_min_confidence) => {
+                    crate::ui::UiUtils::metric_display(
+                        &mut columns[0],
+                        "CPU",
+                        &crate::ui::UiUtils::format_percentage(trend.cpu_predicted as f64),
+                        Some(crate::ui::UiUtils::get_usage_color(trend.cpu_predicted as f64))
+                    );
+                    crate::ui::UiUtils::metric_display(
+                        &mut columns[0],
+                        t("内存"),
+                        &crate::ui::UiUtils::format_percentage(trend.memory_predicted),
+                        Some(crate::ui::UiUtils::get_usage_color(trend.memory_predicted))
+                    );
+                }
+                _ => {
+                    columns[0].weak(t("数据不足以预测"));
+                }
+            }
+            
+            // 右列 - 系统信息
+            columns[1].heading(t("系统信息"));
+            columns[1].separator();
+            
+            crate::ui::UiUtils::metric_display(
+                &mut columns[1], 
+                t("操作系统"), 
+                &format!("{} {}", data.system.os_name, data.system.os_version),
+                None
+            );
+            
+            crate::ui::UiUtils::metric_display(
+                &mut columns[1], 
+                t("主机名"), 
+                &data.system.hostname,
+                None
+            );
+
+            if let Some(load) = data.system.load_average {
+                crate::ui::UiUtils::metric_display(&mut columns[1], t("平均负载 (1/5/15分钟)"), &load.display(), None);
+            }
+            
+            for (label, value) in crate::ui::UiUtils::boot_time_entries(
+                &data.system,
+                context.config.ui.boot_time_display,
+                &context.config.ui.datetime_format,
+            ) {
+                crate::ui::UiUtils::metric_display(&mut columns[1], label, &value, None);
+            }
+        });
+    }
+}
+
+impl TabRenderer for OverviewTabRenderer {
+    fn render(&mut self, ui: &mut egui::Ui, context: &RenderContext<'_>) {
+        if let Some(data) = context.system_data {
+            match ResponsiveLayout::overview_layout(context.config.ui.overview_layout, ui.available_width()) {
+                OverviewLayout::Compact => self.render_compact(ui, data, context),
+                _ => self.render_detailed(ui, data, context),
+            }
         } else {
             ui.centered_and_justified(|ui| {
-                ui.label("正在加载系统数据...");
+                ui.label(t("正在加载系统数据..."));
             });
         }
     }
     
     fn title(&self) -> &str {
-        "概览"
+        t("概览")
+    }
+
+    fn to_clipboard_text(&self, context: &RenderContext<'_>) -> Option<String> {
+        let data = context.system_data?;
+        let mut text = format!("{}: {}\n", t("主机"), data.system.hostname);
+        if let Some(cpu) = data.cpu_usage() {
+            text.push_str(&format!("{}: {}\n", t("CPU使用率"), crate::ui::UiUtils::format_percentage(cpu as f64)));
+        }
+        if let Some(memory) = data.memory_usage() {
+            text.push_str(&format!("{}: {}\n", t("内存使用率"), crate::ui::UiUtils::format_percentage(memory)));
+        }
+        for disk in &data.disks {
+            text.push_str(&format!(
+                "{} {}: {}\n",
+                t("磁盘"),
+                disk.mount_point,
+                crate::ui::UiUtils::format_percentage(disk.usage_percent)
+            ));
+        }
+        text.push_str(&format!("{} {}\n", t("系统状态:"), data.get_health_status().description()));
+        Some(text)
     }
 }
 
 /// CPU标签页渲染器
-pub struct CpuTabRenderer;
+pub struct CpuTabRenderer {
+    /// 历史图表的时间窗口
+    time_window: TimeWindow,
+    /// 核心详情以热力图而非列表显示
+    show_heatmap: bool,
+}
 
 impl CpuTabRenderer {
     pub fn new() -> Self {
-        Self
+        Self { time_window: TimeWindow::default(), show_heatmap: false }
+    }
+
+    /// 以进度条列表显示每个核心
+    fn render_core_list(ui: &mut egui::Ui, cpu: &crate::system::CpuInfo, context: &RenderContext<'_>) {
+        let dimming = &context.config.ui.idle_core_dimming;
+        for (i, core) in cpu.cores.iter().enumerate() {
+            ui.horizontal(|ui| {
+                // 空闲核心淡化显示，突出繁忙核心
+                let text_color = crate::ui::UiUtils::dim_idle_color(ui.visuals().text_color(), core.usage, dimming);
+                let fill = crate::ui::UiUtils::dim_idle_color(
+                    crate::ui::UiUtils::get_usage_color(core.usage as f64),
+                    core.usage,
+                    dimming,
+                );
+                ui.colored_label(text_color, format!("{} {}: ", t("核心"), i));
+                ui.add(egui::ProgressBar::new(core.usage / 100.0).fill(fill));
+                ui.colored_label(text_color, crate::ui::UiUtils::format_percentage(core.usage as f64));
+                ui.colored_label(text_color, format!(
+                    "@ {}",
+                    crate::ui::UiUtils::format_core_frequency(core.frequency, context.config.ui.core_frequency_unit)
+                ));
+            });
+        }
+    }
+
+    /// 以按使用率着色的方格网格显示每个核心，悬停显示详情
+    fn render_core_heatmap(ui: &mut egui::Ui, cpu: &crate::system::CpuInfo, context: &RenderContext<'_>) {
+        let spacing = ui.spacing().item_spacing.x;
+        let columns = ResponsiveLayout::calculate_columns(ui.available_width() + spacing, HEATMAP_CELL_SIZE + spacing);
+        for (row, chunk) in cpu.cores.chunks(columns).enumerate() {
+            ui.horizontal(|ui| {
+                for (column, core) in chunk.iter().enumerate() {
+                    let (rect, response) = ui.allocate_exact_size(egui::vec2(HEATMAP_CELL_SIZE, HEATMAP_CELL_SIZE), egui::Sense::hover());
+                    ui.painter().rect_filled(rect, 2.0, crate::ui::UiUtils::get_usage_color(core.usage as f64));
+                    response.on_hover_text(format!(
+                        "{} {} ({})\n{}: {}\n{}: {}",
+                        t("核心"),
+                        row * columns + column,
+                        core.name,
+                        t("使用率"),
+                        crate::ui::UiUtils::format_percentage(core.usage as f64),
+                        t("频率"),
+                        crate::ui::UiUtils::format_core_frequency(core.frequency, context.config.ui.core_frequency_unit)
+                    ));
+                }
+            });
+        }
     }
 }
 
 impl TabRenderer for CpuTabRenderer {
-    fn render(&mut self, ui: &mut egui::Ui, system_data: Option<&SystemSnapshot>) {
-        if let Some(data) = system_data {
-            ui.heading(format!("CPU信息 - {}核心", data.cpu.core_count));
+    fn render(&mut self, ui: &mut egui::Ui, context: &RenderContext<'_>) {
+        if context.system_data.is_some_and(|data| !data.cpu_collected) {
+            ui.centered_and_justified(|ui| {
+                ui.label(t("CPU监控未启用"));
+            });
+            return;
+        }
+        if let Some(data) = context.system_data {
+            if context.config.ui.show_cpu_brand {
+                ui.heading(data.cpu.brand_display());
+                crate::ui::UiUtils::metric_display(ui, t("厂商"), data.cpu.vendor_display(), None);
+            } else {
+                ui.heading(t("CPU信息"));
+            }
+            ui.label(data.cpu.core_count_display());
             ui.separator();
             
             // 全局CPU使用率
@@ -498,25 +1875,70 @@ impl TabRenderer for CpuTabRenderer {
                 ui, 
                 data.cpu.global_usage, 
                 100.0, 
-                "总体CPU使用率"
+                t("总体CPU使用率")
+            );
+
+            if context.config.ui.show_cpu_busy_cores {
+                ui.label(format!(
+                    "{}: {:.1} / {}",
+                    t("繁忙核心"),
+                    data.cpu.busy_cores(),
+                    data.cpu.core_count
+                ));
+            }
+
+            if let Some(load) = data.system.load_average {
+                crate::ui::UiUtils::metric_display(ui, t("平均负载 (1/5/15分钟)"), &load.display(), None);
+            }
+
+            ui.separator();
+
+            // CPU使用率历史
+            crate::ui::UiUtils::history_chart(
+                ui,
+                &context.metrics.get_cpu_history_timed(),
+                &mut self.time_window,
+                crate::ui::UiUtils::color_from_rgb(context.config.ui.chart_colors_for(ui.visuals().dark_mode).cpu_color),
+                context.config.ui.cpu_chart_autoscale,
+                context.config,
             );
             
             ui.separator();
             
             // CPU核心详情
-            ui.heading("CPU核心详情");
-            for (i, core) in data.cpu.cores.iter().enumerate() {
-                ui.horizontal(|ui| {
-                    ui.label(format!("核心 {}: ", i));
-                    ui.add(egui::ProgressBar::new(core.usage / 100.0)
-                        .fill(crate::ui::UiUtils::get_usage_color(core.usage as f64)));
-                    ui.label(format!("{:.1}%", core.usage));
-                    ui.label(format!("@ {}", crate::ui::UiUtils::format_frequency(core.frequency * 1_000_000)));
-                });
+            ui.horizontal(|ui| {
+                ui.heading(t("CPU核心详情"));
+                ui.selectable_value(&mut self.show_heatmap, false, t("列表"));
+                ui.selectable_value(&mut self.show_heatmap, true, t("热力图"));
+            });
+            if self.show_heatmap {
+                Self::render_core_heatmap(ui, &data.cpu, context);
+            } else {
+                Self::render_core_list(ui, &data.cpu, context);
+            }
+
+            // 温度传感器（按较慢的间隔采样）
+            if !data.sensors.is_empty() {
+                ui.separator();
+                ui.heading(t("温度"));
+                for sensor in &data.sensors {
+                    let (text, color) = match sensor.temperature {
+                        Some(temperature) => {
+                            let color = sensor.is_critical().then_some(context.colors.error);
+                            let text = match sensor.max {
+                                Some(max) => format!("{:.1} °C ({} {:.1} °C)", temperature, t("最高"), max),
+                                None => format!("{:.1} °C", temperature),
+                            };
+                            (text, color)
+                        }
+                        None => (t("未知").to_string(), None),
+                    };
+                    crate::ui::UiUtils::metric_display(ui, &sensor.label, &text, color);
+                }
             }
         } else {
             ui.centered_and_justified(|ui| {
-                ui.label("正在加载CPU数据...");
+                ui.label(t("正在加载CPU数据..."));
             });
         }
     }
@@ -524,4 +1946,26 @@ impl TabRenderer for CpuTabRenderer {
     fn title(&self) -> &str {
         "CPU"
     }
+
+    fn to_clipboard_text(&self, context: &RenderContext<'_>) -> Option<String> {
+        let data = context.system_data.filter(|data| data.cpu_collected)?;
+        let mut text = format!(
+            "{}: {}\n{}: {} ({})\n",
+            t("型号"),
+            data.cpu.brand_display(),
+            t("CPU使用率"),
+            crate::ui::UiUtils::format_percentage(data.cpu.global_usage as f64),
+            data.cpu.core_count_display()
+        );
+        for (i, core) in data.cpu.cores.iter().enumerate() {
+            text.push_str(&format!(
+                "{} {}: {} @ {}\n",
+                t("核心"),
+                i,
+                crate::ui::UiUtils::format_percentage(core.usage as f64),
+                crate::ui::UiUtils::format_core_frequency(core.frequency, context.config.ui.core_frequency_unit)
+            ));
+        }
+        Some(text)
+    }
 }
\ No newline at end of file
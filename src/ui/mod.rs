@@ -10,7 +10,18 @@ pub mod tabs;
 pub use manager::*;
 pub use components::*;
 
+use crate::config::{AlertThreshold, AppConfig, BootTimeDisplay, ChangeThreshold, DeltaConvention, FrequencyUnit, IdleCoreDimming, OverviewLayout, RawBytesDisplay, Theme, UiConfig};
+use crate::system::alert::AlertKind;
+use crate::system::{SystemHealthStatus, SystemInfo, SystemSnapshot};
+use crate::utils::{t, Formatter};
 use eframe::egui;
+use std::time::{Duration, Instant};
+
+/// 历史图表最多绘制的数据点数，更长的时间窗口会降采样
+const MAX_CHART_POINTS: usize = 120;
+
+/// 历史图表高度
+const HISTORY_CHART_HEIGHT: f32 = 150.0;
 
 /// 标签页类型
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -21,18 +32,29 @@ pub enum TabType {
     Disk,
     Process,
     Network,
+    Gpu,
 }
 
 impl TabType {
     /// 获取标签页名称
     pub fn name(&self) -> &'static str {
         match self {
-            TabType::Overview => "概览",
+            TabType::Overview => t("概览"),
             TabType::Cpu => "CPU",
-            TabType::Memory => "内存",
-            TabType::Disk => "磁盘",
-            TabType::Process => "进程",
-            TabType::Network => "网络",
+            TabType::Memory => t("内存"),
+            TabType::Disk => t("磁盘"),
+            TabType::Process => t("进程"),
+            TabType::Network => t("网络"),
+            TabType::Gpu => "GPU",
+        }
+    }
+
+    /// 获取告警类别对应的标签页
+    pub fn for_alert(kind: AlertKind) -> TabType {
+        match kind {
+            AlertKind::Cpu => TabType::Cpu,
+            AlertKind::Memory => TabType::Memory,
+            AlertKind::Disk => TabType::Disk,
         }
     }
 
@@ -45,6 +67,7 @@ impl TabType {
             TabType::Disk,
             TabType::Process,
             TabType::Network,
+            TabType::Gpu,
         ]
     }
 }
@@ -57,6 +80,26 @@ pub enum UiTheme {
 }
 
 impl UiTheme {
+    /// 根据配置解析主题，`Auto` 跟随系统偏好，无法获取系统主题时使用暗色主题
+    pub fn resolve(theme: &Theme, system: Option<egui::Theme>) -> Self {
+        match theme {
+            Theme::Light => UiTheme::Light,
+            Theme::Dark => UiTheme::Dark,
+            Theme::Auto => match system {
+                Some(egui::Theme::Light) => UiTheme::Light,
+                Some(egui::Theme::Dark) | None => UiTheme::Dark,
+            },
+        }
+    }
+
+    /// 主题对应的配色方案
+    pub fn color_scheme(&self) -> ColorScheme {
+        match self {
+            UiTheme::Light => ColorScheme::light(),
+            UiTheme::Dark => ColorScheme::dark(),
+        }
+    }
+
     /// 应用主题到egui上下文
     pub fn apply_to_context(&self, ctx: &egui::Context) {
         match self {
@@ -114,6 +157,15 @@ impl ColorScheme {
             text_secondary: egui::Color32::from_rgb(158, 158, 158),
         }
     }
+
+    /// 获取健康状态对应的颜色
+    pub fn health_color(&self, status: SystemHealthStatus) -> egui::Color32 {
+        match status {
+            SystemHealthStatus::Excellent | SystemHealthStatus::Good => self.success,
+            SystemHealthStatus::Fair | SystemHealthStatus::Poor => self.warning,
+            SystemHealthStatus::Critical => self.error,
+        }
+    }
 }
 
 /// UI状态
@@ -122,9 +174,21 @@ pub struct UiState {
     pub active_tab: TabType,
     pub show_sidebar: bool,
     pub sidebar_width: f32,
+    /// 侧边栏宽度最近一次变化的时间（尚未保存到配置时为 `Some`）
+    pub sidebar_resized_at: Option<Instant>,
     pub font_size: f32,
     pub theme: UiTheme,
     pub color_scheme: ColorScheme,
+    /// 是否跟随系统主题（手动选择亮色或暗色后为 `false`）
+    pub follow_system_theme: bool,
+    /// 窗口当前是否置顶
+    pub always_on_top: bool,
+    /// 窗口当前是否为紧凑模式布局
+    pub compact_mode: bool,
+    /// 进入紧凑模式前的标签页，退出时恢复
+    pub tab_before_compact: Option<TabType>,
+    /// 进入紧凑模式前的窗口尺寸，退出时恢复
+    pub window_size_before_compact: Option<egui::Vec2>,
 }
 
 impl Default for UiState {
@@ -133,9 +197,15 @@ impl Default for UiState {
             active_tab: TabType::Overview,
             show_sidebar: true,
             sidebar_width: 200.0,
+            sidebar_resized_at: None,
             font_size: 14.0,
             theme: UiTheme::Dark,
             color_scheme: ColorScheme::dark(),
+            follow_system_theme: false,
+            always_on_top: false,
+            compact_mode: false,
+            tab_before_compact: None,
+            window_size_before_compact: None,
         }
     }
 }
@@ -146,61 +216,120 @@ pub struct UiUtils;
 impl UiUtils {
     /// 格式化字节数为人类可读格式
     pub fn format_bytes(bytes: u64) -> String {
-        const UNITS: &[&str] = &["B", "KB", "MB", "GB", "TB"];
-        const THRESHOLD: f64 = 1024.0;
+        Formatter::bytes(bytes)
+    }
 
-        if bytes == 0 {
-            return "0 B".to_string();
-        }
+    /// 按指定进制格式化字节数：`binary` 为真时使用 "KiB/MiB/GiB"，否则使用1000进制的 "KB/MB/GB"
+    pub fn format_bytes_with_base(bytes: u64, binary: bool) -> String {
+        Formatter::bytes_with_base(bytes, binary)
+    }
+
+    /// 格式化字节数并附带精确字节数，如 "1.5 GiB (1610612736 bytes)"
+    pub fn format_bytes_detailed(bytes: u64) -> String {
+        format!("{} ({} bytes)", Self::format_bytes(bytes), bytes)
+    }
+
+    /// 格式化百分比
+    pub fn format_percentage(value: f64) -> String {
+        Formatter::percent(value)
+    }
 
-        let mut size = bytes as f64;
-        let mut unit_index = 0;
+    /// 格式化带符号的百分比变化，如 "+5.2%"、"-3.1%"，零值显示为 "0.0%"
+    pub fn format_signed_percent(value: f64) -> String {
+        let rounded = (value * 10.0).round() / 10.0;
+        if rounded == 0.0 {
+            "0.0%".to_string()
+        } else {
+            format!("{:+.1}%", rounded)
+        }
+    }
 
-        while size >= THRESHOLD && unit_index < UNITS.len() - 1 {
-            size /= THRESHOLD;
-            unit_index += 1;
+    /// 获取百分比变化对应的颜色，零值返回 `None` 表示使用中性颜色
+    pub fn signed_percent_color(value: f64, convention: DeltaConvention) -> Option<egui::Color32> {
+        let rounded = (value * 10.0).round() / 10.0;
+        if rounded == 0.0 {
+            return None;
         }
 
-        if unit_index == 0 {
-            format!("{} {}", bytes, UNITS[unit_index])
+        let is_good = match convention {
+            DeltaConvention::IncreaseIsGood => rounded > 0.0,
+            DeltaConvention::IncreaseIsBad => rounded < 0.0,
+        };
+
+        if is_good {
+            Some(egui::Color32::from_rgb(76, 175, 80))  // 绿色
         } else {
-            format!("{:.1} {}", size, UNITS[unit_index])
+            Some(egui::Color32::from_rgb(244, 67, 54))  // 红色
         }
     }
 
-    /// 格式化百分比
-    pub fn format_percentage(value: f64) -> String {
-        format!("{:.1}%", value)
+    /// 创建带符号和颜色的百分比变化标签
+    pub fn signed_percent_label(ui: &mut egui::Ui, value: f64, convention: DeltaConvention) -> egui::Response {
+        let text = Self::format_signed_percent(value);
+        match Self::signed_percent_color(value, convention) {
+            Some(color) => ui.colored_label(color, text),
+            None => ui.weak(text),
+        }
     }
 
     /// 格式化频率（Hz）
     pub fn format_frequency(hz: u64) -> String {
-        if hz >= 1_000_000_000 {
-            format!("{:.1} GHz", hz as f64 / 1_000_000_000.0)
-        } else if hz >= 1_000_000 {
-            format!("{:.1} MHz", hz as f64 / 1_000_000.0)
-        } else if hz >= 1_000 {
-            format!("{:.1} KHz", hz as f64 / 1_000.0)
+        Formatter::frequency(hz)
+    }
+
+    /// 按指定单位格式化核心频率（MHz）
+    pub fn format_core_frequency(mhz: u64, unit: FrequencyUnit) -> String {
+        match unit {
+            FrequencyUnit::Auto => Self::format_frequency(mhz * 1_000_000),
+            FrequencyUnit::MHz => format!("{} MHz", mhz),
+            FrequencyUnit::GHz => format!("{:.2} GHz", mhz as f64 / 1000.0),
+        }
+    }
+
+    /// 解析快捷键文本（如 `Ctrl+Shift+C`），无法识别或为空时返回 `None`
+    ///
+    /// `Ctrl` 与 `Cmd` 均映射为平台命令键，在macOS上对应Command。
+    pub fn parse_shortcut(text: &str) -> Option<egui::KeyboardShortcut> {
+        let mut parts: Vec<&str> = text.split('+').map(str::trim).collect();
+        let key = egui::Key::from_name(parts.pop()?)?;
+
+        let mut modifiers = egui::Modifiers::NONE;
+        for part in parts {
+            modifiers |= match part.to_ascii_lowercase().as_str() {
+                "ctrl" | "cmd" | "command" => egui::Modifiers::COMMAND,
+                "shift" => egui::Modifiers::SHIFT,
+                "alt" => egui::Modifiers::ALT,
+                _ => return None,
+            };
+        }
+        Some(egui::KeyboardShortcut::new(modifiers, key))
+    }
+
+    /// 空闲核心淡化：使用率低于阈值时按配置降低颜色不透明度
+    pub fn dim_idle_color(color: egui::Color32, usage: f32, dimming: &IdleCoreDimming) -> egui::Color32 {
+        if dimming.enabled && usage < dimming.threshold {
+            color.gamma_multiply(dimming.opacity)
         } else {
-            format!("{} Hz", hz)
+            color
         }
     }
 
     /// 格式化时间间隔
     pub fn format_duration(seconds: u64) -> String {
-        let days = seconds / 86400;
-        let hours = (seconds % 86400) / 3600;
-        let minutes = (seconds % 3600) / 60;
-        let secs = seconds % 60;
-
-        if days > 0 {
-            format!("{}天 {}小时 {}分钟", days, hours, minutes)
-        } else if hours > 0 {
-            format!("{}小时 {}分钟", hours, minutes)
-        } else if minutes > 0 {
-            format!("{}分钟 {}秒", minutes, secs)
-        } else {
-            format!("{}秒", secs)
+        Formatter::duration(seconds)
+    }
+
+    /// 按显示方式生成启动时间/运行时间条目（标签, 值）
+    pub fn boot_time_entries(
+        info: &SystemInfo,
+        display: BootTimeDisplay,
+        datetime_format: &str,
+    ) -> Vec<(&'static str, String)> {
+        let (boot_time, uptime) = info.boot_time_and_uptime(datetime_format);
+        match display {
+            BootTimeDisplay::Uptime => vec![(t("运行时间"), uptime)],
+            BootTimeDisplay::BootTime => vec![(t("启动时间"), boot_time)],
+            BootTimeDisplay::Both => vec![(t("启动时间"), boot_time), (t("运行时间"), uptime)],
         }
     }
 
@@ -214,6 +343,118 @@ impl UiUtils {
         }
     }
 
+    /// 按界面配置的线条样式创建线性图表
+    pub fn line_chart(max_points: usize, color: egui::Color32, config: &UiConfig) -> charts::LineChart {
+        charts::LineChart::new(max_points, color)
+            .with_line_width(config.chart_line_width)
+            .with_points(config.chart_show_points)
+    }
+
+    /// 将配置中的RGB颜色（各分量0-1）转换为egui颜色
+    pub fn color_from_rgb(rgb: [f32; 3]) -> egui::Color32 {
+        let [r, g, b] = rgb.map(|component| (component.clamp(0.0, 1.0) * 255.0).round() as u8);
+        egui::Color32::from_rgb(r, g, b)
+    }
+
+    /// 渲染带时间窗口选择按钮的历史图表
+    ///
+    /// 历史数据先按所选窗口截取，点数过多时降采样，保留的历史长度不受影响。
+    pub fn history_chart(
+        ui: &mut egui::Ui,
+        history: &[(Instant, f32)],
+        window: &mut charts::TimeWindow,
+        color: egui::Color32,
+        autoscale: bool,
+        config: &AppConfig,
+    ) -> egui::Response {
+        ui.horizontal(|ui| {
+            for option in charts::TimeWindow::ALL {
+                ui.selectable_value(window, option, option.label());
+            }
+        });
+
+        let windowed = window.slice(history, Instant::now());
+        let points = charts::downsample(windowed, MAX_CHART_POINTS);
+
+        // 降采样后相邻点的间隔按合并的样本数放大，避免误判为断档
+        let bucket_size = windowed.len().div_ceil(MAX_CHART_POINTS).max(1) as u32;
+        let sample_interval = Duration::from_millis(config.monitoring.refresh_interval_ms) * bucket_size;
+
+        let mut chart = Self::line_chart(points.len().max(2), color, &config.ui)
+            .with_gap_detection(sample_interval)
+            .with_autoscale(autoscale);
+        chart.set_timed_data(&points);
+        chart.render(ui, egui::vec2(ui.available_width(), HISTORY_CHART_HEIGHT))
+    }
+
+    /// 获取剩余空间百分比对应的颜色，剩余越少越红
+    pub fn get_free_space_color(free_percent: f64) -> egui::Color32 {
+        Self::get_usage_color(100.0 - free_percent.clamp(0.0, 100.0))
+    }
+
+    /// 创建按剩余空间着色的进度条
+    pub fn free_space_bar(ui: &mut egui::Ui, free_percent: f64, label: &str) -> egui::Response {
+        let free_percent = free_percent.clamp(0.0, 100.0);
+        ui.horizontal(|ui| {
+            ui.label(label);
+            ui.add(egui::ProgressBar::new((free_percent / 100.0) as f32).fill(Self::get_free_space_color(free_percent)));
+            ui.label(Self::format_percentage(free_percent));
+        }).response
+    }
+
+    /// 根据告警阈值生成图表参考线，与告警使用相同的警告/严重阈值和颜色
+    pub fn alert_threshold_lines(
+        threshold: &AlertThreshold,
+        colors: &ColorScheme,
+    ) -> Vec<(f32, egui::Color32, String)> {
+        vec![
+            (threshold.warning as f32, colors.warning, format!("{} {:.0}%", t("警告"), threshold.warning)),
+            (threshold.critical as f32, colors.error, format!("{} {:.0}%", t("严重"), threshold.critical)),
+        ]
+    }
+
+    /// 格式化系统负载评分，如 "42/100"
+    pub fn format_load_score(score: f32, decimals: usize) -> String {
+        format!("{:.*}/100", decimals, score.clamp(0.0, 100.0))
+    }
+
+    /// 创建置信度徽章，如 "置信度 72%"
+    pub fn confidence_badge(ui: &mut egui::Ui, confidence: f64) -> egui::Response {
+        egui::Frame::NONE
+            .fill(ui.visuals().faint_bg_color)
+            .stroke(ui.visuals().widgets.noninteractive.bg_stroke)
+            .corner_radius(8.0)
+            .inner_margin(egui::Margin::symmetric(6, 1))
+            .show(ui, |ui| {
+                ui.small(format!("{} {:.0}%", t("置信度"), (confidence * 100.0).clamp(0.0, 100.0)));
+            })
+            .response
+    }
+
+    /// 判断新快照相对当前显示的快照是否有足以刷新界面的变化
+    ///
+    /// 任一指标的变化超过对应阈值，或磁盘、进程数据的结构发生变化时返回 `true`。
+    pub fn is_significant_change(
+        threshold: &ChangeThreshold,
+        previous: &SystemSnapshot,
+        next: &SystemSnapshot,
+    ) -> bool {
+        let exceeds = |a: f64, b: f64, epsilon: f64| (a - b).abs() > epsilon;
+
+        if previous.disks.len() != next.disks.len()
+            || previous.processes.is_some() != next.processes.is_some()
+            || previous.cpu_collected != next.cpu_collected
+            || previous.memory_collected != next.memory_collected
+        {
+            return true;
+        }
+
+        exceeds(previous.cpu.global_usage as f64, next.cpu.global_usage as f64, threshold.cpu)
+            || exceeds(previous.memory.usage_percent, next.memory.usage_percent, threshold.memory)
+            || previous.disks.iter().zip(&next.disks)
+                .any(|(a, b)| exceeds(a.usage_percent, b.usage_percent, threshold.disk))
+    }
+
     /// 创建进度条
     pub fn progress_bar(ui: &mut egui::Ui, value: f32, max_value: f32, label: &str) -> egui::Response {
         let progress = (value / max_value).clamp(0.0, 1.0);
@@ -222,10 +463,34 @@ impl UiUtils {
         ui.horizontal(|ui| {
             ui.label(label);
             ui.add(egui::ProgressBar::new(progress).fill(color));
-            ui.label(format!("{:.1}%", progress * 100.0));
+            ui.label(Self::format_percentage(progress as f64 * 100.0));
         }).response
     }
 
+    /// 创建堆叠条，每段为（数值, 颜色, 标签），各段按占 `total` 的比例绘制，下方显示图例
+    pub fn stacked_bar(ui: &mut egui::Ui, segments: &[(u64, egui::Color32, &str)], total: u64) -> egui::Response {
+        let size = egui::vec2(ui.available_width(), ui.spacing().interact_size.y);
+        let (rect, response) = ui.allocate_exact_size(size, egui::Sense::hover());
+        let painter = ui.painter_at(rect);
+        painter.rect_filled(rect, 2.0, ui.visuals().extreme_bg_color);
+
+        let mut left = rect.left();
+        for &(value, color, _) in segments {
+            let fraction = if total > 0 { (value as f64 / total as f64).clamp(0.0, 1.0) } else { 0.0 };
+            let right = (left + fraction as f32 * rect.width()).min(rect.right());
+            painter.rect_filled(egui::Rect::from_x_y_ranges(left..=right, rect.y_range()), 0.0, color);
+            left = right;
+        }
+
+        ui.horizontal_wrapped(|ui| {
+            for &(value, color, label) in segments {
+                ui.colored_label(color, "■");
+                ui.label(format!("{} {}", label, Self::format_bytes(value)));
+            }
+        });
+        response
+    }
+
     /// 创建状态指示器
     pub fn status_indicator(ui: &mut egui::Ui, status: &str, color: egui::Color32) -> egui::Response {
         ui.horizontal(|ui| {
@@ -266,6 +531,32 @@ impl UiUtils {
         });
     }
 
+    /// 创建字节数度量显示，按配置内联或悬停显示精确字节数
+    pub fn bytes_metric_display(
+        ui: &mut egui::Ui,
+        label: &str,
+        bytes: u64,
+        color: Option<egui::Color32>,
+        mode: RawBytesDisplay,
+    ) {
+        let value = match mode {
+            RawBytesDisplay::Inline => Self::format_bytes_detailed(bytes),
+            RawBytesDisplay::Off | RawBytesDisplay::Tooltip => Self::format_bytes(bytes),
+        };
+
+        ui.horizontal(|ui| {
+            ui.label(format!("{}:", label));
+            let response = if let Some(color) = color {
+                ui.colored_label(color, value)
+            } else {
+                ui.label(value)
+            };
+            if mode == RawBytesDisplay::Tooltip {
+                response.on_hover_text(format!("{} bytes", bytes));
+            }
+        });
+    }
+
     /// 创建表格行
     pub fn table_row(ui: &mut egui::Ui, columns: &[&str]) {
         ui.horizontal(|ui| {
@@ -299,6 +590,19 @@ impl ResponsiveLayout {
         ((available_width / min_column_width) as usize).max(1)
     }
 
+    /// 解析概览页实际使用的布局，自动模式下宽度不足以容纳两列时使用紧凑布局
+    pub fn overview_layout(layout: OverviewLayout, available_width: f32) -> OverviewLayout {
+        const MIN_COLUMN_WIDTH: f32 = 400.0;
+
+        match layout {
+            OverviewLayout::Auto if Self::calculate_columns(available_width, MIN_COLUMN_WIDTH) >= 2 => {
+                OverviewLayout::Detailed
+            }
+            OverviewLayout::Auto => OverviewLayout::Compact,
+            layout => layout,
+        }
+    }
+
     /// 创建响应式网格
     pub fn grid<R>(
         ui: &mut egui::Ui,
@@ -326,12 +630,45 @@ impl ResponsiveLayout {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_resolve_theme() {
+        assert_eq!(UiTheme::resolve(&Theme::Light, Some(egui::Theme::Dark)), UiTheme::Light);
+        assert_eq!(UiTheme::resolve(&Theme::Dark, Some(egui::Theme::Light)), UiTheme::Dark);
+        assert_eq!(UiTheme::resolve(&Theme::Auto, Some(egui::Theme::Light)), UiTheme::Light);
+        assert_eq!(UiTheme::resolve(&Theme::Auto, Some(egui::Theme::Dark)), UiTheme::Dark);
+        assert_eq!(UiTheme::resolve(&Theme::Auto, None), UiTheme::Dark);
+    }
+
     #[test]
     fn test_format_bytes() {
         assert_eq!(UiUtils::format_bytes(0), "0 B");
-        assert_eq!(UiUtils::format_bytes(1024), "1.0 KB");
-        assert_eq!(UiUtils::format_bytes(1048576), "1.0 MB");
-        assert_eq!(UiUtils::format_bytes(1073741824), "1.0 GB");
+        assert_eq!(UiUtils::format_bytes(1024), "1.0 KiB");
+        assert_eq!(UiUtils::format_bytes(1048576), "1.0 MiB");
+        assert_eq!(UiUtils::format_bytes(1073741824), "1.0 GiB");
+    }
+
+    #[test]
+    fn test_format_bytes_with_base() {
+        assert_eq!(UiUtils::format_bytes_with_base(1000, true), "1000 B");
+        assert_eq!(UiUtils::format_bytes_with_base(1000, false), "1.0 KB");
+        assert_eq!(UiUtils::format_bytes_with_base(1024, true), "1.0 KiB");
+        assert_eq!(UiUtils::format_bytes_with_base(1024, false), "1.0 KB");
+    }
+
+    #[test]
+    fn test_format_bytes_detailed() {
+        assert_eq!(UiUtils::format_bytes_detailed(0), "0 B (0 bytes)");
+        assert_eq!(UiUtils::format_bytes_detailed(1610612736), "1.5 GiB (1610612736 bytes)");
+    }
+
+    #[test]
+    fn test_parse_shortcut() {
+        let shortcut = UiUtils::parse_shortcut("Ctrl+Shift+C").unwrap();
+        assert_eq!(shortcut.logical_key, egui::Key::C);
+        assert_eq!(shortcut.modifiers, egui::Modifiers::COMMAND | egui::Modifiers::SHIFT);
+        assert_eq!(UiUtils::parse_shortcut("F5").unwrap().modifiers, egui::Modifiers::NONE);
+        assert!(UiUtils::parse_shortcut("").is_none());
+        assert!(UiUtils::parse_shortcut("Hyper+C").is_none());
     }
 
     #[test]
@@ -340,6 +677,74 @@ mod tests {
         assert_eq!(UiUtils::format_percentage(100.0), "100.0%");
     }
 
+    #[test]
+    fn test_format_load_score() {
+        assert_eq!(UiUtils::format_load_score(42.46, 0), "42/100");
+        assert_eq!(UiUtils::format_load_score(42.46, 1), "42.5/100");
+        assert_eq!(UiUtils::format_load_score(104.0, 0), "100/100");
+    }
+
+    #[test]
+    fn test_format_signed_percent() {
+        assert_eq!(UiUtils::format_signed_percent(5.23), "+5.2%");
+        assert_eq!(UiUtils::format_signed_percent(-3.12), "-3.1%");
+        assert_eq!(UiUtils::format_signed_percent(0.0), "0.0%");
+        assert_eq!(UiUtils::format_signed_percent(-0.04), "0.0%");
+    }
+
+    #[test]
+    fn test_signed_percent_color() {
+        let green = egui::Color32::from_rgb(76, 175, 80);
+        let red = egui::Color32::from_rgb(244, 67, 54);
+
+        assert_eq!(UiUtils::signed_percent_color(0.0, DeltaConvention::IncreaseIsBad), None);
+        assert_eq!(UiUtils::signed_percent_color(5.0, DeltaConvention::IncreaseIsBad), Some(red));
+        assert_eq!(UiUtils::signed_percent_color(-5.0, DeltaConvention::IncreaseIsBad), Some(green));
+        assert_eq!(UiUtils::signed_percent_color(5.0, DeltaConvention::IncreaseIsGood), Some(green));
+        assert_eq!(UiUtils::signed_percent_color(-5.0, DeltaConvention::IncreaseIsGood), Some(red));
+    }
+
+    #[test]
+    fn test_alert_threshold_lines() {
+        let colors = ColorScheme::dark();
+        let threshold = AlertThreshold { warning: 80.0, critical: 95.0 };
+        let lines = UiUtils::alert_threshold_lines(&threshold, &colors);
+
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0], (80.0, colors.warning, "警告 80%".to_string()));
+        assert_eq!(lines[1], (95.0, colors.error, "严重 95%".to_string()));
+    }
+
+    #[test]
+    fn test_is_significant_change() {
+        use crate::system::{CpuInfo, MemoryInfo, SystemInfo};
+
+        let snapshot = |cpu: f32, memory: f64| SystemSnapshot::new(
+            CpuInfo { global_usage: cpu, ..Default::default() },
+            MemoryInfo { usage_percent: memory, ..Default::default() },
+            vec![],
+            SystemInfo::default(),
+            None,
+        );
+        let threshold = ChangeThreshold { cpu: 0.5, memory: 1.0, disk: 0.5 };
+
+        assert!(!UiUtils::is_significant_change(&threshold, &snapshot(10.0, 50.0), &snapshot(10.3, 50.8)));
+        assert!(UiUtils::is_significant_change(&threshold, &snapshot(10.0, 50.0), &snapshot(10.6, 50.0)));
+        assert!(UiUtils::is_significant_change(&threshold, &snapshot(10.0, 50.0), &snapshot(10.0, 51.5)));
+
+        // 阈值为0时任何变化都会刷新
+        let threshold = ChangeThreshold::default();
+        assert!(UiUtils::is_significant_change(&threshold, &snapshot(10.0, 50.0), &snapshot(10.01, 50.0)));
+    }
+
+    #[test]
+    fn test_health_color() {
+        let colors = ColorScheme::light();
+        assert_eq!(colors.health_color(SystemHealthStatus::Excellent), colors.success);
+        assert_eq!(colors.health_color(SystemHealthStatus::Poor), colors.warning);
+        assert_eq!(colors.health_color(SystemHealthStatus::Critical), colors.error);
+    }
+
     #[test]
     fn test_tab_type_name() {
         assert_eq!(TabType::Overview.name(), "概览");
@@ -352,4 +757,75 @@ mod tests {
         assert_eq!(ResponsiveLayout::calculate_columns(800.0, 200.0), 4);
         assert_eq!(ResponsiveLayout::calculate_columns(150.0, 200.0), 1);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_overview_layout() {
+        assert_eq!(ResponsiveLayout::overview_layout(OverviewLayout::Auto, 1000.0), OverviewLayout::Detailed);
+        assert_eq!(ResponsiveLayout::overview_layout(OverviewLayout::Auto, 500.0), OverviewLayout::Compact);
+        assert_eq!(ResponsiveLayout::overview_layout(OverviewLayout::Compact, 1000.0), OverviewLayout::Compact);
+        assert_eq!(ResponsiveLayout::overview_layout(OverviewLayout::Detailed, 300.0), OverviewLayout::Detailed);
+    }
+
+    #[test]
+    fn test_boot_time_entries() {
+        let info = SystemInfo { uptime: 30, boot_time: 1656633600, ..SystemInfo::default() };
+        assert_eq!(
+            UiUtils::boot_time_entries(&info, BootTimeDisplay::Uptime, "%Y"),
+            vec![("运行时间", "30秒".to_string())]
+        );
+        assert_eq!(
+            UiUtils::boot_time_entries(&info, BootTimeDisplay::Both, "%Y"),
+            vec![("启动时间", "2022".to_string()), ("运行时间", "30秒".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_english_labels() {
+        use crate::config::Language;
+        use crate::utils::I18n;
+
+        I18n::with_language(Language::English, || {
+            let info = SystemInfo { uptime: 30, boot_time: 1656633600, ..SystemInfo::default() };
+            let labels: Vec<&str> = UiUtils::boot_time_entries(&info, BootTimeDisplay::Both, "%Y")
+                .into_iter()
+                .map(|(label, _)| label)
+                .collect();
+            assert_eq!(labels, vec!["Boot Time", "Uptime"]);
+
+            let colors = ColorScheme::dark();
+            let threshold = AlertThreshold { warning: 80.0, critical: 95.0 };
+            let lines = UiUtils::alert_threshold_lines(&threshold, &colors);
+            assert_eq!(lines[0].2, "Warning 80%");
+            assert_eq!(lines[1].2, "Critical 95%");
+        });
+    }
+
+    #[test]
+    fn test_color_from_rgb() {
+        assert_eq!(UiUtils::color_from_rgb([1.0, 0.0, 0.5]), egui::Color32::from_rgb(255, 0, 128));
+        assert_eq!(UiUtils::color_from_rgb([2.0, -1.0, 0.0]), egui::Color32::from_rgb(255, 0, 0));
+    }
+
+    #[test]
+    fn test_dim_idle_color() {
+        let color = egui::Color32::from_rgb(200, 100, 50);
+        let dimming = IdleCoreDimming { enabled: true, threshold: 5.0, opacity: 0.5 };
+        assert_eq!(UiUtils::dim_idle_color(color, 2.0, &dimming), color.gamma_multiply(0.5));
+        assert_eq!(UiUtils::dim_idle_color(color, 50.0, &dimming), color);
+
+        let disabled = IdleCoreDimming { enabled: false, ..dimming };
+        assert_eq!(UiUtils::dim_idle_color(color, 2.0, &disabled), color);
+    }
+
+    #[test]
+    fn test_format_core_frequency() {
+        assert_eq!(UiUtils::format_core_frequency(3600, FrequencyUnit::MHz), "3600 MHz");
+        assert_eq!(UiUtils::format_core_frequency(3600, FrequencyUnit::GHz), "3.60 GHz");
+    }
+
+    #[test]
+    fn test_free_space_color() {
+        assert_eq!(UiUtils::get_free_space_color(5.0), UiUtils::get_usage_color(95.0));
+        assert_eq!(UiUtils::get_free_space_color(90.0), UiUtils::get_usage_color(10.0));
+    }
+}
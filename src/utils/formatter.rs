@@ -0,0 +1,211 @@
+//! 统一格式化模块
+//!
+//! 字节数、百分比、时长和频率的唯一格式化实现，其余模块的格式化函数均委托到这里，
+//! 以保证各处显示的单位和精度一致。
+
+use crate::config::{ByteUnits, FormatConfig, Language};
+use crate::utils::I18n;
+use std::sync::RwLock;
+
+/// 全局格式化设置，由UI在加载或更新配置时写入
+static SETTINGS: RwLock<FormatConfig> = RwLock::new(FormatConfig::DEFAULT);
+
+/// 统一格式化器
+pub struct Formatter;
+
+impl Formatter {
+    /// 设置全局格式化参数
+    pub fn configure(settings: FormatConfig) {
+        if let Ok(mut current) = SETTINGS.write() {
+            *current = settings;
+        }
+    }
+
+    /// 获取当前全局格式化参数
+    pub fn settings() -> FormatConfig {
+        SETTINGS.read().map(|settings| *settings).unwrap_or_default()
+    }
+
+    /// 格式化字节数为人类可读格式
+    pub fn bytes(bytes: u64) -> String {
+        Self::bytes_with(bytes, &Self::settings())
+    }
+
+    /// 按指定参数格式化字节数
+    pub fn bytes_with(bytes: u64, settings: &FormatConfig) -> String {
+        let binary = settings.byte_units == ByteUnits::Binary;
+        Self::format_bytes_base(bytes, binary, settings.decimals)
+    }
+
+    /// 按指定进制格式化字节数，使用全局小数位数
+    ///
+    /// `binary` 为真时以1024为进制并使用 "KiB/MiB/GiB" 单位，否则以1000为进制并使用 "KB/MB/GB"。
+    pub fn bytes_with_base(bytes: u64, binary: bool) -> String {
+        Self::format_bytes_base(bytes, binary, Self::settings().decimals)
+    }
+
+    fn format_bytes_base(bytes: u64, binary: bool, decimals: usize) -> String {
+        const BINARY_UNITS: &[&str] = &["B", "KiB", "MiB", "GiB", "TiB", "PiB"];
+        const DECIMAL_UNITS: &[&str] = &["B", "KB", "MB", "GB", "TB", "PB"];
+
+        if bytes == 0 {
+            return "0 B".to_string();
+        }
+
+        let (units, threshold) = if binary {
+            (BINARY_UNITS, 1024.0)
+        } else {
+            (DECIMAL_UNITS, 1000.0)
+        };
+
+        let mut size = bytes as f64;
+        let mut unit_index = 0;
+
+        while size >= threshold && unit_index < units.len() - 1 {
+            size /= threshold;
+            unit_index += 1;
+        }
+
+        if unit_index == 0 {
+            format!("{} {}", bytes, units[unit_index])
+        } else {
+            format!("{:.*} {}", decimals, size, units[unit_index])
+        }
+    }
+
+    /// 格式化百分比
+    pub fn percent(value: f64) -> String {
+        Self::percent_with(value, &Self::settings())
+    }
+
+    /// 按指定参数格式化百分比
+    pub fn percent_with(value: f64, settings: &FormatConfig) -> String {
+        format!("{:.*}%", settings.decimals, value)
+    }
+
+    /// 格式化频率（Hz）
+    pub fn frequency(hz: u64) -> String {
+        Self::frequency_with(hz, &Self::settings())
+    }
+
+    /// 按指定参数格式化频率
+    pub fn frequency_with(hz: u64, settings: &FormatConfig) -> String {
+        let decimals = settings.decimals;
+        if hz >= 1_000_000_000 {
+            format!("{:.*} GHz", decimals, hz as f64 / 1_000_000_000.0)
+        } else if hz >= 1_000_000 {
+            format!("{:.*} MHz", decimals, hz as f64 / 1_000_000.0)
+        } else if hz >= 1_000 {
+            format!("{:.*} KHz", decimals, hz as f64 / 1_000.0)
+        } else {
+            format!("{} Hz", hz)
+        }
+    }
+
+    /// 按当前界面语言格式化时长（秒）为人类可读格式
+    pub fn duration(seconds: u64) -> String {
+        Self::duration_in(seconds, I18n::language())
+    }
+
+    /// 按指定语言格式化时长（秒）
+    pub fn duration_in(seconds: u64, language: Language) -> String {
+        let days = seconds / 86400;
+        let hours = (seconds % 86400) / 3600;
+        let minutes = (seconds % 3600) / 60;
+        let secs = seconds % 60;
+
+        match language {
+            Language::Chinese => {
+                if days > 0 {
+                    format!("{}天 {}小时 {}分钟", days, hours, minutes)
+                } else if hours > 0 {
+                    format!("{}小时 {}分钟", hours, minutes)
+                } else if minutes > 0 {
+                    format!("{}分钟 {}秒", minutes, secs)
+                } else {
+                    format!("{}秒", secs)
+                }
+            }
+            Language::English => {
+                let unit = |value: u64, name: &str| {
+                    format!("{} {}{}", value, name, if value == 1 { "" } else { "s" })
+                };
+                if days > 0 {
+                    format!("{} {} {}", unit(days, "day"), unit(hours, "hour"), unit(minutes, "minute"))
+                } else if hours > 0 {
+                    format!("{} {}", unit(hours, "hour"), unit(minutes, "minute"))
+                } else if minutes > 0 {
+                    format!("{} {}", unit(minutes, "minute"), unit(secs, "second"))
+                } else {
+                    unit(secs, "second")
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bytes_binary() {
+        let settings = FormatConfig::default();
+        assert_eq!(Formatter::bytes_with(0, &settings), "0 B");
+        assert_eq!(Formatter::bytes_with(1023, &settings), "1023 B");
+        assert_eq!(Formatter::bytes_with(1024, &settings), "1.0 KiB");
+        assert_eq!(Formatter::bytes_with(1536, &settings), "1.5 KiB");
+        assert_eq!(Formatter::bytes_with(1073741824, &settings), "1.0 GiB");
+        assert_eq!(Formatter::bytes_with(1 << 50, &settings), "1.0 PiB");
+        assert_eq!(Formatter::bytes_with(u64::MAX, &settings), "16384.0 PiB");
+    }
+
+    #[test]
+    fn test_bytes_decimal_and_precision() {
+        let settings = FormatConfig { byte_units: ByteUnits::Decimal, decimals: 2 };
+        assert_eq!(Formatter::bytes_with(999, &settings), "999 B");
+        assert_eq!(Formatter::bytes_with(1000, &settings), "1.00 KB");
+        assert_eq!(Formatter::bytes_with(1_500_000, &settings), "1.50 MB");
+
+        let settings = FormatConfig { byte_units: ByteUnits::Binary, decimals: 0 };
+        assert_eq!(Formatter::bytes_with(1536, &settings), "2 KiB");
+    }
+
+    #[test]
+    fn test_bytes_with_base_boundaries() {
+        assert_eq!(Formatter::format_bytes_base(999, false, 1), "999 B");
+        assert_eq!(Formatter::format_bytes_base(1000, false, 1), "1.0 KB");
+        assert_eq!(Formatter::format_bytes_base(1000, true, 1), "1000 B");
+        assert_eq!(Formatter::format_bytes_base(1023, true, 1), "1023 B");
+        assert_eq!(Formatter::format_bytes_base(1024, true, 1), "1.0 KiB");
+        assert_eq!(Formatter::format_bytes_base(1024, false, 1), "1.0 KB");
+        assert_eq!(Formatter::format_bytes_base(1_000_000, false, 1), "1.0 MB");
+        assert_eq!(Formatter::format_bytes_base(1_000_000, true, 1), "976.6 KiB");
+        assert_eq!(Formatter::format_bytes_base(1 << 30, true, 1), "1.0 GiB");
+        assert_eq!(Formatter::format_bytes_base(1 << 30, false, 1), "1.1 GB");
+    }
+
+    #[test]
+    fn test_percent_and_frequency() {
+        let settings = FormatConfig::default();
+        assert_eq!(Formatter::percent_with(25.55, &settings), "25.6%");
+        assert_eq!(Formatter::percent_with(100.0, &FormatConfig { decimals: 0, ..settings }), "100%");
+
+        assert_eq!(Formatter::frequency_with(999, &settings), "999 Hz");
+        assert_eq!(Formatter::frequency_with(2_500, &settings), "2.5 KHz");
+        assert_eq!(Formatter::frequency_with(3_200_000_000, &settings), "3.2 GHz");
+    }
+
+    #[test]
+    fn test_duration() {
+        assert_eq!(Formatter::duration(0), "0秒");
+        assert_eq!(Formatter::duration(59), "59秒");
+        assert_eq!(Formatter::duration(60), "1分钟 0秒");
+        assert_eq!(Formatter::duration(3600), "1小时 0分钟");
+        assert_eq!(Formatter::duration(86400), "1天 0小时 0分钟");
+
+        assert_eq!(Formatter::duration_in(1, Language::English), "1 second");
+        assert_eq!(Formatter::duration_in(125, Language::English), "2 minutes 5 seconds");
+        assert_eq!(Formatter::duration_in(2 * 86400 + 3 * 3600 + 60, Language::English), "2 days 3 hours 1 minute");
+    }
+}
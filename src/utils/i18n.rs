@@ -0,0 +1,407 @@
+//! 界面语言模块
+//!
+//! 以中文原文作为键查找译文：中文界面直接返回原文，英文界面查表，未收录的文本原样显示。
+//! 当前语言由UI在加载或更新配置时写入，与 [`Formatter`](super::Formatter) 的全局设置相同。
+
+use crate::config::Language;
+use std::sync::RwLock;
+
+/// 全局界面语言
+static LANGUAGE: RwLock<Language> = RwLock::new(Language::Chinese);
+
+#[cfg(test)]
+thread_local! {
+    /// 测试中按线程覆盖的界面语言，避免并行运行的测试相互影响
+    static TEST_LANGUAGE: std::cell::Cell<Option<Language>> = const { std::cell::Cell::new(None) };
+}
+
+/// 界面语言
+pub struct I18n;
+
+impl I18n {
+    /// 设置全局界面语言
+    pub fn set_language(language: Language) {
+        if let Ok(mut current) = LANGUAGE.write() {
+            *current = language;
+        }
+    }
+
+    /// 获取当前界面语言
+    pub fn language() -> Language {
+        #[cfg(test)]
+        if let Some(language) = TEST_LANGUAGE.with(|language| language.get()) {
+            return language;
+        }
+        LANGUAGE.read().map(|language| *language).unwrap_or_default()
+    }
+
+    /// 在当前线程以指定语言执行 `f`，不影响全局设置（仅用于测试）
+    #[cfg(test)]
+    pub(crate) fn with_language<R>(language: Language, f: impl FnOnce() -> R) -> R {
+        TEST_LANGUAGE.with(|current| current.set(Some(language)));
+        let result = f();
+        TEST_LANGUAGE.with(|current| current.set(None));
+        result
+    }
+}
+
+/// 按当前语言翻译文本
+pub fn t(key: &'static str) -> &'static str {
+    t_in(key, I18n::language())
+}
+
+/// 按指定语言翻译文本，未收录的文本返回原文
+pub fn t_in(key: &'static str, language: Language) -> &'static str {
+    match language {
+        Language::Chinese => key,
+        Language::English => english(key).unwrap_or(key),
+    }
+}
+
+/// 英文译文表
+fn english(key: &str) -> Option<&'static str> {
+    Some(match key {
+        // 标签页
+        "概览" => "Overview",
+        "内存" => "Memory",
+        "磁盘" => "Disk",
+        "进程" => "Processes",
+        "网络" => "Network",
+
+        // 系统健康状态
+        "系统运行优秀" => "System running excellently",
+        "系统运行良好" => "System running well",
+        "系统运行一般" => "System running fair",
+        "系统负载较高" => "System load is high",
+        "系统负载严重" => "System load is critical",
+
+        // 菜单
+        "文件" => "File",
+        "设置" => "Settings",
+        "导出进程列表" => "Export Process List",
+        "导出CSV" => "Export CSV",
+        "导出保留的快照历史" => "Export the retained snapshot history",
+        "导出快照" => "Export Snapshot",
+        "导出快照及统计" => "Export Snapshot with Statistics",
+        "退出" => "Quit",
+        "视图" => "View",
+        "显示侧边栏" => "Show Sidebar",
+        "紧凑模式" => "Compact Mode",
+        "退出紧凑模式" => "Exit Compact Mode",
+        "告警历史" => "Alert History",
+        "事件日志" => "Event Log",
+        "重置统计" => "Reset Statistics",
+        "清空历史数据，平均值、最值和预测重新开始计算" => "Clear history; averages, extremes and predictions start over",
+        "主题" => "Theme",
+        "亮色主题" => "Light Theme",
+        "暗色主题" => "Dark Theme",
+        "跟随系统" => "Follow System",
+        "主机" => "Host",
+        "本机" => "Local",
+        "帮助" => "Help",
+        "快捷键" => "Keyboard Shortcuts",
+        "立即刷新" => "Refresh Now",
+        "打开设置" => "Open Settings",
+        "复制当前标签页数据" => "Copy Current Tab Data",
+        "关于" => "About",
+
+        // 设置窗口
+        "应用程序设置" => "Application Settings",
+        "监控设置" => "Monitoring",
+        "界面设置" => "Interface",
+        "导出设置" => "Export",
+        "语言:" => "Language:",
+        "撤销未应用的修改" => "Discard Unapplied Changes",
+        "重置为默认" => "Reset to Defaults",
+        "关闭" => "Close",
+        "设置未应用" => "Settings not applied",
+        "刷新间隔 (毫秒):" => "Refresh interval (ms):",
+        "传感器采样间隔 (毫秒):" => "Sensor sampling interval (ms):",
+        "温度传感器读取较慢，按独立的较长间隔采样" => "Temperature sensors are slow to read and are sampled on a separate, longer interval",
+        "保存历史数据" => "Save history",
+        "退出时保存CPU和内存历史，下次启动时恢复图表" => "Save CPU and memory history on exit and restore the charts on next launch",
+        "启用CPU监控" => "Enable CPU monitoring",
+        "启用内存监控" => "Enable memory monitoring",
+        "启用GPU监控" => "Enable GPU monitoring",
+        "启用网络监控" => "Enable network monitoring",
+        "启用磁盘监控" => "Enable disk monitoring",
+        "进程采集:" => "Process collection:",
+        "始终" => "Always",
+        "按需" => "On demand",
+        "从不" => "Never",
+        "进程采集开销较大，按需模式仅在进程页可见时采集" => "Process collection is expensive; on demand only collects while the Processes tab is visible",
+        "进程数上限:" => "Process limit:",
+        "0表示采集全部进程" => "0 collects all processes",
+        "进程选取依据:" => "Select processes by:",
+        "CPU、内存和运行时间取最大的进程，PID和名称按升序取前面的进程" => "CPU, memory and run time keep the largest processes; PID and name keep the first in ascending order",
+        "名称" => "Name",
+        "启动时检查磁盘和内存" => "Check disk and memory on startup",
+        "通知聚合窗口:" => "Notification grouping window:",
+        " 秒" => " s",
+        "窗口内的多条告警合并为一条通知" => "Alerts within the window are combined into one notification",
+        "通知冷却时间:" => "Notification cooldown:",
+        "快速启动（首次仅采集CPU和内存）" => "Fast start (collect only CPU and memory at first)",
+        "最小化时暂停磁盘和进程采集" => "Pause disk and process collection while minimized",
+        "CPU和内存仍会采集，告警照常触发" => "CPU and memory are still collected and alerts still fire",
+        "字体大小:" => "Font size:",
+        "显示网格" => "Show grid",
+        "图表显示数据点" => "Show chart data points",
+        "图表线条宽度:" => "Chart line width:",
+        "显示CPU繁忙核心数" => "Show busy CPU cores",
+        "显示CPU型号" => "Show CPU model",
+        "核心频率单位:" => "Core frequency unit:",
+        "自动" => "Auto",
+        "淡化空闲核心" => "Dim idle cores",
+        "空闲阈值 (%):" => "Idle threshold (%):",
+        "淡化不透明度:" => "Dimmed opacity:",
+        "显示摘要条" => "Show summary strip",
+        "窗口置顶" => "Always on top",
+        "进程表支持多选和批量结束" => "Multi-select and batch kill in the process table",
+        "在概览页显示负载评分" => "Show load score on the Overview tab",
+        "负载评分小数位数:" => "Load score decimals:",
+        "在侧边栏显示数据来源健康状态" => "Show data source health in the sidebar",
+        "数据过期时间:" => "Data stale after:",
+        "图表颜色:" => "Chart colors:",
+        "图表自动缩放:" => "Chart autoscale:",
+        "按数据范围调整Y轴，使用率较低时也能看清变化" => "Fit the Y axis to the data so changes stay visible at low usage",
+        "显示内存分解" => "Show memory breakdown",
+        "显示网络总计" => "Show network total",
+        "网络总计包含虚拟接口" => "Include virtual interfaces in the network total",
+        "回环、容器和VPN接口的流量通常也经过物理接口，计入会重复统计" => "Loopback, container and VPN traffic usually also passes through a physical interface and would be counted twice",
+        "CPU使用率限制在100%以内" => "Clamp CPU usage to 100%",
+        "关闭后显示原始读数，采样误差可能使其略超100%" => "When off, raw readings are shown and sampling error may push them slightly above 100%",
+        "磁盘按剩余空间显示" => "Show free disk space",
+        "界面更新阈值 (百分点):" => "UI update threshold (percentage points):",
+        "侧边栏平均样本数:" => "Sidebar average samples:",
+        "预测最低置信度:" => "Minimum forecast confidence:",
+        "小数位数:" => "Decimals:",
+        "字节单位:" => "Byte units:",
+        "1024进制 (KiB)" => "Base 1024 (KiB)",
+        "1000进制 (KB)" => "Base 1000 (KB)",
+        "概览布局:" => "Overview layout:",
+        "紧凑" => "Compact",
+        "详细" => "Detailed",
+        "概览以环形图显示CPU和内存" => "Show CPU and memory as gauges on the Overview tab",
+        "系统时间显示:" => "System time display:",
+        "启动时间" => "Boot Time",
+        "两者" => "Both",
+        "日期时间格式:" => "Date/time format:",
+        "复制标签页快捷键:" => "Copy tab shortcut:",
+        "如 Ctrl+Shift+C，留空禁用" => "For example Ctrl+Shift+C; leave empty to disable",
+        "精确字节数:" => "Exact byte counts:",
+        "不显示" => "Hidden",
+        "内联显示" => "Inline",
+        "悬停显示" => "On hover",
+        "导出目录:" => "Export directory:",
+        "留空时导出到文档目录" => "Exports to the Documents folder when empty",
+        "文件名模板:" => "File name template:",
+        "可用占位符: {hostname}、{timestamp}、{type}" => "Available placeholders: {hostname}, {timestamp}, {type}",
+
+        // 侧边栏与主内容区
+        "系统监控" => "System Monitor",
+        "不可达" => "unreachable",
+        "尚未采集" => "not collected yet",
+        "无" => "none",
+        "距上次成功采集" => "Since last successful collection",
+        "最近错误" => "Last error",
+        "系统摘要" => "System Summary",
+        "平均采样数" => "Averaged samples",
+        "无可用磁盘信息" => "No disk information available",
+        "更新时间" => "Updated",
+        "🔄 刷新" => "🔄 Refresh",
+        "❄ 冻结显示" => "❄ Freeze Display",
+        "显示已冻结，冻结时长" => "Display frozen for",
+        "恢复实时" => "Resume Live",
+        "标签页内容加载中..." => "Loading tab content...",
+        "正在加载系统数据..." => "Loading system data...",
+        "正在加载CPU数据..." => "Loading CPU data...",
+        "健康" => "Health",
+
+        // 状态栏
+        "运行时间" => "Uptime",
+        "降级模式" => "Degraded Mode",
+        "错误" => "Error",
+        "运行正常" => "Running normally",
+        "个核心" => "cores",
+
+        // 告警、事件日志和通知
+        "警告" => "Warning",
+        "严重" => "Critical",
+        "告警总数" => "Total alerts",
+        "事件总数" => "Total events",
+        "清空" => "Clear",
+        "暂无告警" => "No alerts",
+        "暂无事件" => "No events",
+        "查看" => "View",
+        "告警通知" => "Alert Notification",
+        "查看告警历史" => "View Alert History",
+        "启动健康检查" => "Startup Health Check",
+        "⚠ 检测到以下严重问题：" => "⚠ The following critical problems were detected:",
+        "知道了" => "Got It",
+
+        // 关于窗口
+        "系统监控工具" => "System Monitor",
+        "版本" => "Version",
+        "基于Rust和egui构建的实时系统监控工具" => "A real-time system monitor built with Rust and egui",
+        "提供CPU、内存、磁盘等系统信息的实时监控" => "Monitors CPU, memory, disk and other system information in real time",
+        "运行时间:" => "Uptime:",
+        "系统状态:" => "System status:",
+
+        // 概览和CPU标签页
+        "负载评分" => "Load score",
+        "性能概览" => "Performance Overview",
+        "CPU使用率" => "CPU Usage",
+        "内存使用率" => "Memory Usage",
+        "磁盘使用率" => "Disk Usage",
+        "负载预测" => "Load Forecast",
+        "数据不足以预测" => "Not enough data to forecast",
+        "系统信息" => "System Information",
+        "操作系统" => "Operating System",
+        "主机名" => "Hostname",
+        "平均负载 (1/5/15分钟)" => "Load average (1/5/15 min)",
+        "厂商" => "Vendor",
+        "CPU信息" => "CPU Information",
+        "总体CPU使用率" => "Overall CPU Usage",
+        "繁忙核心" => "Busy cores",
+        "CPU核心详情" => "CPU Core Details",
+        "核心" => "Core",
+        "使用率" => "Usage",
+        "频率" => "Frequency",
+        "列表" => "List",
+        "热力图" => "Heatmap",
+        "温度" => "Temperature",
+        "最高" => "max",
+        "未知" => "Unknown",
+        "型号" => "Model",
+        "物理核心" => "physical cores",
+        "逻辑核心" => "logical cores",
+        "置信度" => "Confidence",
+
+        // 图表
+        "无数据" => "No data",
+        "1分钟" => "1 min",
+        "5分钟" => "5 min",
+        "15分钟" => "15 min",
+        "全部" => "All",
+
+        // 内存
+        "内存信息" => "Memory Information",
+        "交换空间" => "Swap",
+        "未启用交换空间" => "Swap is not enabled",
+        "已使用" => "Used",
+        "可回收缓存" => "Reclaimable cache",
+        "空闲" => "Free",
+        "可回收缓存可在需要时释放给应用程序" => "Reclaimable cache can be released to applications when needed",
+        "内存统计" => "Memory Statistics",
+        "总内存" => "Total Memory",
+        "可用" => "Available",
+        "使用率分析" => "Usage Analysis",
+        "正常" => "Normal",
+        "注意" => "Attention",
+        "正在加载内存数据..." => "Loading memory data...",
+
+        // 磁盘
+        "磁盘信息" => "Disk Information",
+        "已用空间 (GiB)" => "Used space (GiB)",
+        "仅显示物理磁盘" => "Physical disks only",
+        "隐藏回环设备、网络文件系统和可移动磁盘，下次采集时生效" => "Hide loop devices, network file systems and removable disks; takes effect on the next collection",
+        "未检测到磁盘" => "No disks detected",
+        "挂载点" => "Mount Point",
+        "文件系统" => "File System",
+        "总容量" => "Total Capacity",
+        "可用空间" => "Available Space",
+        "剩余" => "remaining",
+        "读取" => "Read",
+        "写入" => "Write",
+        "正在加载磁盘数据..." => "Loading disk data...",
+
+        // GPU
+        "GPU信息" => "GPU Information",
+        "正在加载GPU数据..." => "Loading GPU data...",
+        "GPU监控未启用" => "GPU monitoring is disabled",
+        "CPU监控未启用" => "CPU monitoring is disabled",
+        "内存监控未启用" => "Memory monitoring is disabled",
+        "未检测到支持的GPU（目前仅支持安装了驱动的NVIDIA显卡）" => "No supported GPU detected (only NVIDIA GPUs with drivers installed are supported)",
+        "GPU使用率" => "GPU Usage",
+        "显存使用率" => "VRAM Usage",
+        "总显存" => "Total VRAM",
+        "已用显存" => "Used VRAM",
+        "驱动版本" => "Driver Version",
+        "显存" => "VRAM",
+
+        // 进程
+        "进程信息" => "Process Information",
+        "正在加载进程数据..." => "Loading process data...",
+        "关闭历史曲线" => "Close history chart",
+        "路径" => "Path",
+        "命令行:" => "Command line:",
+        "命令行" => "Command Line",
+        "确认结束进程" => "Confirm Ending Processes",
+        "将结束的进程数" => "Processes to end",
+        "结束" => "End",
+        "取消" => "Cancel",
+        "过滤:" => "Filter:",
+        "显示的进程" => "Shown processes",
+        "全选" => "Select all",
+        "清除选择" => "Clear selection",
+        "结束选中" => "End selected",
+        "用户" => "User",
+        "状态" => "Status",
+        "进程名称包含无效字符，已替换显示" => "The process name contains invalid characters and was replaced for display",
+        "结束进程" => "End Process",
+        "启动于" => "Started at",
+
+        // 连接
+        "连接" => "Connections",
+        "刷新" => "Refresh",
+        "点击刷新获取当前的TCP/UDP连接" => "Click refresh to list the current TCP/UDP connections",
+        "连接数" => "Connections",
+        "协议" => "Protocol",
+        "本地地址" => "Local Address",
+        "远端地址" => "Remote Address",
+
+        // 网络
+        "网络信息" => "Network Information",
+        "未检测到网络接口" => "No network interfaces detected",
+        "网络信息不可用" => "Network information unavailable",
+        "正在加载网络数据..." => "Loading network data...",
+        "接收速率 (KB/s)" => "Receive rate (KB/s)",
+        "发送速率 (KB/s)" => "Send rate (KB/s)",
+        "总计" => "Total",
+        "总计（不含虚拟接口）" => "Total (excluding virtual interfaces)",
+        "接收" => "Received",
+        "发送" => "Sent",
+        "字节数" => "Bytes",
+        "速率" => "Rate",
+        "包数" => "Packets",
+        "错误数" => "Errors",
+        "接口" => "Interface",
+        "保存" => "Save",
+        "重命名" => "Rename",
+
+        // 导出
+        "时间" => "Time",
+        "平均负载" => "Load average",
+        "导出进程列表失败" => "Failed to export process list",
+        "导出CSV失败" => "Failed to export CSV",
+        "导出快照失败" => "Failed to export snapshot",
+        "暂无快照数据" => "no snapshot data yet",
+
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_translate() {
+        assert_eq!(t_in("概览", Language::Chinese), "概览");
+        assert_eq!(t_in("概览", Language::English), "Overview");
+        assert_eq!(t_in("系统负载严重", Language::English), "System load is critical");
+        assert_eq!(t_in("启用CPU监控", Language::English), "Enable CPU monitoring");
+        // 未收录的文本原样显示
+        assert_eq!(t_in("CPU", Language::English), "CPU");
+    }
+}
@@ -2,6 +2,13 @@
 //! 
 //! 提供各种实用工具函数和助手。
 
+pub mod formatter;
+pub mod i18n;
+
+pub use formatter::Formatter;
+pub use i18n::{t, I18n};
+
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 /// 时间工具
@@ -18,21 +25,7 @@ impl TimeUtils {
 
     /// 格式化持续时间为人类可读格式
     pub fn format_duration(duration: Duration) -> String {
-        let total_seconds = duration.as_secs();
-        let days = total_seconds / 86400;
-        let hours = (total_seconds % 86400) / 3600;
-        let minutes = (total_seconds % 3600) / 60;
-        let seconds = total_seconds % 60;
-
-        if days > 0 {
-            format!("{}天 {}小时 {}分钟", days, hours, minutes)
-        } else if hours > 0 {
-            format!("{}小时 {}分钟", hours, minutes)
-        } else if minutes > 0 {
-            format!("{}分钟 {}秒", minutes, seconds)
-        } else {
-            format!("{}秒", seconds)
-        }
+        Formatter::duration(duration.as_secs())
     }
 
     /// 格式化时间戳为本地时间字符串
@@ -96,37 +89,19 @@ impl MathUtils {
 pub struct StringUtils;
 
 impl StringUtils {
-    /// 截断字符串到指定长度
+    /// 截断字符串到指定长度（按字符计数，不会截断多字节字符）
     pub fn truncate(s: &str, max_len: usize) -> String {
-        if s.len() <= max_len {
+        if s.chars().count() <= max_len {
             s.to_string()
         } else {
-            format!("{}...", &s[..max_len.saturating_sub(3)])
+            let kept: String = s.chars().take(max_len.saturating_sub(3)).collect();
+            format!("{}...", kept)
         }
     }
 
     /// 格式化文件大小
     pub fn format_file_size(bytes: u64) -> String {
-        const UNITS: &[&str] = &["B", "KB", "MB", "GB", "TB", "PB"];
-        const THRESHOLD: f64 = 1024.0;
-
-        if bytes == 0 {
-            return "0 B".to_string();
-        }
-
-        let mut size = bytes as f64;
-        let mut unit_index = 0;
-
-        while size >= THRESHOLD && unit_index < UNITS.len() - 1 {
-            size /= THRESHOLD;
-            unit_index += 1;
-        }
-
-        if unit_index == 0 {
-            format!("{} {}", bytes, UNITS[unit_index])
-        } else {
-            format!("{:.1} {}", size, UNITS[unit_index])
-        }
+        Formatter::bytes(bytes)
     }
 
     /// 格式化数字为千分位格式
@@ -263,11 +238,19 @@ impl ValidationUtils {
         port > 0
     }
 
-    /// 验证IP地址格式（简单验证）
+    /// 验证IP地址格式（IPv4或IPv6）
     pub fn is_valid_ip(ip: &str) -> bool {
-        ip.split('.')
-            .filter_map(|s| s.parse::<u8>().ok())
-            .count() == 4
+        ip.parse::<IpAddr>().is_ok()
+    }
+
+    /// 验证IPv4地址格式
+    pub fn is_valid_ipv4(ip: &str) -> bool {
+        ip.parse::<Ipv4Addr>().is_ok()
+    }
+
+    /// 验证IPv6地址格式
+    pub fn is_valid_ipv6(ip: &str) -> bool {
+        ip.parse::<Ipv6Addr>().is_ok()
     }
 
     /// 验证文件路径
@@ -303,14 +286,32 @@ mod tests {
         assert_eq!(MathUtils::clamp(25, 10, 20), 20);
     }
 
+    #[test]
+    fn test_math_utils_degenerate_input() {
+        assert_eq!(MathUtils::percentage(10.0, 0.0), 0.0);
+        assert_eq!(MathUtils::standard_deviation(&[]), 0.0);
+        assert_eq!(MathUtils::standard_deviation(&[42.0]), 0.0);
+        assert!(MathUtils::moving_average(&[1.0, 2.0], 0).is_empty());
+    }
+
     #[test]
     fn test_string_utils() {
-        assert_eq!(StringUtils::format_file_size(1024), "1.0 KB");
-        assert_eq!(StringUtils::format_file_size(1048576), "1.0 MB");
+        assert_eq!(StringUtils::format_file_size(1024), "1.0 KiB");
+        assert_eq!(StringUtils::format_file_size(1048576), "1.0 MiB");
         assert_eq!(StringUtils::truncate("Hello World", 5), "He...");
         assert_eq!(StringUtils::format_number(1234567), "1,234,567");
     }
 
+    #[test]
+    fn test_truncate_multibyte() {
+        assert_eq!(StringUtils::truncate("系统监控进程", 6), "系统监控进程");
+        assert_eq!(StringUtils::truncate("系统监控进程", 5), "系统...");
+        assert_eq!(StringUtils::truncate("系统监控进程", 4), "系...");
+        assert_eq!(StringUtils::truncate("🚀🔥💻🖥️", 3), "...");
+        assert_eq!(StringUtils::truncate("进程🚀abc", 6), "进程🚀abc");
+        assert_eq!(StringUtils::truncate("进程🚀abcd", 6), "进程🚀...");
+    }
+
     #[test]
     fn test_color_utils() {
         let color = ColorUtils::usage_color(25.0);
@@ -326,6 +327,15 @@ mod tests {
         assert!(!ValidationUtils::is_valid_port(0));
         assert!(ValidationUtils::is_valid_ip("192.168.1.1"));
         assert!(!ValidationUtils::is_valid_ip("256.1.1.1"));
+        assert!(!ValidationUtils::is_valid_ip("1.2.3.4.5"));
+        assert!(!ValidationUtils::is_valid_ip("1.2.3"));
+        assert!(ValidationUtils::is_valid_ip("::1"));
+        assert!(ValidationUtils::is_valid_ip("fe80::1"));
+        assert!(!ValidationUtils::is_valid_ip("fe80:::1"));
+        assert!(ValidationUtils::is_valid_ipv4("10.0.0.1"));
+        assert!(!ValidationUtils::is_valid_ipv4("::1"));
+        assert!(ValidationUtils::is_valid_ipv6("::1"));
+        assert!(!ValidationUtils::is_valid_ipv6("10.0.0.1"));
         assert!(ValidationUtils::is_in_range(15, 10, 20));
         assert!(!ValidationUtils::is_in_range(25, 10, 20));
     }